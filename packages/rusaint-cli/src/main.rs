@@ -5,13 +5,16 @@ use std::{fs::File, io::Write, sync::Arc};
 use clap::{Parser, Subcommand};
 use rusaint::{
     application::{
+        chapel::ChapelApplication,
         course_schedule::{
             model::{Lecture, LectureCategory},
             CourseScheduleApplication,
         },
+        personal_course_schedule::{ics::ScheduleCalendar, PersonalCourseScheduleApplication},
         USaintClientBuilder,
     },
-    RusaintError, USaintSession,
+    model::Date,
+    ApplicationError, RusaintError, USaintSession,
 };
 
 use dotenv::dotenv;
@@ -112,6 +115,18 @@ enum Commands {
         #[arg(long)]
         semester: SemesterType,
     },
+    ExportIcs {
+        #[arg(long)]
+        year: u32,
+        #[arg(long)]
+        semester: SemesterType,
+        #[arg(long)]
+        start: Date,
+        #[arg(long)]
+        end: Date,
+        #[arg(long)]
+        chapel: bool,
+    },
 }
 
 #[tokio::main]
@@ -267,6 +282,16 @@ async fn main() -> Result<(), RusaintError> {
             let lectures = find_cyber(session.clone(), year, semester).await?;
             create_json(format!("{}_{}_숭사대", year, semester), lectures)
         }
+        Commands::ExportIcs {
+            year,
+            semester,
+            start,
+            end,
+            chapel,
+        } => {
+            let ics = export_ics(session.clone(), year, semester, start, end, chapel).await?;
+            create_ics(format!("{}_{}_시간표", year, semester), ics)
+        }
     };
 
     Ok(())
@@ -279,6 +304,45 @@ fn create_json(file_name: String, lectures: Vec<Lecture>) {
         .expect("Failed to write to file");
 }
 
+fn create_ics(file_name: String, ics: String) {
+    let mut file = File::create(format!("{}.ics", file_name)).expect("Failed to create .ics");
+    file.write_all(ics.as_bytes())
+        .expect("Failed to write to file");
+}
+
+async fn export_ics(
+    session: Arc<USaintSession>,
+    year: u32,
+    semester: SemesterType,
+    start: Date,
+    end: Date,
+    include_chapel: bool,
+) -> Result<String, RusaintError> {
+    let mut app = USaintClientBuilder::new()
+        .session(session.clone())
+        .build_into::<PersonalCourseScheduleApplication>()
+        .await?;
+    let schedule = app.schedule(year, *semester).await?;
+    let mut calendar = ScheduleCalendar::new(session.id().unwrap_or_default(), start, end);
+    calendar.add_course_schedule(&schedule);
+    if include_chapel {
+        let mut chapel_app = USaintClientBuilder::new()
+            .session(session)
+            .build_into::<ChapelApplication>()
+            .await?;
+        match chapel_app.information(year, *semester).await {
+            Ok(information) => {
+                calendar.add_chapel(information.general_information());
+            }
+            Err(RusaintError::ApplicationError(ApplicationError::NoChapelInformation)) => {
+                println!("No chapel information for {}-{}, skip.", year, semester);
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(calendar.to_ics())
+}
+
 async fn find_by_lecture(
     session: Arc<USaintSession>,
     year: u32,
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use rusaint::{application::academic_calendar::model::AcademicEvent, model::Date};
+use tokio::sync::RwLock;
+
+use crate::application::model::YearSemester;
+use crate::{error::RusaintError, session::USaintSession};
+
+/// [학사일정조회](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW9010)
+#[derive(uniffi::Object)]
+pub struct AcademicCalendarApplication(
+    RwLock<rusaint::application::academic_calendar::AcademicCalendarApplication>,
+);
+
+#[uniffi::export(async_runtime = "tokio")]
+impl AcademicCalendarApplication {
+    /// 해당 학년도의 학사일정을 가져옵니다.
+    pub async fn events(&self, year: u32) -> Result<Vec<AcademicEvent>, RusaintError> {
+        Ok(self.0.write().await.events(year).await?)
+    }
+
+    /// 학사일정을 기준으로 주어진 날짜의 학년도와 학기를 반환합니다.
+    pub async fn current_semester(&self, today: Date) -> Result<YearSemester, RusaintError> {
+        let (year, semester) = self.0.write().await.current_semester(today).await?;
+        Ok(YearSemester::new(year, semester))
+    }
+}
+
+/// [`AcademicCalendarApplication`] 생성을 위한 빌더
+#[derive(uniffi::Object)]
+pub struct AcademicCalendarApplicationBuilder {}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl AcademicCalendarApplicationBuilder {
+    /// 새로운 [`AcademicCalendarApplicationBuilder`]를 만듭니다.
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// 세션과 함께 [`AcademicCalendarApplication`]을 만듭니다.
+    pub async fn build(
+        &self,
+        session: Arc<USaintSession>,
+    ) -> Result<AcademicCalendarApplication, RusaintError> {
+        let original_builder =
+            rusaint::application::USaintClientBuilder::new().session(session.original());
+        let original_app = original_builder
+            .build_into::<rusaint::application::academic_calendar::AcademicCalendarApplication>()
+            .await?;
+        Ok(AcademicCalendarApplication(RwLock::new(original_app)))
+    }
+}
+
+impl Default for AcademicCalendarApplicationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use rusaint::{
+    application::academic_status::model::{AcademicStatusRequest, AcademicStatusRequestKind},
+    model::SemesterType,
+};
+use tokio::sync::RwLock;
+
+use crate::{error::RusaintError, session::USaintSession};
+
+/// [학적변동신청](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW1030)
+#[derive(uniffi::Object)]
+pub struct AcademicStatusApplication(
+    RwLock<rusaint::application::academic_status::AcademicStatusApplication>,
+);
+
+#[uniffi::export(async_runtime = "tokio")]
+impl AcademicStatusApplication {
+    /// 휴학, 복학, 자퇴 등 학적변동 신청 내역과 처리 상태를 가져옵니다.
+    pub async fn requests(&self) -> Result<Vec<AcademicStatusRequest>, RusaintError> {
+        Ok(self.0.write().await.requests().await?)
+    }
+
+    /// 새로운 학적변동을 신청하고, 신청 후의 신청 내역을 반환합니다.
+    pub async fn submit(
+        &self,
+        kind: AcademicStatusRequestKind,
+        year: u32,
+        semester: SemesterType,
+        reason: String,
+    ) -> Result<Vec<AcademicStatusRequest>, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .submit(kind, year, semester, &reason)
+            .await?)
+    }
+}
+
+/// [`AcademicStatusApplication`] 생성을 위한 빌더
+#[derive(uniffi::Object)]
+pub struct AcademicStatusApplicationBuilder {}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl AcademicStatusApplicationBuilder {
+    /// 새로운 [`AcademicStatusApplicationBuilder`]를 만듭니다.
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// 세션과 함께 [`AcademicStatusApplication`]을 만듭니다.
+    pub async fn build(
+        &self,
+        session: Arc<USaintSession>,
+    ) -> Result<AcademicStatusApplication, RusaintError> {
+        let original_builder =
+            rusaint::application::USaintClientBuilder::new().session(session.original());
+        let original_app = original_builder
+            .build_into::<rusaint::application::academic_status::AcademicStatusApplication>()
+            .await?;
+        Ok(AcademicStatusApplication(RwLock::new(original_app)))
+    }
+}
+
+impl Default for AcademicStatusApplicationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use rusaint::{
+    application::advising::model::{Advisor, SemesterCounseling},
+    model::SemesterType,
+};
+use tokio::sync::RwLock;
+
+use crate::application::model::YearSemester;
+use crate::{error::RusaintError, session::USaintSession};
+
+/// [학생상담조회](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW1120)
+#[derive(uniffi::Object)]
+pub struct AdvisingApplication(RwLock<rusaint::application::advising::AdvisingApplication>);
+
+#[uniffi::export(async_runtime = "tokio")]
+impl AdvisingApplication {
+    /// 학생의 지도교수 정보를 가져옵니다.
+    pub async fn advisor(&self) -> Result<Advisor, RusaintError> {
+        Ok(self.0.read().await.advisor()?)
+    }
+
+    /// 해당 학기의 상담 내역과 필수상담 이수 여부를 가져옵니다.
+    pub async fn counseling(
+        &self,
+        year: u32,
+        semester: SemesterType,
+    ) -> Result<SemesterCounseling, RusaintError> {
+        Ok(self.0.write().await.counseling(year, semester).await?)
+    }
+
+    /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
+    /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
+    pub async fn get_selected_semester(&self) -> Result<YearSemester, RusaintError> {
+        let (year, semester) = self.0.read().await.get_selected_semester()?;
+        Ok(YearSemester::new(year, semester))
+    }
+}
+
+/// [`AdvisingApplication`] 생성을 위한 빌더
+#[derive(uniffi::Object)]
+pub struct AdvisingApplicationBuilder {}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl AdvisingApplicationBuilder {
+    /// 새로운 [`AdvisingApplicationBuilder`]를 만듭니다.
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// 세션과 함께 [`AdvisingApplication`]을 만듭니다.
+    pub async fn build(
+        &self,
+        session: Arc<USaintSession>,
+    ) -> Result<AdvisingApplication, RusaintError> {
+        let original_builder =
+            rusaint::application::USaintClientBuilder::new().session(session.original());
+        let original_app = original_builder
+            .build_into::<rusaint::application::advising::AdvisingApplication>()
+            .await?;
+        Ok(AdvisingApplication(RwLock::new(original_app)))
+    }
+}
+
+impl Default for AdvisingApplicationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
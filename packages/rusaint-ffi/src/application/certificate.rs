@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use rusaint::application::certificate::model::{
+    CertificateDocument, CertificateIssuance, CertificateType,
+};
+use tokio::sync::RwLock;
+
+use crate::{error::RusaintError, session::USaintSession};
+
+/// [증명서발급](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW6010)
+#[derive(uniffi::Object)]
+pub struct CertificateApplication(
+    RwLock<rusaint::application::certificate::CertificateApplication>,
+);
+
+#[uniffi::export(async_runtime = "tokio")]
+impl CertificateApplication {
+    /// 발급 가능한 증명서 종류를 가져옵니다.
+    pub async fn certificate_types(&self) -> Result<Vec<CertificateType>, RusaintError> {
+        Ok(self.0.write().await.certificate_types().await?)
+    }
+
+    /// 증명서 발급 내역을 가져옵니다.
+    pub async fn history(&self) -> Result<Vec<CertificateIssuance>, RusaintError> {
+        Ok(self.0.write().await.history().await?)
+    }
+
+    /// 발급 내역의 증명서를 출력하고, 출력용 문서를 내려받습니다.
+    ///
+    /// 포털의 출력 버튼을 누르므로 호출할 때마다 포털에 증명서 출력이 기록됩니다.
+    pub async fn issue_document(
+        &self,
+        issuance: CertificateIssuance,
+    ) -> Result<CertificateDocument, RusaintError> {
+        Ok(self.0.write().await.issue_document(&issuance).await?)
+    }
+}
+
+/// [`CertificateApplication`] 생성을 위한 빌더
+#[derive(uniffi::Object)]
+pub struct CertificateApplicationBuilder {}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl CertificateApplicationBuilder {
+    /// 새로운 [`CertificateApplicationBuilder`]를 만듭니다.
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// 세션과 함께 [`CertificateApplication`]을 만듭니다.
+    pub async fn build(
+        &self,
+        session: Arc<USaintSession>,
+    ) -> Result<CertificateApplication, RusaintError> {
+        let original_builder =
+            rusaint::application::USaintClientBuilder::new().session(session.original());
+        let original_app = original_builder
+            .build_into::<rusaint::application::certificate::CertificateApplication>()
+            .await?;
+        Ok(CertificateApplication(RwLock::new(original_app)))
+    }
+}
+
+impl Default for CertificateApplicationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use rusaint::{application::chapel::model::ChapelInformation, model::SemesterType};
+use rusaint::{
+    application::chapel::model::{ChapelAbsenceReason, ChapelAbsenceSubmission, ChapelInformation},
+    model::{Date, SemesterType},
+};
 use tokio::sync::RwLock;
 
 use crate::application::model::YearSemester;
@@ -21,6 +24,27 @@ impl ChapelApplication {
         Ok(self.0.write().await.information(year, semester).await?)
     }
 
+    /// 결석신청 양식에서 선택할 수 있는 결석 사유 목록을 가져옵니다.
+    pub async fn absence_reasons(&self) -> Result<Vec<ChapelAbsenceReason>, RusaintError> {
+        Ok(self.0.read().await.absence_reasons()?)
+    }
+
+    /// 현재 선택된 학기에 채플 결석신청을 제출하고, 서버의 확인 메시지와 제출 후의 결석신청 목록을 반환합니다.
+    pub async fn submit_absence_request(
+        &self,
+        start: Date,
+        end: Date,
+        reason_code: String,
+        detail: String,
+    ) -> Result<ChapelAbsenceSubmission, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .submit_absence_request(start, end, &reason_code, &detail)
+            .await?)
+    }
+
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
     pub async fn get_selected_semester(&self) -> Result<YearSemester, RusaintError> {
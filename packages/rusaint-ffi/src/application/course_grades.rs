@@ -1,7 +1,9 @@
 use std::{collections::HashMap, sync::Arc};
 
 use rusaint::{
-    application::course_grades::model::{ClassGrade, CourseType, GradeSummary, SemesterGrade},
+    application::course_grades::model::{
+        ClassGrade, CourseType, GradeObjection, GradeSummary, PortalAccount, SemesterGrade,
+    },
     model::SemesterType,
 };
 use tokio::sync::RwLock;
@@ -17,6 +19,11 @@ pub struct CourseGradesApplication(
 
 #[uniffi::export(async_runtime = "tokio")]
 impl CourseGradesApplication {
+    /// 기본으로 선택된 학위과정과 학위과정 목록으로 로그인한 계정의 포털 역할을 판단합니다.
+    pub async fn account(&self) -> Result<PortalAccount, RusaintError> {
+        Ok(self.0.read().await.account()?)
+    }
+
     /// 전체 학기의 학적부 평점 정보를 가져옵니다.
     pub async fn recorded_summary(
         &self,
@@ -81,6 +88,21 @@ impl CourseGradesApplication {
             .await?)
     }
 
+    /// 주어진 학기의 성적 이의신청 내역과 처리 결과를 가져옵니다.
+    pub async fn grade_objections(
+        &self,
+        course_type: CourseType,
+        year: u32,
+        semester: SemesterType,
+    ) -> Result<Vec<GradeObjection>, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .grade_objections(course_type, year, semester)
+            .await?)
+    }
+
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
     pub async fn get_selected_semester(&self) -> Result<YearSemester, RusaintError> {
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use crate::{error::RusaintError, session::USaintSession};
+use rusaint::application::course_registration::model::{RegisteredLectures, RegistrationLecture};
+use tokio::sync::RwLock;
+
+/// [수강신청](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW2100)
+#[derive(uniffi::Object)]
+pub struct CourseRegistrationApplication(
+    RwLock<rusaint::application::course_registration::CourseRegistrationApplication>,
+);
+
+#[uniffi::export(async_runtime = "tokio")]
+impl CourseRegistrationApplication {
+    /// 장바구니에 담긴 강의 목록을 가져옵니다.
+    pub async fn cart(&self) -> Result<Vec<RegistrationLecture>, RusaintError> {
+        Ok(self.0.write().await.cart().await?)
+    }
+
+    /// 현재 수강신청한 강의 목록과 학점 정보를 가져옵니다.
+    pub async fn registered(&self) -> Result<RegisteredLectures, RusaintError> {
+        Ok(self.0.write().await.registered().await?)
+    }
+
+    /// 과목번호와 분반을 직접 입력하여 강의를 신청하고, 신청 후의 수강신청 내역을 반환합니다.
+    pub async fn enroll(
+        &self,
+        code: String,
+        division: Option<String>,
+    ) -> Result<RegisteredLectures, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .enroll(&code, division.as_deref())
+            .await?)
+    }
+
+    /// 장바구니에 담긴 강의를 신청하고, 신청 후의 수강신청 내역을 반환합니다.
+    pub async fn enroll_from_cart(
+        &self,
+        code: String,
+        division: Option<String>,
+    ) -> Result<RegisteredLectures, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .enroll_from_cart(&code, division.as_deref())
+            .await?)
+    }
+
+    /// 신청한 강의를 취소하고, 취소 후의 수강신청 내역을 반환합니다.
+    pub async fn drop_lecture(
+        &self,
+        code: String,
+        division: Option<String>,
+    ) -> Result<RegisteredLectures, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .drop_lecture(&code, division.as_deref())
+            .await?)
+    }
+}
+
+/// [`CourseRegistrationApplication`] 생성을 위한 빌더
+#[derive(uniffi::Object)]
+pub struct CourseRegistrationApplicationBuilder {}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl CourseRegistrationApplicationBuilder {
+    /// 새로운 [`CourseRegistrationApplicationBuilder`]를 만듭니다.
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// 세션과 함께 [`CourseRegistrationApplication`]을 만듭니다.
+    pub async fn build(
+        &self,
+        session: Arc<USaintSession>,
+    ) -> Result<CourseRegistrationApplication, RusaintError> {
+        let original_builder =
+            rusaint::application::USaintClientBuilder::new().session(session.original());
+        let original_app = original_builder
+            .build_into::<rusaint::application::course_registration::CourseRegistrationApplication>(
+            )
+            .await?;
+        Ok(CourseRegistrationApplication(RwLock::new(original_app)))
+    }
+}
+
+impl Default for CourseRegistrationApplicationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
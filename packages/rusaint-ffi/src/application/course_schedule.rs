@@ -1,7 +1,9 @@
 use std::sync::Arc;
 
 use rusaint::{
-    application::course_schedule::model::{Lecture, LectureCategory},
+    application::course_schedule::model::{
+        Lecture, LectureCategory, LectureCategoryTree, Syllabus,
+    },
     model::SemesterType,
 };
 use tokio::sync::RwLock;
@@ -33,6 +35,20 @@ impl CourseScheduleApplication {
             .collect())
     }
 
+    /// 선택한 학기에 검색할 수 있는 모든 강의 분류 선택지를 가져옵니다.
+    pub async fn category_tree(
+        &self,
+        year: u32,
+        semester: SemesterType,
+    ) -> Result<LectureCategoryTree, RusaintError> {
+        Ok(self.0.write().await.category_tree(year, semester).await?)
+    }
+
+    /// 검색한 강의의 강의계획서를 가져옵니다. 강의는 마지막으로 검색한 결과에 포함되어 있어야 합니다.
+    pub async fn lecture_syllabus(&self, lecture: Lecture) -> Result<Syllabus, RusaintError> {
+        Ok(self.0.write().await.lecture_syllabus(&lecture).await?)
+    }
+
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
     pub async fn get_selected_semester(&self) -> Result<YearSemester, RusaintError> {
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use rusaint::application::lecture_assessment_submission::model::{
+    AssessmentAnswer, AssessmentQuestion, PendingAssessment,
+};
+use tokio::sync::RwLock;
+
+use crate::{error::RusaintError, session::USaintSession};
+
+/// [강의평가입력](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMB2W1020)
+#[derive(uniffi::Object)]
+pub struct LectureAssessmentSubmissionApplication(
+    RwLock<
+        rusaint::application::lecture_assessment_submission::LectureAssessmentSubmissionApplication,
+    >,
+);
+
+#[uniffi::export(async_runtime = "tokio")]
+impl LectureAssessmentSubmissionApplication {
+    /// 강의평가 대상 강의 목록을 가져옵니다. 이미 평가를 완료한 강의도 포함됩니다.
+    pub async fn pending_assessments(&self) -> Result<Vec<PendingAssessment>, RusaintError> {
+        Ok(self.0.write().await.pending_assessments().await?)
+    }
+
+    /// 주어진 강의의 강의평가 설문 문항을 가져옵니다.
+    pub async fn questionnaire(
+        &self,
+        assessment: PendingAssessment,
+    ) -> Result<Vec<AssessmentQuestion>, RusaintError> {
+        Ok(self.0.write().await.questionnaire(&assessment).await?)
+    }
+
+    /// 주어진 강의의 강의평가를 제출하고, 제출 후의 강의평가 대상 강의 목록을 반환합니다.
+    pub async fn submit(
+        &self,
+        assessment: PendingAssessment,
+        answers: Vec<AssessmentAnswer>,
+    ) -> Result<Vec<PendingAssessment>, RusaintError> {
+        Ok(self.0.write().await.submit(&assessment, &answers).await?)
+    }
+}
+
+/// [`LectureAssessmentSubmissionApplication`] 생성을 위한 빌더
+#[derive(uniffi::Object)]
+pub struct LectureAssessmentSubmissionApplicationBuilder {}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl LectureAssessmentSubmissionApplicationBuilder {
+    /// 새로운 [`LectureAssessmentSubmissionApplicationBuilder`]를 만듭니다.
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// 세션과 함께 [`LectureAssessmentSubmissionApplication`]을 만듭니다.
+    pub async fn build(
+        &self,
+        session: Arc<USaintSession>,
+    ) -> Result<LectureAssessmentSubmissionApplication, RusaintError> {
+        let original_builder =
+            rusaint::application::USaintClientBuilder::new().session(session.original());
+        let original_app = original_builder
+            .build_into::<rusaint::application::lecture_assessment_submission::LectureAssessmentSubmissionApplication>()
+            .await?;
+        Ok(LectureAssessmentSubmissionApplication(RwLock::new(
+            original_app,
+        )))
+    }
+}
+
+impl Default for LectureAssessmentSubmissionApplicationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -19,8 +19,32 @@ pub mod personal_course_schedule;
 /// 강의평가 조회: [`LectureAssessmentApplication`](lecture_assessment::LectureAssessmentApplication)
 pub mod lecture_assessment;
 
-/// 장학금 수혜내역 조회: [`ScholarshipsApplication`](scholarships::ScholarshipsApplication)
+/// 강의평가 입력: [`LectureAssessmentSubmissionApplication`](lecture_assessment_submission::LectureAssessmentSubmissionApplication)
+pub mod lecture_assessment_submission;
+
+/// 장학금 수혜내역 및 신청 현황 조회: [`ScholarshipsApplication`](scholarships::ScholarshipsApplication)
 pub mod scholarships;
 
+/// 수강신청: [`CourseRegistrationApplication`](course_registration::CourseRegistrationApplication)
+pub mod course_registration;
+
+/// 등록금 고지 및 납부 내역 조회: [`TuitionApplication`](tuition::TuitionApplication)
+pub mod tuition;
+
+/// 학적변동 신청 및 내역 조회: [`AcademicStatusApplication`](academic_status::AcademicStatusApplication)
+pub mod academic_status;
+
+/// 증명서 발급 내역 조회 및 출력: [`CertificateApplication`](certificate::CertificateApplication)
+pub mod certificate;
+
+/// 다전공 신청 현황 조회: [`MultipleMajorApplication`](multiple_major::MultipleMajorApplication)
+pub mod multiple_major;
+
+/// 지도교수 및 상담 내역 조회: [`AdvisingApplication`](advising::AdvisingApplication)
+pub mod advising;
+
+/// 학사일정 조회: [`AcademicCalendarApplication`](academic_calendar::AcademicCalendarApplication)
+pub mod academic_calendar;
+
 /// 플랫폼 지원을 위한 데이터
 pub mod model;
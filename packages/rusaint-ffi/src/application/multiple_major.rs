@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use rusaint::application::multiple_major::model::{
+    MajorApplication, MajorApplicationWindow, MajorEligibilityCriterion, MajorProgram,
+};
+use tokio::sync::RwLock;
+
+use crate::{error::RusaintError, session::USaintSession};
+
+/// [다전공신청](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW2440)
+#[derive(uniffi::Object)]
+pub struct MultipleMajorApplication(
+    RwLock<rusaint::application::multiple_major::MultipleMajorApplication>,
+);
+
+#[uniffi::export(async_runtime = "tokio")]
+impl MultipleMajorApplication {
+    /// 현재 다전공 신청 기간을 가져옵니다. 신청 기간이 아니라면 `None`을 반환합니다.
+    pub async fn application_window(&self) -> Result<Option<MajorApplicationWindow>, RusaintError> {
+        Ok(self.0.write().await.application_window().await?)
+    }
+
+    /// 현재 신청 기간에 신청 가능한 다전공 과정 목록을 가져옵니다.
+    pub async fn programs(&self) -> Result<Vec<MajorProgram>, RusaintError> {
+        Ok(self.0.write().await.programs().await?)
+    }
+
+    /// 학생의 다전공 신청 내역을 가져옵니다.
+    pub async fn applications(&self) -> Result<Vec<MajorApplication>, RusaintError> {
+        Ok(self.0.write().await.applications().await?)
+    }
+
+    /// 페이지에 표시된 다전공 신청 자격 기준과 학생의 충족 여부를 가져옵니다.
+    pub async fn eligibility(&self) -> Result<Vec<MajorEligibilityCriterion>, RusaintError> {
+        Ok(self.0.write().await.eligibility().await?)
+    }
+}
+
+/// [`MultipleMajorApplication`] 생성을 위한 빌더
+#[derive(uniffi::Object)]
+pub struct MultipleMajorApplicationBuilder {}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl MultipleMajorApplicationBuilder {
+    /// 새로운 [`MultipleMajorApplicationBuilder`]를 만듭니다.
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// 세션과 함께 [`MultipleMajorApplication`]을 만듭니다.
+    pub async fn build(
+        &self,
+        session: Arc<USaintSession>,
+    ) -> Result<MultipleMajorApplication, RusaintError> {
+        let original_builder =
+            rusaint::application::USaintClientBuilder::new().session(session.original());
+        let original_app = original_builder
+            .build_into::<rusaint::application::multiple_major::MultipleMajorApplication>()
+            .await?;
+        Ok(MultipleMajorApplication(RwLock::new(original_app)))
+    }
+}
+
+impl Default for MultipleMajorApplicationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,8 +2,10 @@ use std::sync::Arc;
 
 use crate::application::model::YearSemester;
 use crate::{error::RusaintError, session::USaintSession};
+use rusaint::application::chapel::model::GeneralChapelInformation;
+use rusaint::application::personal_course_schedule::ics::ScheduleCalendar;
 use rusaint::application::personal_course_schedule::model::PersonalCourseSchedule;
-use rusaint::model::SemesterType;
+use rusaint::model::{Date, SemesterType};
 use tokio::sync::RwLock;
 
 /// [개인수업시간표](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW2102)
@@ -31,6 +33,24 @@ impl PersonalCourseScheduleApplication {
     }
 }
 
+/// 개인 시간표와 채플 정보를 학기 시작일부터 종료일까지 반복되는 iCalendar(.ics) 문자열로 변환합니다.
+/// 일정의 UID는 주어진 학번을 바탕으로 만들어집니다.
+#[uniffi::export]
+pub fn personal_course_schedule_to_ics(
+    student_id: String,
+    schedule: PersonalCourseSchedule,
+    chapel: Option<GeneralChapelInformation>,
+    start: Date,
+    end: Date,
+) -> String {
+    let mut calendar = ScheduleCalendar::new(&student_id, start, end);
+    calendar.add_course_schedule(&schedule);
+    if let Some(chapel) = chapel {
+        calendar.add_chapel(&chapel);
+    }
+    calendar.to_ics()
+}
+
 /// [`PersonalCourseScheduleApplication`] 생성을 위한 빌더
 #[derive(uniffi::Object)]
 pub struct PersonalCourseScheduleApplicationBuilder {}
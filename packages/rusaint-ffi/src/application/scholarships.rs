@@ -1,7 +1,9 @@
 use std::sync::Arc;
 
 use crate::{error::RusaintError, session::USaintSession};
-use rusaint::application::scholarships::model::Scholarship;
+use rusaint::application::scholarships::model::{
+    OpenScholarship, Scholarship, ScholarshipApplication, ScholarshipDocument,
+};
 use tokio::sync::RwLock;
 
 /// [장학금수혜내역조회](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW7530n)
@@ -16,6 +18,21 @@ impl ScholarshipsApplication {
     pub async fn scholarships(&self) -> Result<Vec<Scholarship>, RusaintError> {
         Ok(self.0.write().await.scholarships().await?)
     }
+
+    /// 현재 신청 가능한 장학금 목록을 가져옵니다.
+    pub async fn open_scholarships(&self) -> Result<Vec<OpenScholarship>, RusaintError> {
+        Ok(self.0.write().await.open_scholarships().await?)
+    }
+
+    /// 학생이 신청한 장학금과 선발 상태를 가져옵니다.
+    pub async fn applications(&self) -> Result<Vec<ScholarshipApplication>, RusaintError> {
+        Ok(self.0.write().await.applications().await?)
+    }
+
+    /// 장학금 신청에 필요한 제출 서류 목록을 가져옵니다.
+    pub async fn required_documents(&self) -> Result<Vec<ScholarshipDocument>, RusaintError> {
+        Ok(self.0.write().await.required_documents().await?)
+    }
 }
 
 /// [`ScholarshipsApplication`] 생성을 위한 빌더
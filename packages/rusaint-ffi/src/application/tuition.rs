@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use rusaint::{
+    application::tuition::model::{TuitionBill, TuitionPayment},
+    model::SemesterType,
+};
+use tokio::sync::RwLock;
+
+use crate::application::model::YearSemester;
+use crate::{error::RusaintError, session::USaintSession};
+
+/// [등록금고지서조회](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW7710)
+#[derive(uniffi::Object)]
+pub struct TuitionApplication(RwLock<rusaint::application::tuition::TuitionApplication>);
+
+#[uniffi::export(async_runtime = "tokio")]
+impl TuitionApplication {
+    /// 해당 학기의 등록금 고지 정보를 가져옵니다.
+    pub async fn bill(
+        &self,
+        year: u32,
+        semester: SemesterType,
+    ) -> Result<TuitionBill, RusaintError> {
+        Ok(self.0.write().await.bill(year, semester).await?)
+    }
+
+    /// 등록금 납부 내역을 가져옵니다.
+    pub async fn payments(&self) -> Result<Vec<TuitionPayment>, RusaintError> {
+        Ok(self.0.write().await.payments().await?)
+    }
+
+    /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
+    /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
+    pub async fn get_selected_semester(&self) -> Result<YearSemester, RusaintError> {
+        let (year, semester) = self.0.read().await.get_selected_semester()?;
+        Ok(YearSemester::new(year, semester))
+    }
+}
+
+/// [`TuitionApplication`] 생성을 위한 빌더
+#[derive(uniffi::Object)]
+pub struct TuitionApplicationBuilder {}
+
+#[uniffi::export(async_runtime = "tokio")]
+impl TuitionApplicationBuilder {
+    /// 새로운 [`TuitionApplicationBuilder`]를 만듭니다.
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// 세션과 함께 [`TuitionApplication`]을 만듭니다.
+    pub async fn build(
+        &self,
+        session: Arc<USaintSession>,
+    ) -> Result<TuitionApplication, RusaintError> {
+        let original_builder =
+            rusaint::application::USaintClientBuilder::new().session(session.original());
+        let original_app = original_builder
+            .build_into::<rusaint::application::tuition::TuitionApplication>()
+            .await?;
+        Ok(TuitionApplication(RwLock::new(original_app)))
+    }
+}
+
+impl Default for TuitionApplicationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,101 @@
+use self::model::{AcademicEvent, semester_at};
+use super::{USaintApplication, USaintClient};
+use crate::application::utils::sap_table::{is_empty_table, try_table_into_with_scroll};
+use crate::webdynpro::command::WebDynproCommandExecutor;
+use crate::webdynpro::element::parser::ElementParser;
+use crate::{
+    RusaintError, define_elements,
+    error::ApplicationError,
+    model::{Date, SemesterType},
+    webdynpro::{
+        client::body::Body,
+        command::element::{
+            action::ButtonPressEventCommand,
+            selection::{ComboBoxLSDataCommand, ComboBoxSelectEventCommand},
+        },
+        element::{action::Button, complex::SapTable, selection::ComboBox},
+    },
+};
+
+/// [학사일정조회](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW9010)
+#[derive(Debug)]
+pub struct AcademicCalendarApplication {
+    client: USaintClient,
+}
+
+impl USaintApplication for AcademicCalendarApplication {
+    const APP_NAME: &'static str = "ZCMW9010";
+
+    fn from_client(client: USaintClient) -> Result<Self, RusaintError> {
+        if client.name() != Self::APP_NAME {
+            Err(RusaintError::InvalidClientError)
+        } else {
+            Ok(Self { client })
+        }
+    }
+}
+
+impl<'a> AcademicCalendarApplication {
+    define_elements! {
+        SEL_PERYR: ComboBox<'a> = "ZCMW9010.ID_0001:VIW_MAIN.PERYR";
+        BTN_SEL: Button<'a> = "ZCMW9010.ID_0001:VIW_MAIN.BTN_SEL";
+        TABLE: SapTable<'a> = "ZCMW9010.ID_0001:VIW_MAIN.TABLE";
+    }
+
+    fn body(&self) -> &Body {
+        self.client.body()
+    }
+
+    async fn select_year(&mut self, year: &str) -> Result<(), RusaintError> {
+        let parser = ElementParser::new(self.body());
+        let year_combobox_lsdata = parser.read(ComboBoxLSDataCommand::new(Self::SEL_PERYR))?;
+        if year_combobox_lsdata.key().map(String::as_str) != Some(year) {
+            let year_select_event = parser.read(ComboBoxSelectEventCommand::new(
+                Self::SEL_PERYR,
+                year,
+                false,
+            ))?;
+            self.client.process_event(false, year_select_event).await?;
+        }
+        let button_press_event = parser.read(ButtonPressEventCommand::new(Self::BTN_SEL))?;
+        self.client.process_event(false, button_press_event).await?;
+        Ok(())
+    }
+
+    /// 해당 학년도의 학사일정을 가져옵니다.
+    pub async fn events(&mut self, year: u32) -> Result<Vec<AcademicEvent>, RusaintError> {
+        self.select_year(&year.to_string()).await?;
+        let parser = ElementParser::new(self.body());
+        if is_empty_table(&parser, Self::TABLE)? {
+            return Ok(Vec::new());
+        }
+        let events =
+            try_table_into_with_scroll::<AcademicEvent>(&mut self.client, parser, Self::TABLE)
+                .await?;
+        Ok(events
+            .into_iter()
+            .map(|event| event.with_year(year))
+            .collect())
+    }
+
+    /// 학사일정을 기준으로 주어진 날짜의 학년도와 학기를 반환합니다.
+    /// 여름·겨울학기는 기간 중일 때에만 반환하며, 그 외에는 주어진 날짜 이전에 가장 최근에 시작된 1·2학기를 반환합니다.
+    /// 다른 애플리케이션의 `get_selected_semester()`와 달리 페이지의 선택 상태에 영향을 받지 않습니다.
+    pub async fn current_semester(
+        &mut self,
+        today: Date,
+    ) -> Result<(u32, SemesterType), RusaintError> {
+        let year = u32::try_from(today.year()).map_err(|_| ApplicationError::NoAcademicCalendar)?;
+        let mut events = self.events(year).await?;
+        // 1, 2월은 이전 학년도의 겨울학기 중일 수 있으므로 이전 학년도의 학사일정도 확인
+        if today.month() < 3 || semester_at(&events, today).is_none() {
+            if let Some(previous_year) = year.checked_sub(1) {
+                events.extend(self.events(previous_year).await?);
+            }
+        }
+        Ok(semester_at(&events, today).ok_or(ApplicationError::NoAcademicCalendar)?)
+    }
+}
+
+/// [`AcademicCalendarApplication`] 애플리케이션에 사용되는 데이터
+pub mod model;
@@ -0,0 +1,178 @@
+use std::collections::HashMap;
+
+use serde::{
+    Deserialize, Serialize,
+    de::{IntoDeserializer, value::MapDeserializer},
+};
+
+use crate::application::utils::de_with::{deserialize_date, deserialize_optional_semester_type};
+use crate::model::{Date, SemesterType};
+use crate::webdynpro::{
+    element::{
+        complex::sap_table::{FromSapTable, SapTableHeader, SapTableRow},
+        definition::ElementDefinition,
+        parser::ElementParser,
+    },
+    error::{ElementError, WebDynproError},
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 학사일정
+pub struct AcademicEvent {
+    #[serde(skip_deserializing)]
+    year: u32,
+    #[serde(
+        rename(deserialize = "학기"),
+        default,
+        deserialize_with = "deserialize_optional_semester_type"
+    )]
+    semester: Option<SemesterType>,
+    #[serde(rename(deserialize = "일정명"))]
+    title: String,
+    #[serde(rename(deserialize = "시작일"), deserialize_with = "deserialize_date")]
+    start: Date,
+    #[serde(rename(deserialize = "종료일"), deserialize_with = "deserialize_date")]
+    end: Date,
+}
+
+impl AcademicEvent {
+    pub(crate) fn with_year(mut self, year: u32) -> Self {
+        self.year = year;
+        self
+    }
+
+    /// 일정이 속한 학년도를 반환합니다.
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 일정이 속한 학기를 반환합니다. 특정 학기에 속하지 않는 일정이라면 `None`을 반환합니다.
+    pub fn semester(&self) -> Option<SemesterType> {
+        self.semester
+    }
+
+    /// 일정명을 반환합니다.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// 일정 시작일을 반환합니다.
+    pub fn start(&self) -> Date {
+        self.start
+    }
+
+    /// 일정 종료일을 반환합니다.
+    pub fn end(&self) -> Date {
+        self.end
+    }
+
+    /// 주어진 날짜가 일정 기간에 포함되는지 여부를 반환합니다.
+    pub fn contains(&self, date: Date) -> bool {
+        self.start <= date && date <= self.end
+    }
+}
+
+impl<'body> FromSapTable<'body> for AcademicEvent {
+    fn from_table(
+        header: &'body SapTableHeader,
+        row: &'body SapTableRow,
+        parser: &'body ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let map_string = row.try_row_into::<HashMap<String, String>>(header, parser)?;
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map_string.into_deserializer();
+        Ok(
+            Self::deserialize(map_de).map_err(|e| ElementError::InvalidContent {
+                element: row.table_def().id().to_string(),
+                content: e.to_string(),
+            })?,
+        )
+    }
+}
+
+/// 학사일정으로부터 주어진 날짜의 학기를 찾습니다.
+///
+/// 각 학기의 일정 중 가장 이른 시작일과 가장 늦은 종료일을 학기의 기간으로 봅니다.
+/// 여름·겨울학기는 기간 중일 때에만 현재 학기로 보며, 그 외에는 주어진 날짜 이전에 가장 최근에 시작된 1·2학기를 반환합니다.
+pub(crate) fn semester_at(events: &[AcademicEvent], date: Date) -> Option<(u32, SemesterType)> {
+    let mut spans: HashMap<(u32, SemesterType), (Date, Date)> = HashMap::new();
+    for event in events {
+        let Some(semester) = event.semester else {
+            continue;
+        };
+        spans
+            .entry((event.year, semester))
+            .and_modify(|(start, end)| {
+                *start = (*start).min(event.start);
+                *end = (*end).max(event.end);
+            })
+            .or_insert((event.start, event.end));
+    }
+    let is_session =
+        |semester: SemesterType| matches!(semester, SemesterType::Summer | SemesterType::Winter);
+    let latest = |sessions: bool| {
+        spans
+            .iter()
+            .filter(|((_, semester), (start, end))| {
+                is_session(*semester) == sessions && *start <= date && (!sessions || date <= *end)
+            })
+            .max_by_key(|(_, (start, _))| *start)
+            .map(|(key, _)| *key)
+    };
+    latest(true).or_else(|| latest(false))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AcademicEvent, semester_at};
+    use crate::model::{Date, SemesterType};
+
+    fn event(year: u32, semester: SemesterType, start: &str, end: &str) -> AcademicEvent {
+        AcademicEvent {
+            year,
+            semester: Some(semester),
+            title: String::new(),
+            start: start.parse().unwrap(),
+            end: end.parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn find_semester_at_date() {
+        let events = vec![
+            event(2024, SemesterType::Two, "2024-08-12", "2024-12-20"),
+            event(2024, SemesterType::Winter, "2024-12-23", "2025-01-20"),
+            event(2025, SemesterType::One, "2025-03-04", "2025-06-20"),
+            event(2025, SemesterType::One, "2025-02-10", "2025-02-14"),
+            event(2025, SemesterType::Summer, "2025-06-23", "2025-07-18"),
+        ];
+        let at = |date: &str| semester_at(&events, date.parse::<Date>().unwrap());
+        assert_eq!(at("2024-10-01"), Some((2024, SemesterType::Two)));
+        assert_eq!(at("2025-02-12"), Some((2025, SemesterType::One)));
+        assert_eq!(at("2025-07-01"), Some((2025, SemesterType::Summer)));
+        assert_eq!(at("2025-08-01"), Some((2025, SemesterType::One)));
+        assert_eq!(at("2024-01-01"), None);
+    }
+
+    #[test]
+    fn find_semester_across_year_boundary() {
+        let events = vec![
+            event(2024, SemesterType::Two, "2024-08-12", "2024-12-20"),
+            event(2024, SemesterType::Winter, "2024-12-23", "2025-01-20"),
+            // 다음 학년도 1학기의 복학 신청 등은 겨울학기 도중에 시작됨
+            event(2025, SemesterType::One, "2025-01-06", "2025-01-10"),
+            event(2025, SemesterType::One, "2025-03-04", "2025-06-20"),
+        ];
+        let at = |date: &str| semester_at(&events, date.parse::<Date>().unwrap());
+        assert_eq!(at("2024-12-22"), Some((2024, SemesterType::Two)));
+        assert_eq!(at("2024-12-31"), Some((2024, SemesterType::Winter)));
+        assert_eq!(at("2025-01-02"), Some((2024, SemesterType::Winter)));
+        assert_eq!(at("2025-01-08"), Some((2024, SemesterType::Winter)));
+        assert_eq!(at("2025-01-21"), Some((2025, SemesterType::One)));
+        let without_next_year = &events[..2];
+        assert_eq!(
+            semester_at(without_next_year, "2025-01-21".parse().unwrap()),
+            Some((2024, SemesterType::Two))
+        );
+    }
+}
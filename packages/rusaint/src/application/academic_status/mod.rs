@@ -0,0 +1,151 @@
+use self::model::{AcademicStatusRequest, AcademicStatusRequestKind};
+use super::{USaintApplication, USaintClient};
+use crate::application::utils::popup::{close_popups, confirm_popup, popup_error};
+use crate::application::utils::sap_table::{is_empty_table, try_table_into_with_scroll};
+use crate::webdynpro::command::WebDynproCommandExecutor;
+use crate::webdynpro::element::parser::ElementParser;
+use crate::{
+    RusaintError, define_elements,
+    error::ApplicationError,
+    model::SemesterType,
+    webdynpro::{
+        client::body::Body,
+        command::element::{
+            action::ButtonPressEventCommand, selection::ComboBoxSelectEventCommand,
+            text::InputFieldChangeEventCommand,
+        },
+        element::{action::Button, complex::SapTable, selection::ComboBox, text::InputField},
+    },
+};
+
+/// [학적변동신청](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW1030)
+#[derive(Debug)]
+pub struct AcademicStatusApplication {
+    client: USaintClient,
+}
+
+impl USaintApplication for AcademicStatusApplication {
+    const APP_NAME: &'static str = "ZCMW1030";
+
+    fn from_client(client: USaintClient) -> Result<Self, RusaintError> {
+        if client.name() != Self::APP_NAME {
+            Err(RusaintError::InvalidClientError)
+        } else {
+            Ok(Self { client })
+        }
+    }
+}
+
+impl<'a> AcademicStatusApplication {
+    define_elements! {
+        // 신청 내역 표
+        TABLE_REQUESTS: SapTable<'a> = "ZCMW1030.ID_0001:VIW_MAIN.TABLE";
+        // 신청구분
+        SEL_KIND: ComboBox<'a> = "ZCMW1030.ID_0001:VIW_MAIN.DD_STATUS";
+        // 신청 학년도
+        SEL_PERYR: ComboBox<'a> = "ZCMW1030.ID_0001:VIW_MAIN.PERYR";
+        // 신청 학기
+        SEL_PERID: ComboBox<'a> = "ZCMW1030.ID_0001:VIW_MAIN.PERID";
+        // 신청 사유
+        INPUT_REASON: InputField<'a> = "ZCMW1030.ID_0001:VIW_MAIN.REASON";
+        // 신청 버튼
+        BTN_APPLY: Button<'a> = "ZCMW1030.ID_0001:VIW_MAIN.BTN_APPLY";
+    }
+
+    const CONFIRM_LABELS: [&'static str; 2] = ["예", "확인"];
+
+    fn semester_to_key(semester: SemesterType) -> &'static str {
+        match semester {
+            SemesterType::One => "090",
+            SemesterType::Summer => "091",
+            SemesterType::Two => "092",
+            SemesterType::Winter => "093",
+        }
+    }
+
+    fn kind_to_key(kind: AcademicStatusRequestKind) -> Option<&'static str> {
+        match kind {
+            AcademicStatusRequestKind::LeaveOfAbsence => Some("10"),
+            AcademicStatusRequestKind::Return => Some("20"),
+            AcademicStatusRequestKind::Withdrawal => Some("30"),
+            AcademicStatusRequestKind::Other => None,
+        }
+    }
+
+    fn body(&self) -> &Body {
+        self.client.body()
+    }
+
+    /// 휴학, 복학, 자퇴 등 학적변동 신청 내역과 처리 상태를 가져옵니다.
+    pub async fn requests(&mut self) -> Result<Vec<AcademicStatusRequest>, RusaintError> {
+        close_popups(&mut self.client).await?;
+        let parser = ElementParser::new(self.body());
+        if is_empty_table(&parser, Self::TABLE_REQUESTS)? {
+            return Ok(Vec::new());
+        }
+        Ok(try_table_into_with_scroll::<AcademicStatusRequest>(
+            &mut self.client,
+            parser,
+            Self::TABLE_REQUESTS,
+        )
+        .await?)
+    }
+
+    /// 새로운 학적변동을 신청하고, 신청 후의 신청 내역을 반환합니다.
+    /// 신청 확인 질문이 표시되면 확인을 누르며, 신청이 거부되어 오류 메시지가 표시되면 [`ApplicationError::RequestRejected`]를 반환합니다.
+    pub async fn submit(
+        &mut self,
+        kind: AcademicStatusRequestKind,
+        year: u32,
+        semester: SemesterType,
+        reason: &str,
+    ) -> Result<Vec<AcademicStatusRequest>, RusaintError> {
+        let Some(kind_key) = Self::kind_to_key(kind) else {
+            return Err(ApplicationError::RequestRejected(format!(
+                "Unsupported request kind: {kind:?}"
+            ))
+            .into());
+        };
+        close_popups(&mut self.client).await?;
+        let events = {
+            let parser = ElementParser::new(self.body());
+            [
+                parser.read(ComboBoxSelectEventCommand::new(
+                    Self::SEL_KIND,
+                    kind_key,
+                    false,
+                ))?,
+                parser.read(ComboBoxSelectEventCommand::new(
+                    Self::SEL_PERYR,
+                    &year.to_string(),
+                    false,
+                ))?,
+                parser.read(ComboBoxSelectEventCommand::new(
+                    Self::SEL_PERID,
+                    Self::semester_to_key(semester),
+                    false,
+                ))?,
+                parser.read(InputFieldChangeEventCommand::new(
+                    Self::INPUT_REASON,
+                    reason,
+                ))?,
+            ]
+        };
+        for event in events {
+            self.client.process_event(false, event).await?;
+        }
+        let apply =
+            ElementParser::new(self.body()).read(ButtonPressEventCommand::new(Self::BTN_APPLY))?;
+        self.client.process_event(false, apply).await?;
+        confirm_popup(&mut self.client, &Self::CONFIRM_LABELS).await?;
+        let error = popup_error(&ElementParser::new(self.body()));
+        close_popups(&mut self.client).await?;
+        if let Some(message) = error {
+            return Err(ApplicationError::RequestRejected(message).into());
+        }
+        self.requests().await
+    }
+}
+
+/// [`AcademicStatusApplication`] 애플리케이션에 사용되는 데이터
+pub mod model;
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use serde::{
+    Deserialize, Serialize,
+    de::{IntoDeserializer, value::MapDeserializer},
+};
+
+use crate::application::utils::de_with::deserialize_optional_string;
+use crate::webdynpro::{
+    element::{
+        complex::sap_table::{FromSapTable, SapTableHeader, SapTableRow},
+        definition::ElementDefinition,
+        parser::ElementParser,
+    },
+    error::{ElementError, WebDynproError},
+};
+
+/// 학적변동 신청 종류
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum AcademicStatusRequestKind {
+    /// 휴학
+    LeaveOfAbsence,
+    /// 복학
+    Return,
+    /// 자퇴
+    Withdrawal,
+    /// 기타
+    Other,
+}
+
+impl AcademicStatusRequestKind {
+    pub(crate) fn from_text(text: &str) -> Self {
+        if text.contains("복학") {
+            Self::Return
+        } else if text.contains("휴학") {
+            Self::LeaveOfAbsence
+        } else if text.contains("자퇴") {
+            Self::Withdrawal
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// 학적변동 신청의 처리 상태
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum AcademicStatusRequestState {
+    /// 신청 후 처리 대기 중
+    Pending,
+    /// 승인됨
+    Approved,
+    /// 반려됨
+    Rejected,
+    /// 신청 취소됨
+    Cancelled,
+    /// 알 수 없는 상태
+    Unknown,
+}
+
+impl AcademicStatusRequestState {
+    pub(crate) fn from_text(text: &str) -> Self {
+        if text.contains("취소") {
+            Self::Cancelled
+        } else if text.contains("반려") || text.contains("불허") {
+            Self::Rejected
+        } else if text.contains("승인") || text.contains("완료") {
+            Self::Approved
+        } else if text.contains("신청") || text.contains("대기") || text.contains("진행") {
+            Self::Pending
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 휴학, 복학, 자퇴 등 학적변동 신청 내역
+pub struct AcademicStatusRequest {
+    #[serde(rename(deserialize = "신청일자"))]
+    requested_at: String,
+    #[serde(rename(deserialize = "신청구분"))]
+    category: String,
+    #[serde(rename(deserialize = "학년도"))]
+    year: String,
+    #[serde(rename(deserialize = "학기"))]
+    term: String,
+    #[serde(
+        rename(deserialize = "사유"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    reason: Option<String>,
+    #[serde(rename(deserialize = "진행상태"))]
+    status: String,
+    #[serde(
+        rename(deserialize = "처리일자"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    processed_at: Option<String>,
+}
+
+impl AcademicStatusRequest {
+    /// 신청일자를 반환합니다.
+    pub fn requested_at(&self) -> &str {
+        &self.requested_at
+    }
+
+    /// 신청구분을 반환합니다.
+    pub fn category(&self) -> &str {
+        &self.category
+    }
+
+    /// 신청구분을 [`AcademicStatusRequestKind`]로 반환합니다.
+    pub fn kind(&self) -> AcademicStatusRequestKind {
+        AcademicStatusRequestKind::from_text(&self.category)
+    }
+
+    /// 학년도를 반환합니다.
+    pub fn year(&self) -> &str {
+        &self.year
+    }
+
+    /// 학기를 반환합니다.
+    pub fn term(&self) -> &str {
+        &self.term
+    }
+
+    /// 사유를 반환합니다.
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    /// 진행상태를 반환합니다.
+    pub fn status(&self) -> &str {
+        &self.status
+    }
+
+    /// 진행상태를 [`AcademicStatusRequestState`]로 반환합니다.
+    pub fn state(&self) -> AcademicStatusRequestState {
+        AcademicStatusRequestState::from_text(&self.status)
+    }
+
+    /// 처리일자를 반환합니다.
+    pub fn processed_at(&self) -> Option<&str> {
+        self.processed_at.as_deref()
+    }
+}
+
+impl<'a> FromSapTable<'a> for AcademicStatusRequest {
+    fn from_table(
+        header: &'a SapTableHeader,
+        row: &'a SapTableRow,
+        parser: &'a ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let map_string = row.try_row_into::<HashMap<String, String>>(header, parser)?;
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map_string.into_deserializer();
+        Ok(AcademicStatusRequest::deserialize(map_de).map_err(|e| {
+            ElementError::InvalidContent {
+                element: row.table_def().id().to_string(),
+                content: e.to_string(),
+            }
+        })?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AcademicStatusRequestKind, AcademicStatusRequestState};
+
+    #[test]
+    fn parse_request_texts() {
+        assert_eq!(
+            AcademicStatusRequestKind::from_text("일반휴학"),
+            AcademicStatusRequestKind::LeaveOfAbsence
+        );
+        assert_eq!(
+            AcademicStatusRequestKind::from_text("휴학 후 복학"),
+            AcademicStatusRequestKind::Return
+        );
+        assert_eq!(
+            AcademicStatusRequestState::from_text("신청취소"),
+            AcademicStatusRequestState::Cancelled
+        );
+        assert_eq!(
+            AcademicStatusRequestState::from_text("승인"),
+            AcademicStatusRequestState::Approved
+        );
+        assert_eq!(
+            AcademicStatusRequestState::from_text("신청"),
+            AcademicStatusRequestState::Pending
+        );
+    }
+}
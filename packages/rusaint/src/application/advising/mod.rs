@@ -0,0 +1,146 @@
+use self::model::{Advisor, CounselingRecord, SemesterCounseling};
+use super::{USaintApplication, USaintClient};
+use crate::application::utils::sap_table::{is_empty_table, try_table_into_with_scroll};
+use crate::application::utils::semester::get_selected_semester;
+use crate::webdynpro::command::WebDynproCommandExecutor;
+use crate::webdynpro::element::parser::ElementParser;
+use crate::{
+    RusaintError, define_elements,
+    model::SemesterType,
+    webdynpro::{
+        client::body::Body,
+        command::element::{
+            action::ButtonPressEventCommand,
+            selection::{ComboBoxLSDataCommand, ComboBoxSelectEventCommand},
+        },
+        element::{
+            action::Button,
+            complex::SapTable,
+            selection::{CheckBox, ComboBox},
+        },
+    },
+};
+
+/// [학생상담조회](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW1120)
+#[derive(Debug)]
+pub struct AdvisingApplication {
+    client: USaintClient,
+}
+
+impl USaintApplication for AdvisingApplication {
+    const APP_NAME: &'static str = "ZCMW1120";
+
+    fn from_client(client: USaintClient) -> Result<Self, RusaintError> {
+        if client.name() != Self::APP_NAME {
+            Err(RusaintError::InvalidClientError)
+        } else {
+            Ok(Self { client })
+        }
+    }
+}
+
+impl<'a> AdvisingApplication {
+    // 아래 ID는 캡처한 ZCMW1120 페이지와 대조하지 못한 추정값이며, 표의 열 구성은 단위 테스트의 픽스처로만 확인함
+    define_elements! {
+        SEL_PERYR: ComboBox<'a> = "ZCMW1120.ID_0001:VIW_MAIN.PERYR";
+        SEL_PERID: ComboBox<'a> = "ZCMW1120.ID_0001:VIW_MAIN.PERID";
+        BTN_SEL: Button<'a> = "ZCMW1120.ID_0001:VIW_MAIN.BTN_SEL";
+        // 필수상담 이수 여부
+        MANDATORY_COMPLETED: CheckBox<'a> = "ZCMW1120.ID_0001:VIW_MAIN.MAND_FLAG";
+        COUNSELING_TABLE: SapTable<'a> = "ZCMW1120.ID_0001:VIW_MAIN.TABLE_CONSULT";
+    }
+
+    fn semester_to_key(semester: SemesterType) -> &'static str {
+        match semester {
+            SemesterType::One => "090",
+            SemesterType::Summer => "091",
+            SemesterType::Two => "092",
+            SemesterType::Winter => "093",
+        }
+    }
+
+    fn body(&self) -> &Body {
+        self.client.body()
+    }
+
+    async fn select_semester(
+        &mut self,
+        year: &str,
+        semester: SemesterType,
+    ) -> Result<(), RusaintError> {
+        let semester = Self::semester_to_key(semester);
+        let parser = ElementParser::new(self.body());
+        let year_combobox_lsdata = parser.read(ComboBoxLSDataCommand::new(Self::SEL_PERYR))?;
+        let semester_combobox_lsdata = parser.read(ComboBoxLSDataCommand::new(Self::SEL_PERID))?;
+        if year_combobox_lsdata.key().map(String::as_str) != Some(year) {
+            let year_select_event = parser.read(ComboBoxSelectEventCommand::new(
+                Self::SEL_PERYR,
+                year,
+                false,
+            ))?;
+            self.client.process_event(false, year_select_event).await?;
+        }
+        if semester_combobox_lsdata.key().map(String::as_str) != Some(semester) {
+            let semester_select_event = parser.read(ComboBoxSelectEventCommand::new(
+                Self::SEL_PERID,
+                semester,
+                false,
+            ))?;
+            self.client
+                .process_event(false, semester_select_event)
+                .await?;
+        }
+        let button_press_event = parser.read(ButtonPressEventCommand::new(Self::BTN_SEL))?;
+        self.client.process_event(false, button_press_event).await?;
+        Ok(())
+    }
+
+    /// 학생의 지도교수 정보를 가져옵니다.
+    pub fn advisor(&self) -> Result<Advisor, RusaintError> {
+        let parser = ElementParser::new(self.body());
+        Advisor::with_parser(&parser)
+    }
+
+    /// 해당 학기의 상담 내역과 필수상담 이수 여부를 가져옵니다.
+    pub async fn counseling(
+        &mut self,
+        year: u32,
+        semester: SemesterType,
+    ) -> Result<SemesterCounseling, RusaintError> {
+        self.select_semester(&year.to_string(), semester).await?;
+        let parser = ElementParser::new(self.body());
+        let mandatory_completed = parser
+            .element_from_def(&Self::MANDATORY_COMPLETED)
+            .ok()
+            .map(|check_box| check_box.checked());
+        let empty = SemesterCounseling::new(year, semester, mandatory_completed, Vec::new());
+        if is_empty_table(&parser, Self::COUNSELING_TABLE)? {
+            return Ok(empty);
+        }
+        let records = try_table_into_with_scroll::<CounselingRecord>(
+            &mut self.client,
+            parser,
+            Self::COUNSELING_TABLE,
+        )
+        .await?;
+        Ok(SemesterCounseling::new(
+            year,
+            semester,
+            mandatory_completed,
+            records,
+        ))
+    }
+
+    /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
+    /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
+    pub fn get_selected_semester(&self) -> Result<(u32, SemesterType), RusaintError> {
+        Ok(get_selected_semester(
+            &self.client,
+            &Self::SEL_PERYR,
+            &Self::SEL_PERID,
+        )?)
+    }
+}
+
+/// [`AdvisingApplication`] 애플리케이션에 사용되는 데이터
+pub mod model;
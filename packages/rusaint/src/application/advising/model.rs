@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+
+use serde::{
+    Deserialize, Serialize,
+    de::{IntoDeserializer, value::MapDeserializer},
+};
+
+use crate::application::utils::de_with::deserialize_optional_string;
+use crate::webdynpro::command::WebDynproCommandExecutor;
+use crate::webdynpro::element::parser::ElementParser;
+use crate::{
+    RusaintError, define_elements,
+    error::ApplicationError,
+    model::SemesterType,
+    webdynpro::{
+        command::element::text::InputFieldValueCommand,
+        element::{
+            complex::sap_table::{FromSapTable, SapTableHeader, SapTableRow},
+            definition::ElementDefinition,
+            text::{InputField, InputFieldDef},
+        },
+        error::{ElementError, WebDynproError},
+    },
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 학생의 지도교수 정보
+pub struct Advisor {
+    name: String,
+    department: Option<String>,
+    email: Option<String>,
+    phone: Option<String>,
+    office: Option<String>,
+}
+
+impl<'a> Advisor {
+    // 캡처한 ZCMW1120 페이지와 대조하지 못한 추정 ID
+    define_elements! {
+        // 지도교수명
+        NAME: InputField<'a> = "ZCMW1120.ID_0001:VIW_MAIN.ADVISOR_NAME";
+        // 소속
+        DEPARTMENT: InputField<'a> = "ZCMW1120.ID_0001:VIW_MAIN.ADVISOR_ORGTX";
+        // 이메일
+        EMAIL: InputField<'a> = "ZCMW1120.ID_0001:VIW_MAIN.ADVISOR_EMAIL";
+        // 연락처
+        PHONE: InputField<'a> = "ZCMW1120.ID_0001:VIW_MAIN.ADVISOR_TEL";
+        // 연구실
+        OFFICE: InputField<'a> = "ZCMW1120.ID_0001:VIW_MAIN.ADVISOR_ROOM";
+    }
+
+    pub(crate) fn with_parser(parser: &ElementParser) -> Result<Self, RusaintError> {
+        let text = |def: InputFieldDef| {
+            parser
+                .read(InputFieldValueCommand::new(def))
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        Ok(Self {
+            name: text(Self::NAME).ok_or(ApplicationError::NoAdvisorInformation)?,
+            department: text(Self::DEPARTMENT),
+            email: text(Self::EMAIL),
+            phone: text(Self::PHONE),
+            office: text(Self::OFFICE),
+        })
+    }
+
+    /// 지도교수명을 반환합니다.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 지도교수의 소속을 반환합니다.
+    pub fn department(&self) -> Option<&str> {
+        self.department.as_deref()
+    }
+
+    /// 지도교수의 이메일 주소를 반환합니다.
+    pub fn email(&self) -> Option<&str> {
+        self.email.as_deref()
+    }
+
+    /// 지도교수의 연락처를 반환합니다.
+    pub fn phone(&self) -> Option<&str> {
+        self.phone.as_deref()
+    }
+
+    /// 지도교수의 연구실 위치를 반환합니다.
+    pub fn office(&self) -> Option<&str> {
+        self.office.as_deref()
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 상담 내역
+pub struct CounselingRecord {
+    #[serde(rename(deserialize = "상담일자"))]
+    date: String,
+    #[serde(
+        rename(deserialize = "상담구분"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    kind: Option<String>,
+    #[serde(
+        rename(deserialize = "상담주제"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    topic: Option<String>,
+    #[serde(
+        rename(deserialize = "상담교수"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    counselor: Option<String>,
+    #[serde(
+        rename(deserialize = "상담내용"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    content: Option<String>,
+}
+
+impl CounselingRecord {
+    /// 상담일자를 반환합니다.
+    pub fn date(&self) -> &str {
+        &self.date
+    }
+
+    /// 상담구분(필수상담, 일반상담 등)을 반환합니다.
+    pub fn kind(&self) -> Option<&str> {
+        self.kind.as_deref()
+    }
+
+    /// 상담주제를 반환합니다.
+    pub fn topic(&self) -> Option<&str> {
+        self.topic.as_deref()
+    }
+
+    /// 상담한 교수명을 반환합니다.
+    pub fn counselor(&self) -> Option<&str> {
+        self.counselor.as_deref()
+    }
+
+    /// 상담내용을 반환합니다.
+    pub fn content(&self) -> Option<&str> {
+        self.content.as_deref()
+    }
+
+    /// 필수상담으로 진행된 상담인지 여부를 반환합니다.
+    pub fn is_mandatory(&self) -> bool {
+        self.kind
+            .as_deref()
+            .is_some_and(|kind| kind.contains("필수"))
+    }
+}
+
+impl<'body> FromSapTable<'body> for CounselingRecord {
+    fn from_table(
+        header: &'body SapTableHeader,
+        row: &'body SapTableRow,
+        parser: &'body ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let map_string = row.try_row_into::<HashMap<String, String>>(header, parser)?;
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map_string.into_deserializer();
+        Ok(
+            Self::deserialize(map_de).map_err(|e| ElementError::InvalidContent {
+                element: row.table_def().id().to_string(),
+                content: e.to_string(),
+            })?,
+        )
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 학기별 상담 내역과 필수상담 이수 여부
+pub struct SemesterCounseling {
+    year: u32,
+    semester: SemesterType,
+    mandatory_completed: bool,
+    records: Vec<CounselingRecord>,
+}
+
+impl SemesterCounseling {
+    pub(crate) fn new(
+        year: u32,
+        semester: SemesterType,
+        mandatory_completed: Option<bool>,
+        records: Vec<CounselingRecord>,
+    ) -> Self {
+        let mandatory_completed = mandatory_completed
+            .unwrap_or_else(|| records.iter().any(CounselingRecord::is_mandatory));
+        Self {
+            year,
+            semester,
+            mandatory_completed,
+            records,
+        }
+    }
+
+    /// 학년도를 반환합니다.
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 학기를 반환합니다.
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 해당 학기의 필수상담을 이수했는지 여부를 반환합니다.
+    pub fn mandatory_completed(&self) -> bool {
+        self.mandatory_completed
+    }
+
+    /// 해당 학기의 상담 내역을 반환합니다.
+    pub fn records(&self) -> &[CounselingRecord] {
+        &self.records
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Advisor, CounselingRecord, SemesterCounseling};
+    use crate::{
+        model::SemesterType,
+        webdynpro::{
+            client::body::{body_fixture, sap_table_fixture},
+            command::{WebDynproCommandExecutor, element::complex::SapTableBodyCommand},
+            element::{complex::SapTableDef, definition::ElementDefinition, parser::ElementParser},
+        },
+    };
+
+    const TABLE: SapTableDef = SapTableDef::new("ZCMW1120.ID_0001:VIW_MAIN.TABLE_CONSULT");
+
+    #[test]
+    fn read_advisor() {
+        let body = body_fixture(concat!(
+            r#"<input id="ZCMW1120.ID_0001:VIW_MAIN.ADVISOR_NAME" ct="I" lsdata="{}" value="홍길동">"#,
+            r#"<input id="ZCMW1120.ID_0001:VIW_MAIN.ADVISOR_ORGTX" ct="I" lsdata="{}" value="컴퓨터학부">"#,
+            r#"<input id="ZCMW1120.ID_0001:VIW_MAIN.ADVISOR_EMAIL" ct="I" lsdata="{}" value=" ">"#,
+        ));
+        let advisor = Advisor::with_parser(&ElementParser::new(&body)).unwrap();
+        assert_eq!(advisor.name(), "홍길동");
+        assert_eq!(advisor.department(), Some("컴퓨터학부"));
+        assert_eq!(advisor.email(), None);
+        assert_eq!(advisor.phone(), None);
+        let body = body_fixture(
+            r#"<input id="ZCMW1120.ID_0001:VIW_MAIN.ADVISOR_NAME" ct="I" lsdata="{}" value="">"#,
+        );
+        assert!(Advisor::with_parser(&ElementParser::new(&body)).is_err());
+    }
+
+    #[test]
+    fn read_counseling_records() {
+        let body = body_fixture(&sap_table_fixture(
+            TABLE.id(),
+            &["상담일자", "상담구분", "상담주제", "상담교수", "상담내용"],
+            &[
+                &[
+                    "2025.03.20",
+                    "필수상담",
+                    "진로",
+                    "홍길동",
+                    "대학원 진학 상담",
+                ],
+                &["2025.04.02", "일반상담", "", "", ""],
+            ],
+        ));
+        let parser = ElementParser::new(&body);
+        let records = parser
+            .read(SapTableBodyCommand::new(TABLE))
+            .unwrap()
+            .try_table_into::<CounselingRecord>(&parser)
+            .unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].date(), "2025.03.20");
+        assert!(records[0].is_mandatory());
+        assert_eq!(records[0].counselor(), Some("홍길동"));
+        assert!(!records[1].is_mandatory());
+        assert_eq!(records[1].topic(), None);
+
+        let counseling = SemesterCounseling::new(2025, SemesterType::One, None, records.clone());
+        assert!(counseling.mandatory_completed());
+        let counseling = SemesterCounseling::new(2025, SemesterType::One, Some(false), records);
+        assert!(!counseling.mandatory_completed());
+    }
+}
@@ -0,0 +1,117 @@
+use self::model::{CertificateDocument, CertificateIssuance, CertificateType};
+use super::{USaintApplication, USaintClient};
+use crate::application::utils::popup::{close_popups, confirm_popup, popup_error};
+use crate::application::utils::sap_table::{
+    is_empty_table, select_row_with_scroll, try_table_into_with_scroll,
+};
+use crate::webdynpro::command::WebDynproCommandExecutor;
+use crate::webdynpro::element::parser::ElementParser;
+use crate::{
+    RusaintError, define_elements,
+    error::ApplicationError,
+    webdynpro::{
+        client::body::Body,
+        command::element::action::ButtonPressEventCommand,
+        element::{
+            action::Button,
+            complex::{SapTable, SapTableDef, sap_table::FromSapTable},
+        },
+    },
+};
+
+/// [증명서발급](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW6010)
+#[derive(Debug)]
+pub struct CertificateApplication {
+    client: USaintClient,
+}
+
+impl USaintApplication for CertificateApplication {
+    const APP_NAME: &'static str = "ZCMW6010";
+
+    fn from_client(client: USaintClient) -> Result<Self, RusaintError> {
+        if client.name() != Self::APP_NAME {
+            Err(RusaintError::InvalidClientError)
+        } else {
+            Ok(Self { client })
+        }
+    }
+}
+
+impl<'a> CertificateApplication {
+    define_elements! {
+        // 발급 가능 증명서 표
+        TABLE_TYPES: SapTable<'a> = "ZCMW6010.ID_0001:VIW_MAIN.TABLE_CERT";
+        // 발급 내역 표
+        TABLE_HISTORY: SapTable<'a> = "ZCMW6010.ID_0001:VIW_MAIN.TABLE_HIST";
+        // 출력 버튼
+        BTN_PRINT: Button<'a> = "ZCMW6010.ID_0001:VIW_MAIN.BTN_PRINT";
+    }
+
+    const CONFIRM_LABELS: [&'static str; 3] = ["예", "확인", "출력"];
+
+    fn body(&self) -> &Body {
+        self.client.body()
+    }
+
+    async fn read_table<T: for<'body> FromSapTable<'body>>(
+        &mut self,
+        table: SapTableDef,
+    ) -> Result<Vec<T>, RusaintError> {
+        close_popups(&mut self.client).await?;
+        let parser = ElementParser::new(self.body());
+        if is_empty_table(&parser, table.clone())? {
+            return Ok(Vec::new());
+        }
+        Ok(try_table_into_with_scroll::<T>(&mut self.client, parser, table).await?)
+    }
+
+    /// 발급 가능한 증명서 종류를 가져옵니다.
+    pub async fn certificate_types(&mut self) -> Result<Vec<CertificateType>, RusaintError> {
+        self.read_table(Self::TABLE_TYPES).await
+    }
+
+    /// 증명서 발급 내역을 가져옵니다.
+    pub async fn history(&mut self) -> Result<Vec<CertificateIssuance>, RusaintError> {
+        self.read_table(Self::TABLE_HISTORY).await
+    }
+
+    /// 발급 내역의 증명서를 출력하고, 출력용 문서를 내려받습니다.
+    ///
+    /// 포털의 출력 버튼을 누르므로 조회 기능이 아니며, 호출할 때마다 포털에 증명서 출력이 기록됩니다.
+    /// 출력 확인 질문이 표시되면 확인을 누르고, 오류 메시지가 표시되면 [`ApplicationError::RequestRejected`]를,
+    /// 포털이 내려받을 문서를 제공하지 않으면 [`ApplicationError::NoCertificateDocument`]를 반환합니다.
+    pub async fn issue_document(
+        &mut self,
+        issuance: &CertificateIssuance,
+    ) -> Result<CertificateDocument, RusaintError> {
+        close_popups(&mut self.client).await?;
+        let selected = select_row_with_scroll(
+            &mut self.client,
+            Self::TABLE_HISTORY,
+            |row: &CertificateIssuance| row.issue_number() == issuance.issue_number(),
+        )
+        .await?;
+        if !selected {
+            return Err(ApplicationError::NoCertificateDocument.into());
+        }
+        let event =
+            ElementParser::new(self.body()).read(ButtonPressEventCommand::new(Self::BTN_PRINT))?;
+        self.client.process_event(false, event).await?;
+        let mut urls = self.client.script_urls();
+        if urls.is_empty() && confirm_popup(&mut self.client, &Self::CONFIRM_LABELS).await? {
+            urls = self.client.script_urls();
+        }
+        let error = popup_error(&ElementParser::new(self.body()));
+        close_popups(&mut self.client).await?;
+        if let Some(message) = error {
+            return Err(ApplicationError::RequestRejected(message).into());
+        }
+        let Some(url) = urls.first() else {
+            return Err(ApplicationError::NoCertificateDocument.into());
+        };
+        Ok(self.client.download(url).await?.into())
+    }
+}
+
+/// [`CertificateApplication`] 애플리케이션에 사용되는 데이터
+pub mod model;
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use serde::{
+    Deserialize, Serialize,
+    de::{IntoDeserializer, value::MapDeserializer},
+};
+
+use crate::application::utils::de_with::{
+    deserialize_comma_u64_string, deserialize_optional_string, deserialize_u32_string,
+};
+use crate::webdynpro::{
+    client::WebDynproDownload,
+    element::{
+        complex::sap_table::{FromSapTable, SapTableHeader, SapTableRow},
+        definition::ElementDefinition,
+        parser::ElementParser,
+    },
+    error::{ElementError, WebDynproError},
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 발급 가능한 증명서 종류
+pub struct CertificateType {
+    #[serde(rename(deserialize = "증명서명"))]
+    name: String,
+    #[serde(
+        rename(deserialize = "언어"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    language: Option<String>,
+    #[serde(
+        rename(deserialize = "수수료"),
+        deserialize_with = "deserialize_comma_u64_string"
+    )]
+    fee: u64,
+    #[serde(
+        rename(deserialize = "비고"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    note: Option<String>,
+}
+
+impl CertificateType {
+    /// 증명서명을 반환합니다.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 증명서 언어를 반환합니다.
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    /// 발급 수수료를 반환합니다.
+    pub fn fee(&self) -> u64 {
+        self.fee
+    }
+
+    /// 비고를 반환합니다.
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 증명서 발급 내역
+pub struct CertificateIssuance {
+    #[serde(rename(deserialize = "발급일자"))]
+    issued_at: String,
+    #[serde(rename(deserialize = "증명서명"))]
+    name: String,
+    #[serde(rename(deserialize = "발급번호"))]
+    issue_number: String,
+    #[serde(
+        rename(deserialize = "매수"),
+        deserialize_with = "deserialize_u32_string"
+    )]
+    copies: u32,
+    #[serde(
+        rename(deserialize = "용도"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    purpose: Option<String>,
+}
+
+impl CertificateIssuance {
+    /// 발급일자를 반환합니다.
+    pub fn issued_at(&self) -> &str {
+        &self.issued_at
+    }
+
+    /// 증명서명을 반환합니다.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 발급번호를 반환합니다.
+    pub fn issue_number(&self) -> &str {
+        &self.issue_number
+    }
+
+    /// 발급 매수를 반환합니다.
+    pub fn copies(&self) -> u32 {
+        self.copies
+    }
+
+    /// 용도를 반환합니다.
+    pub fn purpose(&self) -> Option<&str> {
+        self.purpose.as_deref()
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 내려받은 증명서 문서
+pub struct CertificateDocument {
+    file_name: Option<String>,
+    content_type: String,
+    data: Vec<u8>,
+}
+
+impl CertificateDocument {
+    /// 문서의 파일 이름을 반환합니다.
+    pub fn file_name(&self) -> Option<&str> {
+        self.file_name.as_deref()
+    }
+
+    /// 문서의 Content-Type을 반환합니다.
+    pub fn content_type(&self) -> &str {
+        &self.content_type
+    }
+
+    /// 문서 내용을 반환합니다.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl From<WebDynproDownload> for CertificateDocument {
+    fn from(download: WebDynproDownload) -> Self {
+        Self {
+            file_name: download.file_name().map(str::to_owned),
+            content_type: download
+                .content_type()
+                .unwrap_or("application/octet-stream")
+                .to_owned(),
+            data: download.into_data(),
+        }
+    }
+}
+
+impl<'a> FromSapTable<'a> for CertificateType {
+    fn from_table(
+        header: &'a SapTableHeader,
+        row: &'a SapTableRow,
+        parser: &'a ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let map_string = row.try_row_into::<HashMap<String, String>>(header, parser)?;
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map_string.into_deserializer();
+        Ok(
+            Self::deserialize(map_de).map_err(|e| ElementError::InvalidContent {
+                element: row.table_def().id().to_string(),
+                content: e.to_string(),
+            })?,
+        )
+    }
+}
+
+impl<'a> FromSapTable<'a> for CertificateIssuance {
+    fn from_table(
+        header: &'a SapTableHeader,
+        row: &'a SapTableRow,
+        parser: &'a ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let map_string = row.try_row_into::<HashMap<String, String>>(header, parser)?;
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map_string.into_deserializer();
+        Ok(
+            Self::deserialize(map_de).map_err(|e| ElementError::InvalidContent {
+                element: row.table_def().id().to_string(),
+                content: e.to_string(),
+            })?,
+        )
+    }
+}
@@ -1,20 +1,34 @@
-use model::{ChapelAbsenceRequest, ChapelAttendance, ChapelInformation, GeneralChapelInformation};
+use model::{
+    ChapelAbsenceReason, ChapelAbsenceRequest, ChapelAbsenceSubmission, ChapelAttendance,
+    ChapelInformation, GeneralChapelInformation,
+};
 
 use super::{USaintApplication, USaintClient};
+use crate::application::utils::popup::{close_popups, confirm_popup, popup_result};
 use crate::application::utils::semester::get_selected_semester;
 use crate::webdynpro::command::WebDynproCommandExecutor;
 use crate::webdynpro::element::parser::ElementParser;
 use crate::{
     RusaintError, define_elements,
-    model::SemesterType,
+    error::ApplicationError,
+    model::{Date, SemesterType},
     webdynpro::{
         client::body::Body,
         command::element::{
             action::ButtonPressEventCommand,
-            selection::{ComboBoxLSDataCommand, ComboBoxSelectEventCommand},
+            selection::{
+                ComboBoxItemListBoxCommand, ComboBoxLSDataCommand, ComboBoxSelectEventCommand,
+                ListBoxItemInfoCommand,
+            },
+            text::InputFieldChangeEventCommand,
+        },
+        element::{
+            action::Button,
+            selection::{ComboBox, list_box::item::ListBoxItemInfo},
+            text::InputField,
         },
-        element::{action::Button, selection::ComboBox},
         error::{ElementError, WebDynproError},
+        event::Event,
     },
 };
 
@@ -41,8 +55,16 @@ impl<'a> ChapelApplication {
         SEL_PERYR: ComboBox<'a> = "ZCMW3681.ID_0001:V_MAIN.TC_SEL_PERYR";
         SEL_PERID: ComboBox<'a> = "ZCMW3681.ID_0001:V_MAIN.TC_SEL_PERID";
         BTN_SEL: Button<'a> = "ZCMW3681.ID_0001:V_MAIN.BTN_SEL";
+        // 결석신청 양식: 캡처한 ZCMW3681 페이지와 대조하지 못한 추정 ID로, 실제 페이지와 다를 수 있음
+        ABSENCE_REASON: ComboBox<'a> = "ZCMW3681.ID_0001:V_MAIN.ABSNC_CD";
+        ABSENCE_START: InputField<'a> = "ZCMW3681.ID_0001:V_MAIN.ABSNC_BEGDA";
+        ABSENCE_END: InputField<'a> = "ZCMW3681.ID_0001:V_MAIN.ABSNC_ENDDA";
+        ABSENCE_DETAIL: InputField<'a> = "ZCMW3681.ID_0001:V_MAIN.ABSNC_RSN";
+        BTN_ABSENCE_REQUEST: Button<'a> = "ZCMW3681.ID_0001:V_MAIN.BTN_ABSNC";
     }
 
+    const CONFIRM_LABELS: [&'static str; 2] = ["예", "확인"];
+
     fn semester_to_key(semester: SemesterType) -> &'static str {
         match semester {
             SemesterType::One => "090",
@@ -117,6 +139,116 @@ impl<'a> ChapelApplication {
         ))
     }
 
+    /// 결석신청 양식에서 선택할 수 있는 결석 사유 목록을 가져옵니다.
+    pub fn absence_reasons(&self) -> Result<Vec<ChapelAbsenceReason>, RusaintError> {
+        let parser = ElementParser::new(self.body());
+        let listbox = parser.read(ComboBoxItemListBoxCommand::new(Self::ABSENCE_REASON))?;
+        let items = parser.read(ListBoxItemInfoCommand::new(listbox))?;
+        Ok(items
+            .into_iter()
+            .filter_map(|item| match item {
+                ListBoxItemInfo::Item { key, value1, .. } if !key.trim().is_empty() => {
+                    Some(ChapelAbsenceReason::new(key, value1))
+                }
+                _ => None,
+            })
+            .collect())
+    }
+
+    // 결석신청 양식의 사유, 기간, 상세 사유를 입력하는 이벤트
+    fn absence_request_events(
+        parser: &ElementParser,
+        start: Date,
+        end: Date,
+        reason_code: &str,
+        detail: &str,
+    ) -> Result<[Event; 4], WebDynproError> {
+        let format_date =
+            |date: Date| format!("{:04}.{:02}.{:02}", date.year(), date.month(), date.day());
+        Ok([
+            parser.read(ComboBoxSelectEventCommand::new(
+                Self::ABSENCE_REASON,
+                reason_code,
+                false,
+            ))?,
+            parser.read(InputFieldChangeEventCommand::new(
+                Self::ABSENCE_START,
+                &format_date(start),
+            ))?,
+            parser.read(InputFieldChangeEventCommand::new(
+                Self::ABSENCE_END,
+                &format_date(end),
+            ))?,
+            parser.read(InputFieldChangeEventCommand::new(
+                Self::ABSENCE_DETAIL,
+                detail,
+            ))?,
+        ])
+    }
+
+    /// 현재 선택된 학기에 채플 결석신청을 제출하고, 서버의 확인 메시지와 제출 후의 결석신청 목록을 반환합니다.
+    /// 결석 기간은 채플 기본 정보의 수업 요일과 비교하여 확인하며, 제출 확인 질문이 표시되지 않거나 서버가 신청을 거부하면 서버의 메시지와 함께 [`ApplicationError::RequestRejected`]를 반환합니다.
+    pub async fn submit_absence_request(
+        &mut self,
+        start: Date,
+        end: Date,
+        reason_code: &str,
+        detail: &str,
+    ) -> Result<ChapelAbsenceSubmission, RusaintError> {
+        close_popups(&mut self.client).await?;
+        let general_information =
+            GeneralChapelInformation::with_parser(&ElementParser::new(self.body()))?
+                .pop()
+                .ok_or(ApplicationError::NoChapelInformation)?;
+        general_information
+            .validate_absence_period(start, end)
+            .map_err(ApplicationError::InvalidAbsenceRequest)?;
+        if !self
+            .absence_reasons()?
+            .iter()
+            .any(|reason| reason.code() == reason_code)
+        {
+            return Err(ApplicationError::InvalidAbsenceRequest(format!(
+                "Unknown absence reason code: {reason_code}"
+            ))
+            .into());
+        }
+        if detail.trim().is_empty() {
+            return Err(ApplicationError::InvalidAbsenceRequest(
+                "Absence detail is empty".to_string(),
+            )
+            .into());
+        }
+        let events = Self::absence_request_events(
+            &ElementParser::new(self.body()),
+            start,
+            end,
+            reason_code,
+            detail,
+        )?;
+        for event in events {
+            self.client.process_event(false, event).await?;
+        }
+        let request = ElementParser::new(self.body())
+            .read(ButtonPressEventCommand::new(Self::BTN_ABSENCE_REQUEST))?;
+        self.client.process_event(false, request).await?;
+        let confirmed = confirm_popup(&mut self.client, &Self::CONFIRM_LABELS).await?;
+        let result = popup_result(&ElementParser::new(self.body()));
+        close_popups(&mut self.client).await?;
+        let message = match (confirmed, result) {
+            (true, Ok(message)) => message.unwrap_or_default(),
+            (false, Ok(_)) => {
+                return Err(ApplicationError::RequestRejected(
+                    "Absence request confirmation was not requested".to_string(),
+                )
+                .into());
+            }
+            (_, Err(message)) => return Err(ApplicationError::RequestRejected(message).into()),
+        };
+        let absence_requests = ChapelAbsenceRequest::with_parser(&ElementParser::new(self.body()))?;
+        Ok(ChapelAbsenceSubmission::new(message, absence_requests))
+    }
+
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
     pub fn get_selected_semester(&self) -> Result<(u32, SemesterType), RusaintError> {
@@ -130,3 +262,38 @@ impl<'a> ChapelApplication {
 
 /// [`ChapelApplication`] 애플리케이션에 사용되는 데이터
 pub mod model;
+
+#[cfg(test)]
+mod test {
+    use super::ChapelApplication;
+    use crate::{
+        model::Date,
+        webdynpro::{client::body::body_fixture, element::parser::ElementParser},
+    };
+
+    // 결석신청 양식의 요소 구성. 실제 페이지가 아닌 추정 ID로 작성한 구성이므로 이벤트 생성만 확인함
+    const ABSENCE_FORM: &str = concat!(
+        r#"<div id="ZCMW3681.ID_0001:V_MAIN.ABSNC_CD" ct="CB" lsdata="{}" lsevents="{'Select':[{},{}]}"></div>"#,
+        r#"<input id="ZCMW3681.ID_0001:V_MAIN.ABSNC_BEGDA" ct="I" lsdata="{}" lsevents="{'Change':[{},{}]}">"#,
+        r#"<input id="ZCMW3681.ID_0001:V_MAIN.ABSNC_ENDDA" ct="I" lsdata="{}" lsevents="{'Change':[{},{}]}">"#,
+        r#"<input id="ZCMW3681.ID_0001:V_MAIN.ABSNC_RSN" ct="I" lsdata="{}" lsevents="{'Change':[{},{}]}">"#,
+    );
+
+    #[test]
+    fn absence_request_events() {
+        let body = body_fixture(ABSENCE_FORM);
+        let events = ChapelApplication::absence_request_events(
+            &ElementParser::new(&body),
+            Date::new(2025, 3, 4).unwrap(),
+            Date::new(2025, 3, 11).unwrap(),
+            "01",
+            "병가",
+        )
+        .unwrap()
+        .map(|event| event.to_string());
+        assert!(events[0].contains("ABSNC_CD") && events[0].contains("01"));
+        assert!(events[1].contains("ABSNC_BEGDA") && events[1].contains("2025.03.04"));
+        assert!(events[2].contains("ABSNC_ENDDA") && events[2].contains("2025.03.11"));
+        assert!(events[3].contains("ABSNC_RSN"));
+    }
+}
@@ -11,7 +11,7 @@ use crate::webdynpro::element::parser::ElementParser;
 use crate::{
     RusaintError, define_elements,
     error::ApplicationError,
-    model::SemesterType,
+    model::{Date, SemesterType},
     webdynpro::{
         command::element::complex::SapTableBodyCommand,
         element::{
@@ -175,6 +175,33 @@ impl<'a> GeneralChapelInformation {
     pub fn note(&self) -> &str {
         &self.note
     }
+
+    /// 시간표에서 채플 요일을 월요일을 0으로 하는 요일 번호로 반환합니다.
+    pub fn chapel_weekday_index(&self) -> Option<u32> {
+        self.chapel_time
+            .chars()
+            .find_map(|c| "월화수목금토일".chars().position(|day| day == c))
+            .map(|idx| idx as u32)
+    }
+
+    /// 결석신청 기간이 올바른지 확인합니다. 기간 안에 채플 수업 요일이 없으면 오류를 반환합니다.
+    pub(crate) fn validate_absence_period(&self, start: Date, end: Date) -> Result<(), String> {
+        if start > end {
+            return Err(format!("Absence start {start} is after end {end}"));
+        }
+        let Some(weekday) = self.chapel_weekday_index() else {
+            return Ok(());
+        };
+        let days = end.days_from_epoch() - start.days_from_epoch();
+        if (0..=days.min(6)).any(|offset| start.add_days(offset).weekday_index() == weekday) {
+            Ok(())
+        } else {
+            Err(format!(
+                "No chapel class ({}) between {start} and {end}",
+                self.chapel_time
+            ))
+        }
+    }
 }
 
 impl<'body> FromSapTable<'body> for GeneralChapelInformation {
@@ -306,6 +333,30 @@ impl<'body> FromSapTable<'body> for ChapelAttendance {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 채플 결석신청 양식에서 선택할 수 있는 결석 사유
+pub struct ChapelAbsenceReason {
+    code: String,
+    name: String,
+}
+
+impl ChapelAbsenceReason {
+    pub(crate) fn new(code: String, name: String) -> Self {
+        Self { code, name }
+    }
+
+    /// 결석 사유 코드를 반환합니다.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// 결석 사유 이름을 반환합니다.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 /// 채플 결석신청 정보
@@ -434,3 +485,78 @@ impl<'body> FromSapTable<'body> for ChapelAbsenceRequest {
         )
     }
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 채플 결석신청 제출 결과
+pub struct ChapelAbsenceSubmission {
+    message: String,
+    absence_requests: Vec<ChapelAbsenceRequest>,
+}
+
+impl ChapelAbsenceSubmission {
+    pub(crate) fn new(message: String, absence_requests: Vec<ChapelAbsenceRequest>) -> Self {
+        Self {
+            message,
+            absence_requests,
+        }
+    }
+
+    /// 제출 후 서버가 표시한 확인 메시지를 반환합니다.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// 제출 후의 채플 결석신청 정보를 반환합니다.
+    pub fn absence_requests(&self) -> &[ChapelAbsenceRequest] {
+        &self.absence_requests
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use serde::{
+        Deserialize,
+        de::{IntoDeserializer, value::MapDeserializer},
+    };
+
+    use super::GeneralChapelInformation;
+    use crate::model::Date;
+
+    fn information(chapel_time: &str) -> GeneralChapelInformation {
+        let map: HashMap<String, String> = [
+            ("분반", "1"),
+            ("시간표", chapel_time),
+            ("강의실", "한경직기념관"),
+            ("층수", "1"),
+            ("좌석번호", "A-1"),
+            ("결석일수", "0"),
+            ("성적", ""),
+            ("비고", ""),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map.into_deserializer();
+        GeneralChapelInformation::deserialize(map_de).unwrap()
+    }
+
+    #[test]
+    fn validate_absence_period() {
+        let info = information("화 10:30-11:20");
+        assert_eq!(info.chapel_weekday_index(), Some(1));
+        // 2025-03-04 is Tuesday
+        let tuesday = Date::new(2025, 3, 4).unwrap();
+        let wednesday = Date::new(2025, 3, 5).unwrap();
+        let friday = Date::new(2025, 3, 7).unwrap();
+        assert!(info.validate_absence_period(tuesday, tuesday).is_ok());
+        assert!(info.validate_absence_period(wednesday, friday).is_err());
+        assert!(info.validate_absence_period(friday, tuesday).is_err());
+        assert!(
+            info.validate_absence_period(wednesday, wednesday.add_days(7))
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,359 @@
+use std::{collections::HashMap, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::SemesterType;
+
+use super::model::{ClassGrade, ClassScore, GradeSummary};
+
+/// 4.5 만점 기준 성적 등급
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum GradeRank {
+    /// A+ (4.5)
+    APlus,
+    /// A0 (4.3)
+    AZero,
+    /// A- (4.0)
+    AMinus,
+    /// B+ (3.5)
+    BPlus,
+    /// B0 (3.3)
+    BZero,
+    /// B- (3.0)
+    BMinus,
+    /// C+ (2.5)
+    CPlus,
+    /// C0 (2.3)
+    CZero,
+    /// C- (2.0)
+    CMinus,
+    /// D+ (1.5)
+    DPlus,
+    /// D0 (1.3)
+    DZero,
+    /// D- (1.0)
+    DMinus,
+    /// F (0.0)
+    F,
+    /// P/F 과목의 Pass
+    Pass,
+    /// P/F 과목의 Fail
+    Fail,
+}
+
+impl GradeRank {
+    /// 등급의 평점을 반환합니다. P/F 등급은 평점에 포함되지 않으므로 `None`을 반환합니다.
+    pub fn points(&self) -> Option<f32> {
+        Some(match self {
+            GradeRank::APlus => 4.5,
+            GradeRank::AZero => 4.3,
+            GradeRank::AMinus => 4.0,
+            GradeRank::BPlus => 3.5,
+            GradeRank::BZero => 3.3,
+            GradeRank::BMinus => 3.0,
+            GradeRank::CPlus => 2.5,
+            GradeRank::CZero => 2.3,
+            GradeRank::CMinus => 2.0,
+            GradeRank::DPlus => 1.5,
+            GradeRank::DZero => 1.3,
+            GradeRank::DMinus => 1.0,
+            GradeRank::F => 0.0,
+            GradeRank::Pass | GradeRank::Fail => return None,
+        })
+    }
+
+    /// 등급 구간의 최저 점수를 반환합니다. P/F 등급은 `None`을 반환합니다.
+    pub fn minimum_score(&self) -> Option<u32> {
+        Some(match self {
+            GradeRank::APlus => 95,
+            GradeRank::AZero => 90,
+            GradeRank::AMinus => 85,
+            GradeRank::BPlus => 80,
+            GradeRank::BZero => 75,
+            GradeRank::BMinus => 70,
+            GradeRank::CPlus => 65,
+            GradeRank::CZero => 60,
+            GradeRank::CMinus => 55,
+            GradeRank::DPlus => 50,
+            GradeRank::DZero => 45,
+            GradeRank::DMinus => 40,
+            GradeRank::F => 0,
+            GradeRank::Pass | GradeRank::Fail => return None,
+        })
+    }
+
+    /// 학점을 취득하는 등급인지 여부를 반환합니다.
+    pub fn is_passed(&self) -> bool {
+        !matches!(self, GradeRank::F | GradeRank::Fail)
+    }
+
+    /// P/F 과목의 등급인지 여부를 반환합니다.
+    pub fn is_pass_fail(&self) -> bool {
+        matches!(self, GradeRank::Pass | GradeRank::Fail)
+    }
+}
+
+impl FromStr for GradeRank {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "A+" => GradeRank::APlus,
+            "A0" | "A" => GradeRank::AZero,
+            "A-" => GradeRank::AMinus,
+            "B+" => GradeRank::BPlus,
+            "B0" | "B" => GradeRank::BZero,
+            "B-" => GradeRank::BMinus,
+            "C+" => GradeRank::CPlus,
+            "C0" | "C" => GradeRank::CZero,
+            "C-" => GradeRank::CMinus,
+            "D+" => GradeRank::DPlus,
+            "D0" | "D" => GradeRank::DZero,
+            "D-" => GradeRank::DMinus,
+            "F" => GradeRank::F,
+            "P" => GradeRank::Pass,
+            "NP" | "FAIL" => GradeRank::Fail,
+            other => return Err(format!("Unknown grade rank: {other}")),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SimulatedClass {
+    code: String,
+    credits: f32,
+    rank: GradeRank,
+    score: Option<u32>,
+    order: (u32, u32),
+}
+
+/// 수업별 성적과 가상의 성적을 이용해 예상 평점을 계산하는 계산기
+///
+/// 숭실대학교 학칙에 따라 4.5 만점으로 계산하며, P/F 과목은 평점 계산에서 제외합니다.
+/// 같은 과목코드의 수업이 여러 번 입력된 경우 가장 나중에 이수한 성적만을 반영합니다(재수강).
+/// ```
+/// use rusaint::application::course_grades::calculator::{GpaCalculator, GradeRank};
+///
+/// let mut calculator = GpaCalculator::new();
+/// calculator.add_hypothetical("21500001", 3.0, GradeRank::F);
+/// calculator.add_hypothetical("21500002", 3.0, GradeRank::APlus);
+/// // 21500001 과목을 재수강하여 A0를 받는 경우
+/// calculator.add_hypothetical("21500001", 3.0, GradeRank::AZero);
+/// let summary = calculator.summary();
+/// assert!((summary.grade_points_average() - 4.4).abs() < 1e-4);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GpaCalculator {
+    classes: Vec<SimulatedClass>,
+    hypothetical_count: u32,
+    retake_cap: Option<GradeRank>,
+}
+
+impl GpaCalculator {
+    /// 빈 계산기를 만듭니다.
+    pub fn new() -> GpaCalculator {
+        GpaCalculator::default()
+    }
+
+    /// [`CourseGradesApplication::classes`](super::CourseGradesApplication::classes)로 가져온 수업별 성적으로 계산기를 만듭니다.
+    pub fn with_classes<'a>(classes: impl IntoIterator<Item = &'a ClassGrade>) -> GpaCalculator {
+        let mut calculator = GpaCalculator::new();
+        for class in classes {
+            calculator.add_class(class);
+        }
+        calculator
+    }
+
+    /// 재수강 시 받을 수 있는 최고 등급을 설정합니다. 설정하지 않으면 제한하지 않습니다.
+    pub fn retake_cap(&mut self, cap: Option<GradeRank>) -> &mut GpaCalculator {
+        self.retake_cap = cap;
+        self
+    }
+
+    /// 실제 수업 성적을 추가합니다. 아직 성적이 입력되지 않은 수업은 무시됩니다.
+    pub fn add_class(&mut self, class: &ClassGrade) -> &mut GpaCalculator {
+        let rank = match class.score() {
+            ClassScore::Pass => Some(GradeRank::Pass),
+            ClassScore::Failed => Some(GradeRank::Fail),
+            ClassScore::Score(_) => class.rank().parse::<GradeRank>().ok(),
+            ClassScore::Empty => None,
+        };
+        let Some(rank) = rank else {
+            return self;
+        };
+        let score = match class.score() {
+            ClassScore::Score(score) => Some(score),
+            _ => None,
+        };
+        self.classes.push(SimulatedClass {
+            code: class.code().to_string(),
+            credits: class.grade_points(),
+            rank,
+            score,
+            order: (class.year(), semester_order(class.semester())),
+        });
+        self
+    }
+
+    /// 가상의 수업 성적을 추가합니다. 가상의 성적은 실제 성적보다 나중에 이수한 것으로 간주합니다.
+    ///
+    /// 이미 이수한 과목코드를 추가하면 재수강으로 처리합니다.
+    pub fn add_hypothetical(
+        &mut self,
+        code: &str,
+        credits: f32,
+        rank: GradeRank,
+    ) -> &mut GpaCalculator {
+        self.add_hypothetical_with_score(code, credits, rank, None)
+    }
+
+    /// 점수와 함께 가상의 수업 성적을 추가합니다. 점수가 없다면 등급 구간의 최저 점수로 산술평균을 계산합니다.
+    pub fn add_hypothetical_with_score(
+        &mut self,
+        code: &str,
+        credits: f32,
+        rank: GradeRank,
+        score: Option<u32>,
+    ) -> &mut GpaCalculator {
+        self.hypothetical_count += 1;
+        self.classes.push(SimulatedClass {
+            code: code.to_string(),
+            credits,
+            rank,
+            score,
+            order: (u32::MAX, self.hypothetical_count),
+        });
+        self
+    }
+
+    /// 재수강 규칙을 적용한 뒤 예상 성적 요약을 계산합니다.
+    pub fn summary(&self) -> GradeSummary {
+        let mut latest: HashMap<&str, (&SimulatedClass, bool)> = HashMap::new();
+        for class in &self.classes {
+            latest
+                .entry(class.code.as_str())
+                .and_modify(|(kept, retaken)| {
+                    *retaken = true;
+                    if class.order >= kept.order {
+                        *kept = class;
+                    }
+                })
+                .or_insert((class, false));
+        }
+        let mut attempted_credits = 0.0;
+        let mut earned_credits = 0.0;
+        let mut pf_earned_credits = 0.0;
+        let mut graded_credits = 0.0;
+        let mut grade_points_sum = 0.0;
+        let mut score_sum = 0.0;
+        for (class, retaken) in latest.values() {
+            let rank = match self.retake_cap {
+                Some(cap) if *retaken && cap.points() < class.rank.points() => cap,
+                _ => class.rank,
+            };
+            attempted_credits += class.credits;
+            if rank.is_passed() {
+                earned_credits += class.credits;
+            }
+            if rank.is_pass_fail() {
+                if rank.is_passed() {
+                    pf_earned_credits += class.credits;
+                }
+                continue;
+            }
+            let points = rank.points().unwrap_or_default();
+            let score = class.score.or(rank.minimum_score()).unwrap_or_default();
+            graded_credits += class.credits;
+            grade_points_sum += points * class.credits;
+            score_sum += score as f32 * class.credits;
+        }
+        let (grade_points_average, arithmetic_mean) = if graded_credits > 0.0 {
+            (
+                grade_points_sum / graded_credits,
+                score_sum / graded_credits,
+            )
+        } else {
+            (0.0, 0.0)
+        };
+        GradeSummary::new(
+            attempted_credits,
+            earned_credits,
+            grade_points_sum,
+            grade_points_average,
+            arithmetic_mean,
+            pf_earned_credits,
+        )
+    }
+}
+
+fn semester_order(semester: SemesterType) -> u32 {
+    match semester {
+        SemesterType::One => 0,
+        SemesterType::Summer => 1,
+        SemesterType::Two => 2,
+        SemesterType::Winter => 3,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{GpaCalculator, GradeRank};
+    use crate::{
+        application::course_grades::model::{ClassGrade, ClassScore, GradeState},
+        model::SemesterType,
+    };
+
+    fn class(year: u32, code: &str, credits: f32, score: ClassScore, rank: &str) -> ClassGrade {
+        ClassGrade::new(
+            year,
+            SemesterType::One,
+            code.to_string(),
+            code.to_string(),
+            credits,
+            score,
+            rank.to_string(),
+            String::new(),
+            None,
+            GradeState::Confirmed,
+        )
+    }
+
+    #[test]
+    fn excludes_pass_fail() {
+        let classes = [
+            class(2022, "A", 3.0, ClassScore::Score(97), "A+"),
+            class(2022, "B", 1.0, ClassScore::Pass, "P"),
+        ];
+        let summary = GpaCalculator::with_classes(&classes).summary();
+        assert_eq!(summary.attempted_credits(), 4.0);
+        assert_eq!(summary.earned_credits(), 4.0);
+        assert_eq!(summary.pf_earned_credits(), 1.0);
+        assert_eq!(summary.grade_points_average(), 4.5);
+        assert_eq!(summary.arithmetic_mean(), 97.0);
+    }
+
+    #[test]
+    fn replaces_retaken_class() {
+        let classes = [
+            class(2023, "A", 3.0, ClassScore::Score(91), "A0"),
+            class(2022, "A", 3.0, ClassScore::Score(30), "F"),
+            class(2022, "B", 3.0, ClassScore::Score(75), "B0"),
+        ];
+        let summary = GpaCalculator::with_classes(&classes).summary();
+        assert_eq!(summary.attempted_credits(), 6.0);
+        assert_eq!(summary.earned_credits(), 6.0);
+        assert!((summary.grade_points_average() - 3.8).abs() < 1e-4);
+    }
+
+    #[test]
+    fn applies_retake_cap() {
+        let classes = [class(2022, "A", 3.0, ClassScore::Score(30), "F")];
+        let mut calculator = GpaCalculator::with_classes(&classes);
+        calculator
+            .retake_cap(Some(GradeRank::AZero))
+            .add_hypothetical("A", 3.0, GradeRank::APlus);
+        let summary = calculator.summary();
+        assert!((summary.grade_points_average() - 4.3).abs() < 1e-4);
+    }
+}
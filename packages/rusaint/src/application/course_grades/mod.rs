@@ -1,5 +1,8 @@
-use self::model::{ClassGrade, CourseType, GradeSummary, SemesterGrade};
+use self::model::{
+    ClassGrade, CourseType, GradeObjection, GradeState, GradeSummary, PortalAccount, SemesterGrade,
+};
 use super::{USaintApplication, USaintClient};
+use crate::application::utils::popup::close_popups;
 use crate::application::utils::sap_table::try_table_into_with_scroll;
 use crate::application::utils::semester::get_selected_semester;
 use crate::webdynpro::client::body::Body;
@@ -8,18 +11,21 @@ use crate::webdynpro::element::complex::sap_table::cell::SapTableCellWrapper;
 use crate::webdynpro::element::parser::ElementParser;
 use crate::{
     RusaintError, define_elements,
+    error::ApplicationError,
     model::SemesterType,
     webdynpro::{
         command::element::{
             complex::SapTableBodyCommand,
-            selection::{ComboBoxLSDataCommand, ComboBoxSelectEventCommand},
+            selection::{
+                ComboBoxItemListBoxCommand, ComboBoxLSDataCommand, ComboBoxSelectEventCommand,
+                ListBoxItemInfoCommand,
+            },
         },
         element::{
             Element, ElementDefWrapper, ElementWrapper,
             complex::sap_table::{SapTable, cell::SapTableCell},
             definition::ElementDefinition,
-            layout::PopupWindow,
-            selection::ComboBox,
+            selection::{ComboBox, list_box::item::ListBoxItemInfo},
             text::InputField,
         },
         error::{BodyError, ElementError, WebDynproError},
@@ -86,27 +92,17 @@ impl<'a> CourseGradesApplication {
         PERIOD_YEAR: ComboBox<'a> = "ZCMW_PERIOD_RE.ID_0DC742680F42DA9747594D1AE51A0C69:VIW_MAIN.PERYR";
         PERIOD_SEMESTER: ComboBox<'a> = "ZCMW_PERIOD_RE.ID_0DC742680F42DA9747594D1AE51A0C69:VIW_MAIN.PERID";
         GRADE_BY_CLASSES_TABLE: SapTable<'a> = "ZCMB3W0017.ID_0001:VIW_MAIN.TABLE_1";
+        // Grade objection requests of selected semester.
+        // UNVERIFIED: this ID has not been checked against a captured ZCMB3W0017 page. If it
+        // points to another table, reading rows as `GradeObjection` fails with an element error.
+        GRADE_OBJECTION_TABLE: SapTable<'a> = "ZCMB3W0017.ID_0001:VIW_MAIN.TABLE_2";
     );
 
+    /// 과목별 성적 표의 확정 여부 열 이름
+    const CONFIRMED_COLUMN: &'static str = "확정여부";
+
     async fn close_popups(&mut self) -> Result<(), WebDynproError> {
-        let popup_selector =
-            Selector::parse(format!(r#"[ct="{}"]"#, PopupWindow::CONTROL_ID).as_str()).unwrap();
-        fn make_close_event(body: &Body, selector: &Selector) -> Option<Event> {
-            let parser = ElementParser::new(body);
-            let mut popup_iter = parser.document().select(selector);
-            popup_iter.next().and_then(|elem| {
-                let elem_wrapped = ElementWrapper::from_ref(elem).ok()?;
-                if let ElementWrapper::PopupWindow(popup) = elem_wrapped {
-                    popup.close().ok()
-                } else {
-                    None
-                }
-            })
-        }
-        while let Some(event) = make_close_event(self.body(), &popup_selector) {
-            self.client.process_event(false, event).await?;
-        }
-        Ok(())
+        close_popups(&mut self.client).await
     }
 
     fn semester_to_key(semester: SemesterType) -> &'static str {
@@ -128,6 +124,17 @@ impl<'a> CourseGradesApplication {
         }
     }
 
+    fn key_to_course_type(key: &str) -> Option<CourseType> {
+        match key {
+            "DR" => Some(CourseType::Phd),
+            "MA" => Some(CourseType::Master),
+            "MP" => Some(CourseType::PhdIntergrated),
+            "RE" => Some(CourseType::Research),
+            "UG" => Some(CourseType::Bachelor),
+            _ => None,
+        }
+    }
+
     async fn select_course(
         &mut self,
         parser: &ElementParser,
@@ -187,6 +194,39 @@ impl<'a> CourseGradesApplication {
         )?)
     }
 
+    /// 현재 계정으로 성적을 조회할 수 있는 학위과정 목록을 가져옵니다.
+    pub fn course_types(&self) -> Result<Vec<CourseType>, RusaintError> {
+        let parser = ElementParser::new(self.body());
+        let item_box = parser.read(ComboBoxItemListBoxCommand::new(Self::PROGRESS_TYPE))?;
+        let items = parser.read(ListBoxItemInfoCommand::new(item_box))?;
+        Ok(items
+            .iter()
+            .filter_map(|item| match item {
+                ListBoxItemInfo::Item { key, .. } => Self::key_to_course_type(key),
+                ListBoxItemInfo::ActionItem { .. } => None,
+            })
+            .collect())
+    }
+
+    /// 성적 조회 화면에 기본으로 선택된 현재 계정의 학위과정을 가져옵니다.
+    pub fn current_course_type(&self) -> Result<Option<CourseType>, RusaintError> {
+        let parser = ElementParser::new(self.body());
+        let combobox_lsdata = parser.read(ComboBoxLSDataCommand::new(Self::PROGRESS_TYPE))?;
+        Ok(combobox_lsdata
+            .key()
+            .and_then(|key| Self::key_to_course_type(key)))
+    }
+
+    /// 기본으로 선택된 학위과정과 학위과정 목록으로 로그인한 계정의 포털 역할을 판단합니다.
+    ///
+    /// 학위과정 선택 항목을 읽을 수 없거나 역할을 판단할 수 없는 계정(교직원 등)은 오류를 반환합니다.
+    pub fn account(&self) -> Result<PortalAccount, RusaintError> {
+        let current_course_type = self.current_course_type()?;
+        let course_types = self.course_types()?;
+        Ok(PortalAccount::new(current_course_type, course_types)
+            .ok_or(ApplicationError::UnknownPortalRole)?)
+    }
+
     /// 전체 학기의 학적부 평점 정보를 가져옵니다.
     /// ### 예시
     /// ```no_run
@@ -448,6 +488,11 @@ impl<'a> CourseGradesApplication {
             } else {
                 None
             };
+            // 확정 여부 열은 성적 확정 기간에만 표시되므로, 열이 없다면 확정 여부를 알 수 없음
+            let state = values
+                .get(Self::CONFIRMED_COLUMN)
+                .map(|value| GradeState::from_status(value))
+                .unwrap_or(GradeState::Unknown);
             let parsed: Option<ClassGrade> = (|| {
                 Some(ClassGrade::new(
                     year,
@@ -459,6 +504,7 @@ impl<'a> CourseGradesApplication {
                     values["등급"].trim().to_owned(),
                     values["교수명"].trim().to_owned(),
                     detail,
+                    state,
                 ))
             })();
             if let Some(parsed) = parsed {
@@ -540,6 +586,45 @@ impl<'a> CourseGradesApplication {
         self.class_detail_in_popup(btn).await
     }
 
+    /// 주어진 학기의 성적 이의신청 내역과 처리 결과를 가져옵니다.
+    /// ### 예시
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// # use std::sync::Arc;
+    /// # use rusaint::USaintSession;
+    /// # use rusaint::model::SemesterType;
+    /// # use rusaint::application::course_grades::{ model::CourseType, CourseGradesApplication };
+    /// # use rusaint::application::USaintClientBuilder;
+    /// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+    /// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseGradesApplication>().await.unwrap();
+    /// let objections = app.grade_objections(CourseType::Bachelor, 2022, SemesterType::Two).await.unwrap();
+    /// println!("{:?}", objections);
+    /// // [GradeObjection { ... }]
+    /// # })
+    /// ```
+    pub async fn grade_objections(
+        &mut self,
+        course_type: CourseType,
+        year: u32,
+        semester: SemesterType,
+    ) -> Result<Vec<GradeObjection>, RusaintError> {
+        {
+            self.close_popups().await?;
+            let parser = ElementParser::new(self.client.body());
+            self.select_course(&parser, course_type).await?;
+            self.select_semester(&parser, &year.to_string(), semester)
+                .await?;
+        }
+        let parser = ElementParser::new(self.client.body());
+        let objections = try_table_into_with_scroll::<GradeObjection>(
+            &mut self.client,
+            parser,
+            Self::GRADE_OBJECTION_TABLE,
+        )
+        .await?;
+        Ok(objections)
+    }
+
     fn body(&self) -> &Body {
         self.client.body()
     }
@@ -548,6 +633,12 @@ impl<'a> CourseGradesApplication {
 /// [`CourseGradesApplication`]에서 사용하는 데이터
 pub mod model;
 
+/// 예상 평점 계산기: [`GpaCalculator`](calculator::GpaCalculator)
+pub mod calculator;
+
+/// 성적 변경 감시: [`GradeWatcher`](watcher::GradeWatcher)
+pub mod watcher;
+
 #[cfg(test)]
 mod test {
     use crate::webdynpro::element::parser::ElementParser;
@@ -12,10 +12,10 @@ use crate::webdynpro::{
 };
 use crate::{
     application::utils::de_with::{
-        deserialize_empty, deserialize_f32_string, deserialize_semester_type,
-        deserialize_u32_string,
+        deserialize_empty, deserialize_f32_string, deserialize_optional_string,
+        deserialize_semester_type, deserialize_u32_string, deserialize_with_trim,
     },
-    model::SemesterType,
+    model::{PortalRole, SemesterType},
 };
 
 /// 전체 성적(학적부, 증명)
@@ -87,7 +87,7 @@ impl GradeSummary {
 }
 
 /// 학기별 성적
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(unused)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct SemesterGrade {
@@ -269,7 +269,7 @@ impl<'body> FromSapTable<'body> for SemesterGrade {
 }
 
 /// 과목별 성적
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(unused)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct ClassGrade {
@@ -291,6 +291,9 @@ pub struct ClassGrade {
     professor: String,
     /// 상세성적
     detail: Option<HashMap<String, f32>>,
+    /// 성적 확정 상태
+    #[serde(default)]
+    state: GradeState,
 }
 
 impl ClassGrade {
@@ -305,6 +308,7 @@ impl ClassGrade {
         rank: String,
         professor: String,
         detail: Option<HashMap<String, f32>>,
+        state: GradeState,
     ) -> ClassGrade {
         ClassGrade {
             year,
@@ -316,6 +320,7 @@ impl ClassGrade {
             rank,
             professor,
             detail,
+            state,
         }
     }
 
@@ -363,10 +368,202 @@ impl ClassGrade {
     pub fn detail(&self) -> Option<&HashMap<String, f32>> {
         self.detail.as_ref()
     }
+
+    /// 성적 확정 상태
+    pub fn state(&self) -> GradeState {
+        self.state
+    }
+
+    /// 성적이 확정되었는지 여부
+    pub fn is_confirmed(&self) -> bool {
+        self.state == GradeState::Confirmed
+    }
+}
+
+/// 성적 확정 상태
+///
+/// 성적 확정 기간 동안에는 u-saint 에 임시 성적이 표시되며, 확정 기간이 지나면 모든 성적이 확정 상태가 됩니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum GradeState {
+    /// 임시(미확정) 성적
+    Provisional,
+    /// 확정 성적
+    Confirmed,
+    /// 성적 표에 확정 여부 열이 없는 등의 이유로 확정 여부를 알 수 없음
+    #[default]
+    Unknown,
+}
+
+impl GradeState {
+    /// 성적 표의 확정 여부 열의 값을 [`GradeState`]로 변환합니다.
+    pub(crate) fn from_status(status: &str) -> GradeState {
+        match status.trim() {
+            "Y" | "확정" => GradeState::Confirmed,
+            "N" | "미확정" | "임시" | "임시성적" => GradeState::Provisional,
+            _ => GradeState::Unknown,
+        }
+    }
+}
+
+/// 성적 이의신청 내역
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[allow(unused)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct GradeObjection {
+    /// 과목코드
+    #[serde(
+        rename(deserialize = "과목코드"),
+        deserialize_with = "deserialize_with_trim"
+    )]
+    code: String,
+    /// 과목명
+    #[serde(
+        rename(deserialize = "과목명"),
+        deserialize_with = "deserialize_with_trim"
+    )]
+    class_name: String,
+    /// 신청일자
+    #[serde(
+        rename(deserialize = "신청일자"),
+        deserialize_with = "deserialize_with_trim"
+    )]
+    application_date: String,
+    /// 신청사유
+    #[serde(
+        rename(deserialize = "신청사유"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    reason: Option<String>,
+    /// 처리상태
+    #[serde(
+        rename(deserialize = "처리상태"),
+        deserialize_with = "deserialize_objection_status"
+    )]
+    status: GradeObjectionStatus,
+    /// 처리일자
+    #[serde(
+        rename(deserialize = "처리일자"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    process_date: Option<String>,
+    /// 교수 답변
+    #[serde(
+        rename(deserialize = "답변내용"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    answer: Option<String>,
+    /// 변경전 성적
+    #[serde(
+        rename(deserialize = "변경전성적"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    previous_rank: Option<String>,
+    /// 변경후 성적
+    #[serde(
+        rename(deserialize = "변경후성적"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    changed_rank: Option<String>,
+}
+
+impl GradeObjection {
+    /// 과목코드
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// 과목명
+    pub fn class_name(&self) -> &str {
+        &self.class_name
+    }
+
+    /// 신청일자
+    pub fn application_date(&self) -> &str {
+        &self.application_date
+    }
+
+    /// 신청사유
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    /// 처리상태
+    pub fn status(&self) -> &GradeObjectionStatus {
+        &self.status
+    }
+
+    /// 처리일자
+    pub fn process_date(&self) -> Option<&str> {
+        self.process_date.as_deref()
+    }
+
+    /// 교수 답변
+    pub fn answer(&self) -> Option<&str> {
+        self.answer.as_deref()
+    }
+
+    /// 변경전 성적(등급)
+    pub fn previous_rank(&self) -> Option<&str> {
+        self.previous_rank.as_deref()
+    }
+
+    /// 변경후 성적(등급)
+    pub fn changed_rank(&self) -> Option<&str> {
+        self.changed_rank.as_deref()
+    }
+}
+
+impl<'body> FromSapTable<'body> for GradeObjection {
+    fn from_table(
+        header: &'body crate::webdynpro::element::complex::sap_table::SapTableHeader,
+        row: &'body crate::webdynpro::element::complex::sap_table::SapTableRow,
+        parser: &'body ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let map_string = row.try_row_into::<HashMap<String, String>>(header, parser)?;
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map_string.into_deserializer();
+        Ok(
+            GradeObjection::deserialize(map_de).map_err(|e| ElementError::InvalidContent {
+                element: row.table_def().id().to_string(),
+                content: e.to_string(),
+            })?,
+        )
+    }
+}
+
+/// 성적 이의신청 처리상태
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum GradeObjectionStatus {
+    /// 신청(처리 대기)
+    Requested,
+    /// 승인(성적 변경)
+    Accepted,
+    /// 반려(성적 유지)
+    Rejected,
+    /// 기타 상태
+    Other(String),
+}
+
+fn deserialize_objection_status<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<GradeObjectionStatus, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    Ok(match value.trim() {
+        "신청" | "접수" | "처리중" => GradeObjectionStatus::Requested,
+        "승인" | "처리완료" | "변경" => GradeObjectionStatus::Accepted,
+        "반려" | "기각" | "불가" => GradeObjectionStatus::Rejected,
+        other => GradeObjectionStatus::Other(other.to_string()),
+    })
 }
 
 /// 학위과정
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[allow(unused)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum CourseType {
@@ -383,7 +580,7 @@ pub enum CourseType {
 }
 
 /// 과목 점수
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[allow(unused)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum ClassScore {
@@ -409,3 +606,136 @@ impl FromStr for ClassScore {
         })
     }
 }
+
+/// 로그인한 계정의 포털 역할과 학위과정 정보
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct PortalAccount {
+    role: PortalRole,
+    current_course_type: Option<CourseType>,
+    course_types: Vec<CourseType>,
+}
+
+impl PortalAccount {
+    fn role_of(course_type: CourseType) -> PortalRole {
+        match course_type {
+            CourseType::Bachelor => PortalRole::Undergraduate,
+            CourseType::Phd
+            | CourseType::Master
+            | CourseType::PhdIntergrated
+            | CourseType::Research => PortalRole::Graduate,
+        }
+    }
+
+    /// 현재 학위과정이 선택되어 있지 않다면 모든 학위과정이 같은 역할일 때에만 역할을 결정하며, 결정할 수 없다면 `None`을 반환합니다.
+    pub(crate) fn new(
+        current_course_type: Option<CourseType>,
+        course_types: Vec<CourseType>,
+    ) -> Option<PortalAccount> {
+        let role = match current_course_type {
+            Some(course_type) => Self::role_of(course_type),
+            None => {
+                let mut roles = course_types
+                    .iter()
+                    .map(|course_type| Self::role_of(*course_type));
+                let first = roles.next()?;
+                if !roles.all(|role| role == first) {
+                    return None;
+                }
+                first
+            }
+        };
+        Some(PortalAccount {
+            role,
+            current_course_type,
+            course_types,
+        })
+    }
+
+    /// 계정의 포털 역할을 반환합니다.
+    pub fn role(&self) -> PortalRole {
+        self.role
+    }
+
+    /// 성적 조회 화면에 기본으로 선택된 계정의 현재 학위과정을 반환합니다.
+    pub fn current_course_type(&self) -> Option<CourseType> {
+        self.current_course_type
+    }
+
+    /// 계정이 성적을 가지고 있는 학위과정 목록을 반환합니다.
+    pub fn course_types(&self) -> &[CourseType] {
+        &self.course_types
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use serde::{
+        Deserialize,
+        de::{IntoDeserializer, value::MapDeserializer},
+    };
+
+    use super::{CourseType, GradeObjection, GradeObjectionStatus, GradeState, PortalAccount};
+    use crate::model::PortalRole;
+
+    #[test]
+    fn grade_state_from_status() {
+        assert_eq!(GradeState::from_status("Y"), GradeState::Confirmed);
+        assert_eq!(GradeState::from_status(" 확정 "), GradeState::Confirmed);
+        assert_eq!(GradeState::from_status("N"), GradeState::Provisional);
+        assert_eq!(GradeState::from_status("임시성적"), GradeState::Provisional);
+        assert_eq!(GradeState::from_status(""), GradeState::Unknown);
+        assert_eq!(GradeState::from_status("?"), GradeState::Unknown);
+    }
+
+    #[test]
+    fn grade_objection_from_columns() {
+        let columns: HashMap<String, String> = [
+            ("과목코드", " 2150012345 "),
+            ("과목명", "자료구조"),
+            ("신청일자", "2022.12.27"),
+            ("신청사유", ""),
+            ("처리상태", "반려"),
+            ("처리일자", "2022.12.29"),
+            ("변경전성적", "B+"),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+        let map_de: MapDeserializer<_, serde::de::value::Error> = columns.into_deserializer();
+        let objection = GradeObjection::deserialize(map_de).unwrap();
+        assert_eq!(objection.code(), "2150012345");
+        assert_eq!(objection.reason(), None);
+        assert_eq!(objection.status(), &GradeObjectionStatus::Rejected);
+        assert_eq!(objection.process_date(), Some("2022.12.29"));
+        assert_eq!(objection.answer(), None);
+        assert_eq!(objection.previous_rank(), Some("B+"));
+        assert_eq!(objection.changed_rank(), None);
+    }
+
+    #[test]
+    fn role_follows_current_course_type() {
+        let account = PortalAccount::new(
+            Some(CourseType::Master),
+            vec![CourseType::Bachelor, CourseType::Master],
+        )
+        .unwrap();
+        assert_eq!(account.role(), PortalRole::Graduate);
+        let account = PortalAccount::new(
+            Some(CourseType::Bachelor),
+            vec![CourseType::Bachelor, CourseType::Master],
+        )
+        .unwrap();
+        assert_eq!(account.role(), PortalRole::Undergraduate);
+    }
+
+    #[test]
+    fn role_without_current_course_type() {
+        let account = PortalAccount::new(None, vec![CourseType::Phd, CourseType::Master]).unwrap();
+        assert_eq!(account.role(), PortalRole::Graduate);
+        assert!(PortalAccount::new(None, vec![CourseType::Bachelor, CourseType::Master]).is_none());
+        assert!(PortalAccount::new(None, Vec::new()).is_none());
+    }
+}
@@ -0,0 +1,384 @@
+use std::{collections::HashMap, time::Duration};
+
+use crate::{
+    RusaintError,
+    application::utils::poller::{PollTarget, Poller},
+    model::SemesterType,
+};
+
+use super::{
+    CourseGradesApplication,
+    model::{ClassGrade, ClassScore, CourseType, GradeState, SemesterGrade},
+};
+
+/// [`GradeWatcher`]가 발생시키는 성적 변경 이벤트
+#[derive(Debug, Clone)]
+pub enum GradeChangeEvent {
+    /// 새로운 과목의 성적이 입력됨
+    ClassGraded(ClassGrade),
+    /// 이미 입력된 과목의 성적이 변경됨
+    ScoreChanged {
+        /// 변경 전 점수
+        previous: ClassScore,
+        /// 변경 전 등급
+        previous_rank: String,
+        /// 변경된 과목 성적
+        current: ClassGrade,
+    },
+    /// 임시 성적이 확정됨
+    GradeConfirmed(ClassGrade),
+    /// 이전에 읽은 과목이 성적 목록에서 사라짐
+    ClassRemoved(ClassGrade),
+    /// 학기 평점평균이 변경됨
+    GpaChanged {
+        /// 변경 전 평점평균, 학기 성적이 처음 나타난 경우 `None`
+        previous: Option<f32>,
+        /// 변경된 학기 성적
+        current: SemesterGrade,
+    },
+}
+
+/// 특정 학기의 성적을 주기적으로 다시 읽어 변경 사항을 알려주는 감시자
+///
+/// 하나의 [`CourseGradesApplication`]을 계속 재사용하며, 클라이언트를 다시 생성하지 않습니다.
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::model::SemesterType;
+/// # use rusaint::application::course_grades::{ model::CourseType, CourseGradesApplication, watcher::GradeWatcherBuilder };
+/// # use rusaint::application::USaintClientBuilder;
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let app = USaintClientBuilder::new().session(session).build_into::<CourseGradesApplication>().await.unwrap();
+/// let mut watcher = GradeWatcherBuilder::new(CourseType::Bachelor, 2024, SemesterType::Two).build(app);
+/// loop {
+///     for event in watcher.next().await.unwrap() {
+///         println!("{:?}", event);
+///     }
+/// }
+/// # })
+/// ```
+#[derive(Debug)]
+pub struct GradeWatcher(Poller<GradeTarget>);
+
+#[derive(Debug)]
+struct GradeTarget {
+    course_type: CourseType,
+    year: u32,
+    semester: SemesterType,
+    include_details: bool,
+    classes: HashMap<String, ClassGrade>,
+    semester_grade: Option<SemesterGrade>,
+}
+
+impl PollTarget for GradeTarget {
+    type App = CourseGradesApplication;
+    type Event = GradeChangeEvent;
+
+    const DEFAULT_INTERVAL: Duration = Duration::from_secs(10 * 60);
+    const MINIMUM_INTERVAL: Duration = Duration::from_secs(30);
+
+    async fn poll(
+        &mut self,
+        app: &mut CourseGradesApplication,
+    ) -> Result<Vec<GradeChangeEvent>, RusaintError> {
+        let semester_grade = app
+            .semesters(self.course_type)
+            .await?
+            .into_iter()
+            .find(|grade| grade.year() == self.year && grade.semester() == self.semester);
+        let classes = app
+            .classes(
+                self.course_type,
+                self.year,
+                self.semester,
+                self.include_details,
+            )
+            .await?;
+        let mut events = diff_classes(&mut self.classes, classes);
+        if let Some(current) = semester_grade {
+            events.extend(diff_semester_grade(&mut self.semester_grade, current));
+        }
+        Ok(events)
+    }
+}
+
+impl GradeWatcher {
+    /// 성적을 한 번 다시 읽고, 이전에 읽은 성적과 비교하여 변경 사항을 반환합니다.
+    ///
+    /// 처음 호출될 때에는 [`GradeWatcherBuilder::emit_initial`]이 설정되지 않았다면 기준 상태만 기록하고 빈 목록을 반환합니다.
+    /// 마지막으로 읽은 지 30초가 지나지 않았다면 지날 때까지 기다린 후 읽습니다.
+    pub async fn poll(&mut self) -> Result<Vec<GradeChangeEvent>, RusaintError> {
+        self.0.poll().await
+    }
+
+    /// 변경 사항이 생길 때까지 설정된 주기마다 성적을 다시 읽고, 변경 사항을 반환합니다.
+    pub async fn next(&mut self) -> Result<Vec<GradeChangeEvent>, RusaintError> {
+        self.0.next().await
+    }
+
+    /// 기준 성적을 읽었는지 여부를 반환합니다.
+    pub fn initialized(&self) -> bool {
+        self.0.initialized()
+    }
+
+    /// 마지막으로 읽은 과목별 성적을 반환합니다.
+    pub fn classes(&self) -> impl Iterator<Item = &ClassGrade> {
+        self.0.target().classes.values()
+    }
+
+    /// 마지막으로 읽은 학기 성적을 반환합니다.
+    pub fn semester_grade(&self) -> Option<&SemesterGrade> {
+        self.0.target().semester_grade.as_ref()
+    }
+
+    /// 감시에 사용하는 애플리케이션을 반환합니다.
+    pub fn app_mut(&mut self) -> &mut CourseGradesApplication {
+        self.0.app_mut()
+    }
+
+    /// 감시를 종료하고 내부 애플리케이션을 반환합니다.
+    pub fn into_inner(self) -> CourseGradesApplication {
+        self.0.into_inner()
+    }
+}
+
+/// 새로 읽은 과목별 성적을 이전 성적과 비교하여 변경 이벤트를 만들고, 이전 성적을 갱신합니다.
+fn diff_classes(
+    previous_classes: &mut HashMap<String, ClassGrade>,
+    classes: Vec<ClassGrade>,
+) -> Vec<GradeChangeEvent> {
+    let mut events = Vec::new();
+    let mut removed = std::mem::take(previous_classes);
+    for class in classes {
+        let previous = removed.remove(class.code());
+        previous_classes.insert(class.code().to_string(), class.clone());
+        let Some(previous) = previous else {
+            if class.score() != ClassScore::Empty {
+                events.push(GradeChangeEvent::ClassGraded(class));
+            }
+            continue;
+        };
+        if previous.score() == ClassScore::Empty {
+            if class.score() != ClassScore::Empty {
+                events.push(GradeChangeEvent::ClassGraded(class));
+            }
+            continue;
+        }
+        if previous.score() != class.score() || previous.rank() != class.rank() {
+            events.push(GradeChangeEvent::ScoreChanged {
+                previous: previous.score(),
+                previous_rank: previous.rank().to_string(),
+                current: class.clone(),
+            });
+        }
+        if previous.state() == GradeState::Provisional && class.state() == GradeState::Confirmed {
+            events.push(GradeChangeEvent::GradeConfirmed(class));
+        }
+    }
+    let mut removed = removed.into_values().collect::<Vec<_>>();
+    removed.sort_by(|a, b| a.code().cmp(b.code()));
+    events.extend(removed.into_iter().map(GradeChangeEvent::ClassRemoved));
+    events
+}
+
+/// 새로 읽은 학기 성적의 평점평균이 이전과 다르다면 변경 이벤트를 만들고, 이전 학기 성적을 갱신합니다.
+fn diff_semester_grade(
+    previous_grade: &mut Option<SemesterGrade>,
+    current: SemesterGrade,
+) -> Option<GradeChangeEvent> {
+    let previous = previous_grade
+        .as_ref()
+        .map(SemesterGrade::grade_points_average);
+    let event =
+        (previous != Some(current.grade_points_average())).then(|| GradeChangeEvent::GpaChanged {
+            previous,
+            current: current.clone(),
+        });
+    *previous_grade = Some(current);
+    event
+}
+
+/// 새로운 [`GradeWatcher`]를 생성하는 빌더
+#[derive(Debug)]
+pub struct GradeWatcherBuilder {
+    course_type: CourseType,
+    year: u32,
+    semester: SemesterType,
+    interval: Duration,
+    include_details: bool,
+    emit_initial: bool,
+}
+
+impl GradeWatcherBuilder {
+    /// 감시할 학위과정과 학기로 새로운 빌더를 만듭니다.
+    pub fn new(course_type: CourseType, year: u32, semester: SemesterType) -> GradeWatcherBuilder {
+        GradeWatcherBuilder {
+            course_type,
+            year,
+            semester,
+            interval: GradeTarget::DEFAULT_INTERVAL,
+            include_details: false,
+            emit_initial: false,
+        }
+    }
+
+    /// 성적을 다시 읽는 주기를 설정합니다. 기본값은 10분이며, 30초보다 짧게 설정할 수 없습니다.
+    pub fn interval(mut self, interval: Duration) -> GradeWatcherBuilder {
+        self.interval = interval.max(GradeTarget::MINIMUM_INTERVAL);
+        self
+    }
+
+    /// 상세 성적도 함께 읽을지 설정합니다. 상세 성적이 있는 과목 수 만큼 요청이 늘어납니다.
+    pub fn include_details(mut self, include_details: bool) -> GradeWatcherBuilder {
+        self.include_details = include_details;
+        self
+    }
+
+    /// 처음 읽은 성적도 이벤트로 발생시킬지 설정합니다.
+    pub fn emit_initial(mut self, emit_initial: bool) -> GradeWatcherBuilder {
+        self.emit_initial = emit_initial;
+        self
+    }
+
+    /// 주어진 애플리케이션으로 [`GradeWatcher`]를 생성합니다.
+    pub fn build(self, app: CourseGradesApplication) -> GradeWatcher {
+        let target = GradeTarget {
+            course_type: self.course_type,
+            year: self.year,
+            semester: self.semester,
+            include_details: self.include_details,
+            classes: HashMap::new(),
+            semester_grade: None,
+        };
+        GradeWatcher(Poller::new(app, target, self.interval, self.emit_initial))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::{GradeChangeEvent, diff_classes};
+    use crate::{
+        application::course_grades::model::{ClassGrade, ClassScore, GradeState},
+        model::SemesterType,
+    };
+
+    fn class(code: &str, score: ClassScore, rank: &str, state: GradeState) -> ClassGrade {
+        ClassGrade::new(
+            2024,
+            SemesterType::Two,
+            code.to_string(),
+            code.to_string(),
+            3.0,
+            score,
+            rank.to_string(),
+            String::new(),
+            None,
+            state,
+        )
+    }
+
+    #[test]
+    fn reports_newly_graded_classes() {
+        let mut previous = HashMap::new();
+        let events = diff_classes(
+            &mut previous,
+            vec![
+                class("A", ClassScore::Empty, "", GradeState::Provisional),
+                class("B", ClassScore::Score(90), "A0", GradeState::Provisional),
+            ],
+        );
+        assert!(
+            matches!(&events[..], [GradeChangeEvent::ClassGraded(class)] if class.code() == "B")
+        );
+        let events = diff_classes(
+            &mut previous,
+            vec![
+                class("A", ClassScore::Score(80), "B0", GradeState::Provisional),
+                class("B", ClassScore::Score(90), "A0", GradeState::Provisional),
+            ],
+        );
+        assert!(
+            matches!(&events[..], [GradeChangeEvent::ClassGraded(class)] if class.code() == "A")
+        );
+        assert_eq!(previous.len(), 2);
+    }
+
+    #[test]
+    fn reports_both_score_change_and_confirmation() {
+        let mut previous = HashMap::new();
+        diff_classes(
+            &mut previous,
+            vec![class(
+                "A",
+                ClassScore::Score(85),
+                "B+",
+                GradeState::Provisional,
+            )],
+        );
+        let events = diff_classes(
+            &mut previous,
+            vec![class(
+                "A",
+                ClassScore::Score(90),
+                "A0",
+                GradeState::Confirmed,
+            )],
+        );
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            &events[0],
+            GradeChangeEvent::ScoreChanged { previous: ClassScore::Score(85), previous_rank, current }
+                if previous_rank == "B+" && current.rank() == "A0"
+        ));
+        assert!(
+            matches!(&events[1], GradeChangeEvent::GradeConfirmed(class) if class.code() == "A")
+        );
+    }
+
+    #[test]
+    fn reports_confirmation_without_score_change() {
+        let mut previous = HashMap::new();
+        diff_classes(
+            &mut previous,
+            vec![class("A", ClassScore::Pass, "P", GradeState::Provisional)],
+        );
+        let events = diff_classes(
+            &mut previous,
+            vec![class("A", ClassScore::Pass, "P", GradeState::Confirmed)],
+        );
+        assert!(matches!(&events[..], [GradeChangeEvent::GradeConfirmed(_)]));
+        let events = diff_classes(
+            &mut previous,
+            vec![class("A", ClassScore::Pass, "P", GradeState::Confirmed)],
+        );
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn reports_removed_classes() {
+        let mut previous = HashMap::new();
+        diff_classes(
+            &mut previous,
+            vec![
+                class("A", ClassScore::Score(90), "A0", GradeState::Provisional),
+                class("B", ClassScore::Empty, "", GradeState::Provisional),
+            ],
+        );
+        let events = diff_classes(
+            &mut previous,
+            vec![class(
+                "A",
+                ClassScore::Score(90),
+                "A0",
+                GradeState::Provisional,
+            )],
+        );
+        assert!(
+            matches!(&events[..], [GradeChangeEvent::ClassRemoved(class)] if class.code() == "B")
+        );
+        assert!(!previous.contains_key("B"));
+    }
+}
@@ -0,0 +1,180 @@
+use self::model::{RegisteredLectures, RegistrationLecture, rejection_error};
+use super::{USaintApplication, USaintClient};
+use crate::application::utils::popup::{close_popups, confirm_popup, popup_result};
+use crate::application::utils::sap_table::{select_row_with_scroll, try_table_into_with_scroll};
+use crate::webdynpro::command::WebDynproCommandExecutor;
+use crate::webdynpro::element::parser::ElementParser;
+use crate::{
+    RusaintError, define_elements,
+    error::ApplicationError,
+    webdynpro::{
+        client::body::Body,
+        command::element::{action::ButtonPressEventCommand, text::InputFieldChangeEventCommand},
+        element::{
+            action::{Button, ButtonDef},
+            complex::{SapTable, SapTableDef},
+            text::InputField,
+        },
+    },
+};
+
+/// [수강신청](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW2110)
+#[derive(Debug)]
+pub struct CourseRegistrationApplication {
+    client: USaintClient,
+}
+
+impl USaintApplication for CourseRegistrationApplication {
+    const APP_NAME: &'static str = "ZCMW2110";
+
+    fn from_client(client: USaintClient) -> Result<Self, RusaintError> {
+        if client.name() != Self::APP_NAME {
+            Err(RusaintError::InvalidClientError)
+        } else {
+            Ok(Self { client })
+        }
+    }
+}
+
+impl<'a> CourseRegistrationApplication {
+    define_elements! {
+        CART_TABLE: SapTable<'a> = "ZCMW2110.ID_0001:VIW_MAIN.TABLE_CART";
+        REGISTERED_TABLE: SapTable<'a> = "ZCMW2110.ID_0001:VIW_MAIN.TABLE_REGIST";
+        INPUT_CODE: InputField<'a> = "ZCMW2110.ID_0001:VIW_MAIN.SM_OBJID";
+        INPUT_DIVISION: InputField<'a> = "ZCMW2110.ID_0001:VIW_MAIN.SE_SHORT";
+        MAX_CREDITS: InputField<'a> = "ZCMW2110.ID_0001:VIW_MAIN.MAX_CREDIT";
+        BTN_ENROLL: Button<'a> = "ZCMW2110.ID_0001:VIW_MAIN.BTN_REGIST";
+        BTN_ENROLL_CART: Button<'a> = "ZCMW2110.ID_0001:VIW_MAIN.BTN_CART_REGIST";
+        BTN_DROP: Button<'a> = "ZCMW2110.ID_0001:VIW_MAIN.BTN_CANCEL";
+    }
+
+    const CONFIRM_LABELS: [&'static str; 2] = ["예", "확인"];
+
+    fn body(&self) -> &Body {
+        self.client.body()
+    }
+
+    /// 요청 후 표시된 팝업을 닫고, 팝업이 성공 또는 정보 메시지가 아니라면 팝업의 메시지를 거부 사유로 반환합니다.
+    async fn check_result(&mut self) -> Result<(), RusaintError> {
+        let result = popup_result(&ElementParser::new(self.body()));
+        close_popups(&mut self.client).await?;
+        result
+            .map(|_| ())
+            .map_err(|message| rejection_error(message).into())
+    }
+
+    /// 테이블에서 과목번호와 분반이 일치하는 행을 선택합니다.
+    async fn select_row(
+        &mut self,
+        table: SapTableDef,
+        code: &str,
+        division: Option<&str>,
+    ) -> Result<(), RusaintError> {
+        if select_row_with_scroll(&mut self.client, table, |lecture: &RegistrationLecture| {
+            lecture.matches(code, division)
+        })
+        .await?
+        {
+            Ok(())
+        } else {
+            Err(ApplicationError::NoLectureResult.into())
+        }
+    }
+
+    async fn press(&mut self, button: ButtonDef) -> Result<(), RusaintError> {
+        let event = ElementParser::new(self.body()).read(ButtonPressEventCommand::new(button))?;
+        self.client.process_event(false, event).await?;
+        Ok(())
+    }
+
+    /// 장바구니에 담긴 강의 목록을 가져옵니다.
+    pub async fn cart(&mut self) -> Result<Vec<RegistrationLecture>, RusaintError> {
+        close_popups(&mut self.client).await?;
+        let parser = ElementParser::new(self.body());
+        Ok(try_table_into_with_scroll::<RegistrationLecture>(
+            &mut self.client,
+            parser,
+            Self::CART_TABLE,
+        )
+        .await?)
+    }
+
+    /// 현재 수강신청한 강의 목록과 학점 정보를 가져옵니다.
+    pub async fn registered(&mut self) -> Result<RegisteredLectures, RusaintError> {
+        close_popups(&mut self.client).await?;
+        let parser = ElementParser::new(self.body());
+        let max_credits = parser
+            .element_from_def(&Self::MAX_CREDITS)
+            .and_then(|field| field.value_into_f32())
+            .ok();
+        let lectures = try_table_into_with_scroll::<RegistrationLecture>(
+            &mut self.client,
+            parser,
+            Self::REGISTERED_TABLE,
+        )
+        .await?;
+        Ok(RegisteredLectures::new(lectures, max_credits))
+    }
+
+    /// 과목번호와 분반을 직접 입력하여 강의를 신청하고, 신청 후의 수강신청 내역을 반환합니다.
+    pub async fn enroll(
+        &mut self,
+        code: &str,
+        division: Option<&str>,
+    ) -> Result<RegisteredLectures, RusaintError> {
+        close_popups(&mut self.client).await?;
+        let (code_event, division_event) = {
+            let parser = ElementParser::new(self.body());
+            (
+                parser.read(InputFieldChangeEventCommand::new(Self::INPUT_CODE, code))?,
+                parser.read(InputFieldChangeEventCommand::new(
+                    Self::INPUT_DIVISION,
+                    division.unwrap_or(""),
+                ))?,
+            )
+        };
+        self.client.process_event(false, code_event).await?;
+        self.client.process_event(false, division_event).await?;
+        self.press(Self::BTN_ENROLL).await?;
+        self.check_result().await?;
+        self.registered().await
+    }
+
+    /// 장바구니에 담긴 강의를 신청하고, 신청 후의 수강신청 내역을 반환합니다.
+    pub async fn enroll_from_cart(
+        &mut self,
+        code: &str,
+        division: Option<&str>,
+    ) -> Result<RegisteredLectures, RusaintError> {
+        close_popups(&mut self.client).await?;
+        self.select_row(Self::CART_TABLE, code, division).await?;
+        self.press(Self::BTN_ENROLL_CART).await?;
+        self.check_result().await?;
+        self.registered().await
+    }
+
+    /// 신청한 강의를 취소하고, 취소 후의 수강신청 내역을 반환합니다.
+    pub async fn drop_lecture(
+        &mut self,
+        code: &str,
+        division: Option<&str>,
+    ) -> Result<RegisteredLectures, RusaintError> {
+        close_popups(&mut self.client).await?;
+        self.select_row(Self::REGISTERED_TABLE, code, division)
+            .await?;
+        self.press(Self::BTN_DROP).await?;
+        if !confirm_popup(&mut self.client, &Self::CONFIRM_LABELS).await? {
+            // 취소 확인 질문 대신 표시된 팝업은 취소 요청이 거부되었음을 나타냄
+            self.check_result().await?;
+            return Err(ApplicationError::RegistrationRejected(
+                "Drop confirmation was not requested".to_string(),
+            )
+            .into());
+        }
+        self.check_result().await?;
+        self.registered().await
+    }
+}
+
+/// [`CourseRegistrationApplication`] 애플리케이션에 사용되는 데이터
+pub mod model;
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use serde::{
+    Deserialize, Serialize,
+    de::{IntoDeserializer, value::MapDeserializer},
+};
+
+use crate::application::utils::de_with::{deserialize_f32_string, deserialize_optional_string};
+use crate::error::ApplicationError;
+use crate::webdynpro::{
+    element::{
+        complex::sap_table::{FromSapTable, SapTableHeader, SapTableRow},
+        definition::ElementDefinition,
+        parser::ElementParser,
+    },
+    error::{ElementError, WebDynproError},
+};
+
+/// 장바구니 또는 수강신청 내역의 강의
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct RegistrationLecture {
+    /// 이수구분
+    #[serde(
+        rename(deserialize = "이수구분"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    category: Option<String>,
+    /// 과목번호
+    #[serde(rename(deserialize = "과목번호"))]
+    code: String,
+    /// 과목명
+    #[serde(rename(deserialize = "과목명"))]
+    name: String,
+    /// 분반
+    #[serde(
+        rename(deserialize = "분반"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    division: Option<String>,
+    /// 교수명
+    #[serde(
+        rename(deserialize = "교수명"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    professor: Option<String>,
+    /// 학점
+    #[serde(
+        rename(deserialize = "학점"),
+        deserialize_with = "deserialize_f32_string"
+    )]
+    credit: f32,
+    /// 강의시간(강의실)
+    #[serde(
+        rename(deserialize = "강의시간(강의실)"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    schedule_room: Option<String>,
+}
+
+impl RegistrationLecture {
+    /// 이수구분
+    pub fn category(&self) -> Option<&str> {
+        self.category.as_deref()
+    }
+
+    /// 과목번호
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// 과목명
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 분반
+    pub fn division(&self) -> Option<&str> {
+        self.division.as_deref()
+    }
+
+    /// 교수명
+    pub fn professor(&self) -> Option<&str> {
+        self.professor.as_deref()
+    }
+
+    /// 학점
+    pub fn credit(&self) -> f32 {
+        self.credit
+    }
+
+    /// 강의시간(강의실)
+    pub fn schedule_room(&self) -> Option<&str> {
+        self.schedule_room.as_deref()
+    }
+
+    /// 과목번호와 분반이 일치하는지 확인합니다. 분반이 주어지지 않으면 과목번호만 비교합니다.
+    pub fn matches(&self, code: &str, division: Option<&str>) -> bool {
+        self.code.trim() == code.trim()
+            && division.is_none_or(|division| {
+                self.division.as_deref().map(str::trim) == Some(division.trim())
+            })
+    }
+}
+
+impl<'body> FromSapTable<'body> for RegistrationLecture {
+    fn from_table(
+        header: &'body SapTableHeader,
+        row: &'body SapTableRow,
+        parser: &'body ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let map_string = row.try_row_into::<HashMap<String, String>>(header, parser)?;
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map_string.into_deserializer();
+        Ok(
+            RegistrationLecture::deserialize(map_de).map_err(|e| ElementError::InvalidContent {
+                element: row.table_def().id().to_string(),
+                content: e.to_string(),
+            })?,
+        )
+    }
+}
+
+/// 현재 수강신청 내역
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct RegisteredLectures {
+    lectures: Vec<RegistrationLecture>,
+    total_credits: f32,
+    max_credits: Option<f32>,
+}
+
+impl RegisteredLectures {
+    pub(crate) fn new(lectures: Vec<RegistrationLecture>, max_credits: Option<f32>) -> Self {
+        let total_credits = lectures.iter().map(RegistrationLecture::credit).sum();
+        Self {
+            lectures,
+            total_credits,
+            max_credits,
+        }
+    }
+
+    /// 신청한 강의 목록
+    pub fn lectures(&self) -> &[RegistrationLecture] {
+        &self.lectures
+    }
+
+    /// 신청한 총 학점
+    pub fn total_credits(&self) -> f32 {
+        self.total_credits
+    }
+
+    /// 신청 가능한 최대 학점
+    pub fn max_credits(&self) -> Option<f32> {
+        self.max_credits
+    }
+
+    /// 추가로 신청 가능한 학점
+    pub fn remaining_credits(&self) -> Option<f32> {
+        self.max_credits
+            .map(|max| (max - self.total_credits).max(0.0))
+    }
+}
+
+/// 수강신청 요청 후 표시된 메시지를 거부 사유에 맞는 오류로 변환합니다. 모든 오류는 메시지 내용을 포함합니다.
+pub(crate) fn rejection_error(message: String) -> ApplicationError {
+    if message.contains("시간") && (message.contains("중복") || message.contains("겹")) {
+        ApplicationError::TimeConflict(message)
+    } else if message.contains("학점") && (message.contains("초과") || message.contains("최대"))
+    {
+        ApplicationError::CreditLimitExceeded(message)
+    } else if message.contains("정원") || message.contains("여석") {
+        ApplicationError::LectureFull(message)
+    } else {
+        ApplicationError::RegistrationRejected(message)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::rejection_error;
+    use crate::error::ApplicationError;
+
+    #[test]
+    fn classify_registration_errors() {
+        let classify = |message: &str| rejection_error(message.to_string());
+        assert!(matches!(
+            classify("수강 정원이 초과되어 신청할 수 없습니다."),
+            ApplicationError::LectureFull(message) if message.contains("정원")
+        ));
+        assert!(matches!(
+            classify("이미 신청한 과목과 강의시간이 중복됩니다."),
+            ApplicationError::TimeConflict(_)
+        ));
+        assert!(matches!(
+            classify("신청 가능 최대 학점을 초과하였습니다."),
+            ApplicationError::CreditLimitExceeded(_)
+        ));
+        assert!(matches!(
+            classify("수강신청 기간이 아닙니다."),
+            ApplicationError::RegistrationRejected(_)
+        ));
+    }
+}
@@ -0,0 +1,319 @@
+use std::collections::{BTreeMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::model::{Lecture, LectureCategory};
+
+/// 강의를 구분하는 과목번호와 분반
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct LectureKey {
+    code: String,
+    division: Option<String>,
+}
+
+impl LectureKey {
+    /// 과목번호와 분반으로 새로운 [`LectureKey`]를 만듭니다.
+    pub fn new(code: &str, division: Option<&str>) -> LectureKey {
+        LectureKey {
+            code: code.trim().to_string(),
+            division: division
+                .map(str::trim)
+                .filter(|division| !division.is_empty())
+                .map(str::to_string),
+        }
+    }
+
+    /// 강의의 [`LectureKey`]를 반환합니다.
+    pub fn of(lecture: &Lecture) -> LectureKey {
+        LectureKey::new(lecture.code(), lecture.division())
+    }
+
+    /// 과목번호
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// 분반
+    pub fn division(&self) -> Option<&str> {
+        self.division.as_deref()
+    }
+}
+
+/// [`LectureCatalog`]에 포함된 강의와 강의가 검색된 분류 목록
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CatalogEntry {
+    lecture: Lecture,
+    categories: Vec<LectureCategory>,
+}
+
+impl CatalogEntry {
+    /// 강의 정보
+    pub fn lecture(&self) -> &Lecture {
+        &self.lecture
+    }
+
+    /// 강의가 검색된 분류 목록
+    pub fn categories(&self) -> &[LectureCategory] {
+        &self.categories
+    }
+}
+
+/// 한 학기의 강의를 과목번호와 분반으로 중복 없이 모아 둔 카탈로그
+///
+/// 직렬화 시 [`CatalogEntry`]의 배열로 표현되므로, 저장해 두었다가 다음 수집 결과와 [`diff`](LectureCatalog::diff)할 수 있습니다.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(from = "Vec<CatalogEntry>", into = "Vec<CatalogEntry>")]
+pub struct LectureCatalog {
+    entries: BTreeMap<LectureKey, CatalogEntry>,
+}
+
+impl LectureCatalog {
+    /// 빈 카탈로그를 만듭니다.
+    pub fn new() -> LectureCatalog {
+        LectureCatalog::default()
+    }
+
+    /// 주어진 분류에서 검색된 강의들을 카탈로그에 추가합니다.
+    ///
+    /// 이미 존재하는 강의는 새 강의 정보로 갱신되고, 분류 목록에 주어진 분류가 추가됩니다.
+    pub fn insert(
+        &mut self,
+        category: &LectureCategory,
+        lectures: impl IntoIterator<Item = Lecture>,
+    ) {
+        for lecture in lectures {
+            let key = LectureKey::of(&lecture);
+            match self.entries.get_mut(&key) {
+                Some(entry) => {
+                    entry.lecture = lecture;
+                    if !entry.categories.contains(category) {
+                        entry.categories.push(category.clone());
+                    }
+                }
+                None => {
+                    self.entries.insert(
+                        key,
+                        CatalogEntry {
+                            lecture,
+                            categories: vec![category.clone()],
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    /// 과목번호와 분반에 해당하는 강의를 반환합니다.
+    pub fn get(&self, key: &LectureKey) -> Option<&CatalogEntry> {
+        self.entries.get(key)
+    }
+
+    /// 카탈로그에 포함된 강의 수를 반환합니다.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// 카탈로그가 비어있는지 여부를 반환합니다.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 과목번호와 분반 순으로 정렬된 카탈로그 항목을 반환합니다.
+    pub fn entries(&self) -> impl Iterator<Item = &CatalogEntry> {
+        self.entries.values()
+    }
+
+    /// 과목번호와 분반 순으로 정렬된 강의를 반환합니다.
+    pub fn lectures(&self) -> impl Iterator<Item = &Lecture> {
+        self.entries.values().map(CatalogEntry::lecture)
+    }
+
+    /// 이 카탈로그를 이전 상태로 보고, 새로운 카탈로그와의 차이를 반환합니다.
+    pub fn diff(&self, current: &LectureCatalog) -> CatalogDiff {
+        let added = current
+            .entries
+            .iter()
+            .filter(|(key, _)| !self.entries.contains_key(key))
+            .map(|(_, entry)| entry.lecture.clone())
+            .collect();
+        let removed = self
+            .entries
+            .iter()
+            .filter(|(key, _)| !current.entries.contains_key(key))
+            .map(|(_, entry)| entry.lecture.clone())
+            .collect();
+        let seat_changes = self
+            .entries
+            .iter()
+            .filter_map(|(key, previous)| {
+                let current = current.entries.get(key)?;
+                let change = SeatChange {
+                    key: key.clone(),
+                    previous_personeel: previous.lecture.personeel_count(),
+                    current_personeel: current.lecture.personeel_count(),
+                    previous_remaining_seats: previous.lecture.remaining_seats_count(),
+                    current_remaining_seats: current.lecture.remaining_seats_count(),
+                    lecture: current.lecture.clone(),
+                };
+                (change.previous_personeel != change.current_personeel
+                    || change.previous_remaining_seats != change.current_remaining_seats)
+                    .then_some(change)
+            })
+            .collect();
+        CatalogDiff {
+            added,
+            removed,
+            seat_changes,
+        }
+    }
+
+    /// 카탈로그에 포함된 모든 분류를 중복 없이 반환합니다.
+    pub fn categories(&self) -> Vec<&LectureCategory> {
+        let mut seen = HashSet::new();
+        self.entries
+            .values()
+            .flat_map(|entry| entry.categories.iter())
+            .filter(|category| seen.insert(category.to_string()))
+            .collect()
+    }
+}
+
+impl From<Vec<CatalogEntry>> for LectureCatalog {
+    fn from(entries: Vec<CatalogEntry>) -> Self {
+        LectureCatalog {
+            entries: entries
+                .into_iter()
+                .map(|entry| (LectureKey::of(&entry.lecture), entry))
+                .collect(),
+        }
+    }
+}
+
+impl From<LectureCatalog> for Vec<CatalogEntry> {
+    fn from(catalog: LectureCatalog) -> Self {
+        catalog.entries.into_values().collect()
+    }
+}
+
+/// 두 [`LectureCatalog`] 사이의 차이
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CatalogDiff {
+    added: Vec<Lecture>,
+    removed: Vec<Lecture>,
+    seat_changes: Vec<SeatChange>,
+}
+
+impl CatalogDiff {
+    /// 새로 개설된 강의
+    pub fn added(&self) -> &[Lecture] {
+        &self.added
+    }
+
+    /// 폐강되었거나 더 이상 검색되지 않는 강의
+    pub fn removed(&self) -> &[Lecture] {
+        &self.removed
+    }
+
+    /// 수강인원이나 여석이 변경된 강의
+    pub fn seat_changes(&self) -> &[SeatChange] {
+        &self.seat_changes
+    }
+
+    /// 변경 사항이 없는지 여부를 반환합니다.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.seat_changes.is_empty()
+    }
+}
+
+/// 강의의 수강인원 및 여석 변경 내역
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SeatChange {
+    key: LectureKey,
+    previous_personeel: Option<u32>,
+    current_personeel: Option<u32>,
+    previous_remaining_seats: Option<u32>,
+    current_remaining_seats: Option<u32>,
+    lecture: Lecture,
+}
+
+impl SeatChange {
+    /// 변경된 강의의 과목번호와 분반
+    pub fn key(&self) -> &LectureKey {
+        &self.key
+    }
+
+    /// 변경 전 수강인원
+    pub fn previous_personeel(&self) -> Option<u32> {
+        self.previous_personeel
+    }
+
+    /// 변경 후 수강인원
+    pub fn current_personeel(&self) -> Option<u32> {
+        self.current_personeel
+    }
+
+    /// 변경 전 여석
+    pub fn previous_remaining_seats(&self) -> Option<u32> {
+        self.previous_remaining_seats
+    }
+
+    /// 변경 후 여석
+    pub fn current_remaining_seats(&self) -> Option<u32> {
+        self.current_remaining_seats
+    }
+
+    /// 변경 후 강의 정보
+    pub fn lecture(&self) -> &Lecture {
+        &self.lecture
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{LectureCatalog, LectureKey};
+    use crate::application::course_schedule::model::{Lecture, LectureCategory, lecture_fixture};
+
+    fn lecture(code: &str, division: &str, remaining_seats: &str) -> Lecture {
+        lecture_fixture(&[
+            ("과목번호", code),
+            ("과목명", code),
+            ("분반", division),
+            ("여석", remaining_seats),
+        ])
+    }
+
+    #[test]
+    fn deduplicate_with_categories() {
+        let major = LectureCategory::major("IT대학", "컴퓨터학부", None);
+        let search = LectureCategory::find_by_lecture("A");
+        let mut catalog = LectureCatalog::new();
+        catalog.insert(&major, [lecture("A", "01", "3"), lecture("A", "02", "3")]);
+        catalog.insert(&search, [lecture("A", "01", "2")]);
+        assert_eq!(catalog.len(), 2);
+        let entry = catalog.get(&LectureKey::new("A", Some("01"))).unwrap();
+        assert_eq!(entry.categories(), &[major, search]);
+        assert_eq!(entry.lecture().remaining_seats(), "2");
+    }
+
+    #[test]
+    fn diff_catalogs() {
+        let category = LectureCategory::cyber();
+        let mut previous = LectureCatalog::new();
+        previous.insert(
+            &category,
+            [lecture("A", "01", "3"), lecture("B", "01", "3")],
+        );
+        let mut current = LectureCatalog::new();
+        current.insert(
+            &category,
+            [lecture("A", "01", "0"), lecture("C", "01", "3")],
+        );
+        let diff = previous.diff(&current);
+        assert_eq!(diff.added()[0].code(), "C");
+        assert_eq!(diff.removed()[0].code(), "B");
+        assert_eq!(diff.seat_changes().len(), 1);
+        assert_eq!(diff.seat_changes()[0].previous_remaining_seats(), Some(3));
+        assert_eq!(diff.seat_changes()[0].current_remaining_seats(), Some(0));
+        assert!(previous.diff(&previous).is_empty());
+    }
+}
@@ -1,30 +1,43 @@
 use super::{USaintApplication, USaintClient};
+use crate::application::course_schedule::catalog::LectureCatalog;
+use crate::application::course_schedule::query::{LectureQuery, LectureRows};
 use crate::application::course_schedule::utils::{
     combo_box_items, select_lv1, select_lv2, select_tab,
 };
-use crate::application::utils::sap_table::try_table_into_with_scroll;
+use crate::application::utils::popup::{close_popups, has_popup, popup_fields, popup_tables};
+use crate::application::utils::sap_table::{find_row_with_scroll, try_table_into_with_scroll};
 use crate::application::utils::semester::get_selected_semester;
 use crate::webdynpro::command::WebDynproCommandExecutor;
 use crate::webdynpro::element::layout::tab_strip::item::TabStripItem;
 use crate::webdynpro::element::parser::ElementParser;
 use crate::{
     ApplicationError, RusaintError,
-    application::course_schedule::model::{Lecture, LectureCategory},
+    application::course_schedule::model::{
+        CollageCategory, DepartmentCategory, Lecture, LectureCategory, LectureCategoryTree,
+        Syllabus,
+    },
     define_elements,
     model::SemesterType,
     webdynpro::{
         client::body::Body,
-        command::element::{complex::SapTableBodyCommand, selection::ComboBoxSelectEventCommand},
+        command::element::{
+            complex::SapTableBodyCommand,
+            selection::{
+                ComboBoxLSDataCommand, ComboBoxSelectByValue1EventCommand,
+                ComboBoxSelectEventCommand,
+            },
+        },
         element::{
             ElementDefWrapper,
             complex::{
                 SapTable,
                 sap_table::cell::{SapTableCell, SapTableCellWrapper},
             },
-            layout::TabStrip,
-            selection::ComboBox,
+            layout::{TabStrip, tab_strip::item::TabStripItemDef},
+            selection::{ComboBox, ComboBoxDef},
         },
         error::WebDynproError,
+        event::Event,
     },
 };
 
@@ -53,8 +66,18 @@ impl<'app> CourseScheduleApplication {
         PERIOD_YEAR: ComboBox<'app> = "ZCMW_PERIOD_RE.ID_A61C4ED604A2BFC2A8F6C6038DE6AF18:VIW_MAIN.PERYR";
         PERIOD_ID: ComboBox<'app> = "ZCMW_PERIOD_RE.ID_A61C4ED604A2BFC2A8F6C6038DE6AF18:VIW_MAIN.PERID";
         TABLE_ROWS: ComboBox<'app> = "ZCMW2100.ID_0001:VIW_MODULES.ROWS";
+        // 검색 필터
+        FILTER_GRADE: ComboBox<'app> = "ZCMW2100.ID_0001:VIW_MODULES.FILTER_GRADE";
+        FILTER_WEEKDAY: ComboBox<'app> = "ZCMW2100.ID_0001:VIW_MODULES.FILTER_DAY";
+        FILTER_TIME: ComboBox<'app> = "ZCMW2100.ID_0001:VIW_MODULES.FILTER_TIME";
+        FILTER_LANGUAGE: ComboBox<'app> = "ZCMW2100.ID_0001:VIW_MODULES.FILTER_LANGU";
         TABSTRIP: TabStrip<'app> = "ZCMW2100.ID_0001:VIW_MAIN.MODULE_TABSTRIP";
         MAIN_TABLE: SapTable<'app> = "SALV_WD_TABLE.ID_DE0D9128A4327646C94670E2A892C99C:VIEW_TABLE.SALV_WD_UIE_TABLE";
+        // 타전공인정과목 분류 탭
+        TAB_OTHER_GC: TabStripItem<'app> = "ZCMW2100.ID_0001:VIW_MAIN.TAB_OTHER_GC";
+        OTHER_GC_DDK_LV3: ComboBox<'app> = "ZCMW2100.ID_0001:VIW_TAB_OTHER_GC.DDK_LV3";
+        OTHER_GC_DDK_LV4: ComboBox<'app> = "ZCMW2100.ID_0001:VIW_TAB_OTHER_GC.DDK_LV4";
+        OTHER_GC_DDK_LV5: ComboBox<'app> = "ZCMW2100.ID_0001:VIW_TAB_OTHER_GC.DDK_LV5";
     }
 
     fn semester_to_key(semester: SemesterType) -> &'static str {
@@ -92,17 +115,54 @@ impl<'app> CourseScheduleApplication {
     async fn select_rows(
         &mut self,
         parser: &ElementParser,
-        row: u32,
+        rows: LectureRows,
     ) -> Result<(), WebDynproError> {
         let event = parser.read(ComboBoxSelectEventCommand::new(
             Self::TABLE_ROWS,
-            row.to_string().as_str(),
+            rows.key(),
             false,
         ))?;
         self.client.process_event(false, event).await?;
         Ok(())
     }
 
+    // 필터 값이 없다면 이전 검색에서 설정한 필터를 해제합니다.
+    async fn select_filter(
+        &mut self,
+        filter: ComboBoxDef,
+        value: Option<&str>,
+    ) -> Result<(), WebDynproError> {
+        let parser = ElementParser::new(self.body());
+        let event = match value {
+            Some(value) => parser.read(ComboBoxSelectByValue1EventCommand::new(
+                filter, value, false,
+            ))?,
+            None => {
+                let Ok(lsdata) = parser.read(ComboBoxLSDataCommand::new(filter.clone())) else {
+                    return Ok(());
+                };
+                if lsdata.key().is_none_or(|key| key.is_empty()) {
+                    return Ok(());
+                }
+                parser.read(ComboBoxSelectEventCommand::new(filter, "", false))?
+            }
+        };
+        self.client.process_event(false, event).await?;
+        Ok(())
+    }
+
+    async fn select_filters(&mut self, query: &LectureQuery) -> Result<(), WebDynproError> {
+        self.select_filter(Self::FILTER_GRADE, query.grade_filter().as_deref())
+            .await?;
+        self.select_filter(Self::FILTER_WEEKDAY, query.weekday_filter())
+            .await?;
+        self.select_filter(Self::FILTER_TIME, query.start_time_filter().as_deref())
+            .await?;
+        self.select_filter(Self::FILTER_LANGUAGE, query.language_filter())
+            .await?;
+        Ok(())
+    }
+
     fn body(&self) -> &Body {
         self.client.body()
     }
@@ -334,13 +394,44 @@ impl<'app> CourseScheduleApplication {
         semester: SemesterType,
         lecture_category: &LectureCategory,
     ) -> Result<impl Iterator<Item = Lecture>, RusaintError> {
+        let query = LectureQuery::new(lecture_category.clone());
+        Ok(self
+            .search_lectures(year, semester, &query)
+            .await?
+            .into_iter())
+    }
+
+    /// 학기, 학년도와 [`LectureQuery`]를 통해 강의를 찾습니다.
+    ///
+    /// 쿼리의 강의 분류와 필터로 검색한 뒤, 교수명과 여석 조건을 만족하는 강의만 반환합니다.
+    pub async fn query_lectures(
+        &mut self,
+        year: u32,
+        semester: SemesterType,
+        query: &LectureQuery,
+    ) -> Result<Vec<Lecture>, RusaintError> {
+        Ok(self
+            .search_lectures(year, semester, query)
+            .await?
+            .into_iter()
+            .filter(|lecture| query.matches(lecture))
+            .collect())
+    }
+
+    async fn search_lectures(
+        &mut self,
+        year: u32,
+        semester: SemesterType,
+        query: &LectureQuery,
+    ) -> Result<Vec<Lecture>, RusaintError> {
         {
             let parser = ElementParser::new(self.body());
             let year_str = format!("{year}");
-            self.select_rows(&parser, 500).await?;
+            self.select_rows(&parser, query.row_count()).await?;
             self.select_semester(&parser, &year_str, semester).await?;
         }
-        lecture_category.request_query(&mut self.client.0).await?;
+        self.select_filters(query).await?;
+        query.category().request_query(&mut self.client.0).await?;
         let parser = ElementParser::new(self.body());
         let table = parser.read(SapTableBodyCommand::new(Self::MAIN_TABLE))?;
         let Some(first_row) = table.iter().next() else {
@@ -359,15 +450,261 @@ impl<'app> CourseScheduleApplication {
             try_table_into_with_scroll::<Lecture>(&mut self.client, parser, Self::MAIN_TABLE)
                 .await?;
 
-        Ok(lectures.into_iter())
+        Ok(lectures)
     }
 
-    // TO-DO: 카테고리 별 선택지 가져오기 기능
+    async fn syllabus_event(
+        &mut self,
+        code: &str,
+        division: Option<&str>,
+    ) -> Result<Event, RusaintError> {
+        let event = find_row_with_scroll(
+            &mut self.client,
+            Self::MAIN_TABLE,
+            |table, row, parser| -> Result<Option<Event>, RusaintError> {
+                let values =
+                    row.try_row_into::<Vec<(String, Option<String>)>>(table.header(), parser)?;
+                let value = |key: &str| {
+                    values
+                        .iter()
+                        .find(|(header, _)| header == key)
+                        .and_then(|(_, value)| value.as_deref())
+                        .map(str::trim)
+                };
+                if value("과목번호") != Some(code)
+                    || (division.is_some() && value("분반") != division)
+                {
+                    return Ok(None);
+                }
+                let cell = values
+                    .iter()
+                    .position(|(header, _)| header == "계획")
+                    .and_then(|idx| SapTableCellWrapper::from_def(&row[idx], parser).ok());
+                let event = match cell.and_then(|cell| cell.content()) {
+                    Some(ElementDefWrapper::Link(link)) => {
+                        parser.element_from_def(&link)?.activate(false, false)?
+                    }
+                    Some(ElementDefWrapper::Button(button)) => {
+                        parser.element_from_def(&button)?.press()?
+                    }
+                    _ => return Err(ApplicationError::NoSyllabus.into()),
+                };
+                Ok(Some(event))
+            },
+        )
+        .await?;
+        event.ok_or_else(|| ApplicationError::NoLectureResult.into())
+    }
+
+    /// 검색한 강의의 강의계획서를 가져옵니다.
+    ///
+    /// 강의는 마지막으로 [`find_lectures`](Self::find_lectures)를 호출하여 검색한 결과에 포함되어 있어야 합니다.
+    /// ### 예시
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// # use std::sync::Arc;
+    /// # use rusaint::USaintSession;
+    /// # use rusaint::model::SemesterType;
+    /// # use rusaint::application::course_schedule::{CourseScheduleApplication, model::LectureCategory};
+    /// # use rusaint::application::USaintClientBuilder;
+    /// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+    /// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseScheduleApplication>().await.unwrap();
+    /// let category = LectureCategory::find_by_lecture("컴퓨터구조");
+    /// let lecture = app.find_lectures(2024, SemesterType::One, &category).await.unwrap().next().unwrap();
+    /// let syllabus = app.lecture_syllabus(&lecture).await.unwrap();
+    /// println!("{:?}", syllabus.weekly_plans());
+    /// # })
+    /// ```
+    pub async fn lecture_syllabus(&mut self, lecture: &Lecture) -> Result<Syllabus, RusaintError> {
+        close_popups(&mut self.client).await?;
+        let event = self
+            .syllabus_event(lecture.code(), lecture.division())
+            .await?;
+        self.client.process_event(false, event).await?;
+        if !has_popup(self.body()) {
+            return Err(ApplicationError::NoSyllabus.into());
+        }
+        let syllabus = {
+            let parser = ElementParser::new(self.body());
+            Syllabus::from_popup(lecture, popup_fields(&parser), popup_tables(&parser)?)
+        };
+        close_popups(&mut self.client).await?;
+        Ok(syllabus)
+    }
+
+    /// 선택한 학기의 모든 강의 분류를 검색하여 중복 없는 [`LectureCatalog`]를 만듭니다.
+    ///
+    /// [`category_tree`](Self::category_tree)의 모든 분류를 검색하므로 매우 많은 요청이 발생합니다.
+    /// ### 예시
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// # use std::sync::Arc;
+    /// # use rusaint::USaintSession;
+    /// # use rusaint::model::SemesterType;
+    /// # use rusaint::application::course_schedule::CourseScheduleApplication;
+    /// # use rusaint::application::USaintClientBuilder;
+    /// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+    /// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseScheduleApplication>().await.unwrap();
+    /// let previous = app.crawl_catalog(2025, SemesterType::One).await.unwrap();
+    /// let current = app.crawl_catalog(2025, SemesterType::One).await.unwrap();
+    /// let diff = previous.diff(&current);
+    /// println!("{:?}", diff.seat_changes());
+    /// # })
+    /// ```
+    pub async fn crawl_catalog(
+        &mut self,
+        year: u32,
+        semester: SemesterType,
+    ) -> Result<LectureCatalog, RusaintError> {
+        let categories = self.category_tree(year, semester).await?.categories();
+        self.crawl_catalog_with(year, semester, &categories).await
+    }
+
+    /// 주어진 강의 분류들을 검색하여 중복 없는 [`LectureCatalog`]를 만듭니다. 검색 결과가 없는 분류는 무시합니다.
+    pub async fn crawl_catalog_with(
+        &mut self,
+        year: u32,
+        semester: SemesterType,
+        categories: &[LectureCategory],
+    ) -> Result<LectureCatalog, RusaintError> {
+        let mut catalog = LectureCatalog::new();
+        for category in categories {
+            match self.find_lectures(year, semester, category).await {
+                Ok(lectures) => catalog.insert(category, lectures),
+                Err(RusaintError::ApplicationError(ApplicationError::NoLectureResult)) => {
+                    log::debug!("No lecture found in category {category}, skip.");
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(catalog)
+    }
+
+    async fn collage_categories(
+        &mut self,
+        tab: TabStripItemDef,
+        tab_index: u32,
+        lv3: ComboBoxDef,
+        lv4: ComboBoxDef,
+        lv5: Option<ComboBoxDef>,
+    ) -> Result<Vec<CollageCategory>, RusaintError> {
+        fn non_empty(items: Vec<String>) -> Vec<String> {
+            items
+                .into_iter()
+                .filter(|item| !item.trim().is_empty())
+                .collect()
+        }
+        select_tab(&mut self.client.0, tab, tab_index).await?;
+        let collages = non_empty(combo_box_items(&mut self.client.0, lv3.clone())?);
+        let mut collage_categories = Vec::with_capacity(collages.len());
+        for collage in collages {
+            select_lv1(&mut self.client.0, lv3.clone(), &collage).await?;
+            let departments = non_empty(combo_box_items(&mut self.client.0, lv4.clone())?);
+            let mut department_categories = Vec::with_capacity(departments.len());
+            for department in departments {
+                let majors = match &lv5 {
+                    Some(lv5) => {
+                        select_lv1(&mut self.client.0, lv4.clone(), &department).await?;
+                        // 전공 구분이 없는 학과(부)는 전공 선택지가 존재하지 않음
+                        combo_box_items(&mut self.client.0, lv5.clone())
+                            .map(non_empty)
+                            .unwrap_or_default()
+                    }
+                    None => Vec::new(),
+                };
+                department_categories.push(DepartmentCategory::new(department, majors));
+            }
+            collage_categories.push(CollageCategory::new(collage, department_categories));
+        }
+        Ok(collage_categories)
+    }
+
+    /// 선택한 학기에 검색할 수 있는 모든 강의 분류 선택지를 가져옵니다.
+    ///
+    /// 모든 탭과 단과대학, 학과(부)를 차례로 선택하므로 많은 요청이 발생합니다. 결과를 캐싱하여 사용하는 것을 권장합니다.
+    /// ### 예시
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// # use std::sync::Arc;
+    /// # use rusaint::USaintSession;
+    /// # use rusaint::model::SemesterType;
+    /// # use rusaint::application::course_schedule::CourseScheduleApplication;
+    /// # use rusaint::application::USaintClientBuilder;
+    /// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+    /// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseScheduleApplication>().await.unwrap();
+    /// let tree = app.category_tree(2025, SemesterType::One).await.unwrap();
+    /// for category in tree.categories() {
+    ///     println!("{}", category);
+    /// }
+    /// # })
+    /// ```
+    pub async fn category_tree(
+        &mut self,
+        year: u32,
+        semester: SemesterType,
+    ) -> Result<LectureCategoryTree, RusaintError> {
+        self.select_semester(
+            &ElementParser::new(self.client.body()),
+            &format!("{year}"),
+            semester,
+        )
+        .await?;
+        let majors = self
+            .collage_categories(
+                Self::TAB_OTHERS,
+                0,
+                Self::OTHERS_DDK_LV3,
+                Self::OTHERS_DDK_LV4,
+                Some(Self::OTHERS_DDK_LV5),
+            )
+            .await?;
+        let required_electives = self.required_electives(year, semester).await?;
+        let optional_electives = self.optional_elective_categories(year, semester).await?;
+        let chapels = self.chapel_categories(year, semester).await?;
+        let graduated = self
+            .collage_categories(
+                Self::TAB_GRADUATE,
+                5,
+                Self::GRADUATE_DDK_LV3,
+                Self::GRADUATE_DDK_LV4,
+                None,
+            )
+            .await?;
+        let connected_majors = self.connected_majors(year, semester).await?;
+        let united_majors = self.united_majors(year, semester).await?;
+        let recognized_other_majors = self
+            .collage_categories(
+                Self::TAB_OTHER_GC,
+                12,
+                Self::OTHER_GC_DDK_LV3,
+                Self::OTHER_GC_DDK_LV4,
+                Some(Self::OTHER_GC_DDK_LV5),
+            )
+            .await?;
+        Ok(LectureCategoryTree::new(
+            majors,
+            required_electives,
+            optional_electives,
+            chapels,
+            graduated,
+            connected_majors,
+            united_majors,
+            recognized_other_majors,
+        ))
+    }
 }
 
 #[cfg(test)]
 mod test {}
 
+/// 학기 전체 강의 카탈로그 및 변경 사항 비교: [`LectureCatalog`](catalog::LectureCatalog)
+pub mod catalog;
 /// 강의시간표 애플리케이션에서 사용하는 데이터 모델
 pub mod model;
+/// 추가 조건을 포함한 강의 검색: [`LectureQuery`](query::LectureQuery)
+pub mod query;
+/// 시간표 충돌 검사 및 시간표 후보 생성: [`ScheduleBuilder`](scheduler::ScheduleBuilder)
+pub mod scheduler;
+/// 강의 여석 감시: [`SeatMonitor`](seat_monitor::SeatMonitor)
+pub mod seat_monitor;
 mod utils;
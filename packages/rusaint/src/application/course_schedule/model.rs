@@ -1,4 +1,4 @@
-use std::{collections::HashMap, fmt::Display};
+use std::{collections::HashMap, fmt::Display, sync::LazyLock};
 
 use serde::{
     Deserialize, Serialize,
@@ -8,7 +8,10 @@ use serde::{
 use crate::application::course_schedule::utils::{
     request, request_lv1, request_lv2, request_lv3, request_text,
 };
+use crate::application::personal_course_schedule::model::Weekday;
 use crate::application::utils::de_with::deserialize_optional_string;
+use crate::application::utils::popup::PopupTable;
+use crate::model::TimeOfDay;
 use crate::webdynpro::element::parser::ElementParser;
 use crate::{
     define_elements,
@@ -25,7 +28,7 @@ use crate::{
 /// 강의를 찾을 때 사용하는 강의 카테고리
 #[allow(unused)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LectureCategory {
     /// 전공 강의
     Major {
@@ -471,9 +474,197 @@ impl LectureCategory {
     }
 }
 
+/// 학기에 선택할 수 있는 모든 강의 분류의 계층 구조
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct LectureCategoryTree {
+    majors: Vec<CollageCategory>,
+    required_electives: Vec<String>,
+    optional_electives: Vec<String>,
+    chapels: Vec<String>,
+    graduated: Vec<CollageCategory>,
+    connected_majors: Vec<String>,
+    united_majors: Vec<String>,
+    recognized_other_majors: Vec<CollageCategory>,
+}
+
+/// 단과대학과 그에 속한 학과(부) 선택지
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct CollageCategory {
+    name: String,
+    departments: Vec<DepartmentCategory>,
+}
+
+/// 학과(부)와 그에 속한 전공 선택지
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct DepartmentCategory {
+    name: String,
+    majors: Vec<String>,
+}
+
+impl LectureCategoryTree {
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn new(
+        majors: Vec<CollageCategory>,
+        required_electives: Vec<String>,
+        optional_electives: Vec<String>,
+        chapels: Vec<String>,
+        graduated: Vec<CollageCategory>,
+        connected_majors: Vec<String>,
+        united_majors: Vec<String>,
+        recognized_other_majors: Vec<CollageCategory>,
+    ) -> LectureCategoryTree {
+        LectureCategoryTree {
+            majors,
+            required_electives,
+            optional_electives,
+            chapels,
+            graduated,
+            connected_majors,
+            united_majors,
+            recognized_other_majors,
+        }
+    }
+
+    /// 학부전공별 단과대학 목록
+    pub fn majors(&self) -> &[CollageCategory] {
+        &self.majors
+    }
+
+    /// 교양필수 과목명 목록
+    pub fn required_electives(&self) -> &[String] {
+        &self.required_electives
+    }
+
+    /// 교양선택 분야 목록
+    pub fn optional_electives(&self) -> &[String] {
+        &self.optional_electives
+    }
+
+    /// 채플 과목명 목록
+    pub fn chapels(&self) -> &[String] {
+        &self.chapels
+    }
+
+    /// 대학원 단과대학 목록
+    pub fn graduated(&self) -> &[CollageCategory] {
+        &self.graduated
+    }
+
+    /// 연계전공 목록
+    pub fn connected_majors(&self) -> &[String] {
+        &self.connected_majors
+    }
+
+    /// 융합전공 목록
+    pub fn united_majors(&self) -> &[String] {
+        &self.united_majors
+    }
+
+    /// 타전공인정과목 단과대학 목록
+    pub fn recognized_other_majors(&self) -> &[CollageCategory] {
+        &self.recognized_other_majors
+    }
+
+    /// 트리에 포함된 모든 선택지를 검색 가능한 [`LectureCategory`] 목록으로 변환합니다.
+    ///
+    /// 전공이 있는 학과(부)는 학과(부) 전체와 각 전공에 대한 분류가 모두 포함되며, 교직과 숭실사이버대 분류도 포함됩니다.
+    pub fn categories(&self) -> Vec<LectureCategory> {
+        fn collage_categories(
+            collages: &[CollageCategory],
+            make: fn(&str, &str, Option<&str>) -> LectureCategory,
+        ) -> impl Iterator<Item = LectureCategory> + '_ {
+            collages.iter().flat_map(move |collage| {
+                collage.departments.iter().flat_map(move |department| {
+                    std::iter::once(make(&collage.name, &department.name, None)).chain(
+                        department
+                            .majors
+                            .iter()
+                            .map(move |major| make(&collage.name, &department.name, Some(major))),
+                    )
+                })
+            })
+        }
+        let mut categories: Vec<LectureCategory> =
+            collage_categories(&self.majors, LectureCategory::major).collect();
+        categories.extend(
+            self.required_electives
+                .iter()
+                .map(|name| LectureCategory::required_elective(name)),
+        );
+        categories.extend(
+            self.optional_electives
+                .iter()
+                .map(|name| LectureCategory::optional_elective(name)),
+        );
+        categories.extend(
+            self.chapels
+                .iter()
+                .map(|name| LectureCategory::chapel(name)),
+        );
+        categories.push(LectureCategory::education());
+        categories.extend(self.graduated.iter().flat_map(|collage| {
+            collage
+                .departments
+                .iter()
+                .map(|department| LectureCategory::graduated(&collage.name, &department.name))
+        }));
+        categories.extend(
+            self.connected_majors
+                .iter()
+                .map(|name| LectureCategory::connected_major(name)),
+        );
+        categories.extend(
+            self.united_majors
+                .iter()
+                .map(|name| LectureCategory::united_major(name)),
+        );
+        categories.extend(collage_categories(
+            &self.recognized_other_majors,
+            LectureCategory::recognized_other_major,
+        ));
+        categories.push(LectureCategory::cyber());
+        categories
+    }
+}
+
+impl CollageCategory {
+    pub(super) fn new(name: String, departments: Vec<DepartmentCategory>) -> CollageCategory {
+        CollageCategory { name, departments }
+    }
+
+    /// 단과대학명
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 학과(부) 목록
+    pub fn departments(&self) -> &[DepartmentCategory] {
+        &self.departments
+    }
+}
+
+impl DepartmentCategory {
+    pub(super) fn new(name: String, majors: Vec<String>) -> DepartmentCategory {
+        DepartmentCategory { name, majors }
+    }
+
+    /// 학과(부)명
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 전공 목록, 전공 구분이 없다면 비어 있습니다.
+    pub fn majors(&self) -> &[String] {
+        &self.majors
+    }
+}
+
 /// 과목 정보
 #[allow(unused)]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct Lecture {
     /// 계획
@@ -543,6 +734,305 @@ pub struct Lecture {
     target: String,
 }
 
+impl Lecture {
+    /// 강의계획서 정보
+    pub fn syllabus(&self) -> Option<&str> {
+        self.syllabus.as_deref()
+    }
+
+    /// 이수구분(주전공)
+    pub fn category(&self) -> &str {
+        &self.category
+    }
+
+    /// 이수구분(다전공)
+    pub fn sub_category(&self) -> Option<&str> {
+        self.sub_category.as_deref()
+    }
+
+    /// 공학인증
+    pub fn abeek_info(&self) -> Option<&str> {
+        self.abeek_info.as_deref()
+    }
+
+    /// 교과영역
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+
+    /// 과목번호
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// 과목명
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 분반
+    pub fn division(&self) -> Option<&str> {
+        self.division.as_deref()
+    }
+
+    /// 교수명
+    pub fn professor(&self) -> &str {
+        &self.professor
+    }
+
+    /// 개설학과
+    pub fn department(&self) -> &str {
+        &self.department
+    }
+
+    /// 시간/학점(설계)
+    pub fn time_points(&self) -> &str {
+        &self.time_points
+    }
+
+    /// 수강인원
+    pub fn personeel(&self) -> &str {
+        &self.personeel
+    }
+
+    /// 여석
+    pub fn remaining_seats(&self) -> &str {
+        &self.remaining_seats
+    }
+
+    /// 강의시간(강의실)
+    pub fn schedule_room(&self) -> &str {
+        &self.schedule_room
+    }
+
+    /// 수강대상
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// 시간/학점(설계) 정보를 [`LectureCredits`]로 변환하여 반환합니다.
+    pub fn credits(&self) -> Option<LectureCredits> {
+        LectureCredits::parse(&self.time_points)
+    }
+
+    /// 수강인원을 숫자로 반환합니다.
+    pub fn personeel_count(&self) -> Option<u32> {
+        parse_leading_number(&self.personeel)
+    }
+
+    /// 여석을 숫자로 반환합니다. 인원 제한이 없는 강의는 `None`을 반환합니다.
+    pub fn remaining_seats_count(&self) -> Option<u32> {
+        parse_leading_number(&self.remaining_seats)
+    }
+
+    /// 여석을 [`RemainingSeats`]로 변환하여 반환합니다. 여석을 읽을 수 없다면 `None`을 반환합니다.
+    pub fn seat_availability(&self) -> Option<RemainingSeats> {
+        RemainingSeats::parse(&self.remaining_seats)
+    }
+
+    /// 강의시간(강의실) 정보를 [`LectureTimeSlot`] 목록으로 변환하여 반환합니다.
+    ///
+    /// 요일과 시간이 없는 강의(온라인 강의 등)는 빈 목록을 반환합니다.
+    pub fn time_slots(&self) -> Vec<LectureTimeSlot> {
+        LectureTimeSlot::parse_all(&self.schedule_room)
+    }
+}
+
+/// 강의의 여석
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum RemainingSeats {
+    /// 남은 자리 수
+    Limited(u32),
+    /// 인원 제한 없음
+    Unlimited,
+}
+
+impl RemainingSeats {
+    fn parse(value: &str) -> Option<RemainingSeats> {
+        // 제한 없음 표기는 캡처한 화면과 대조하지 못했으므로 알려진 표현들을 모두 허용
+        let compact: String = value.chars().filter(|c| !c.is_whitespace()).collect();
+        if compact.contains("제한없음") || compact.contains("무제한") {
+            return Some(RemainingSeats::Unlimited);
+        }
+        parse_leading_number(value).map(RemainingSeats::Limited)
+    }
+
+    /// 수강신청할 수 있는 자리가 남아 있는지 여부를 반환합니다.
+    pub fn is_available(&self) -> bool {
+        match self {
+            RemainingSeats::Limited(count) => *count > 0,
+            RemainingSeats::Unlimited => true,
+        }
+    }
+}
+
+fn parse_leading_number(value: &str) -> Option<u32> {
+    let digits: String = value
+        .trim()
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+/// 강의의 시간, 학점, 설계학점 정보
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct LectureCredits {
+    hours: f32,
+    credits: f32,
+    design_credits: f32,
+}
+
+impl LectureCredits {
+    /// `3.0/3.0(0)` 형태의 문자열을 변환합니다.
+    pub(crate) fn parse(value: &str) -> Option<LectureCredits> {
+        let (hours, rest) = value.trim().split_once('/')?;
+        let (credits, design_credits) = match rest.split_once('(') {
+            Some((credits, design)) => (credits, design.trim_end_matches(')')),
+            None => (rest, "0"),
+        };
+        Some(LectureCredits {
+            hours: hours.trim().parse().ok()?,
+            credits: credits.trim().parse().ok()?,
+            design_credits: design_credits.trim().parse().ok()?,
+        })
+    }
+
+    /// 주당 강의 시간
+    pub fn hours(&self) -> f32 {
+        self.hours
+    }
+
+    /// 학점
+    pub fn credits(&self) -> f32 {
+        self.credits
+    }
+
+    /// 설계학점
+    pub fn design_credits(&self) -> f32 {
+        self.design_credits
+    }
+}
+
+// 강의실 이름에 괄호가 한 번 더 들어가는 경우가 있음(`형남공학관 05104 (김낙경강의실)-차형태`)
+static TIME_SLOT_REGEX: LazyLock<regex_lite::Regex> = LazyLock::new(|| {
+    regex_lite::Regex::new(
+        r"((?:[월화수목금토일]\s*)+)(\d{1,2}:\d{2})\s*-\s*(\d{1,2}:\d{2})(?:\s*\(((?:[^()]|\([^()]*\))*)\))?",
+    )
+    .unwrap()
+});
+
+/// 강의의 요일별 시간과 강의실 정보
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct LectureTimeSlot {
+    weekday: Weekday,
+    start: TimeOfDay,
+    end: TimeOfDay,
+    building: Option<String>,
+    room: Option<String>,
+    professor: Option<String>,
+}
+
+impl LectureTimeSlot {
+    /// `월 수 09:00-10:15 (정보과학관 21203-김교수)` 형태의 문자열들을 변환합니다.
+    pub(crate) fn parse_all(value: &str) -> Vec<LectureTimeSlot> {
+        TIME_SLOT_REGEX
+            .captures_iter(value)
+            .flat_map(|captures| {
+                let (Ok(start), Ok(end)) = (
+                    captures[2].parse::<TimeOfDay>(),
+                    captures[3].parse::<TimeOfDay>(),
+                ) else {
+                    return Vec::new();
+                };
+                let (building, room, professor) = captures
+                    .get(4)
+                    .map(|place| Self::parse_place(place.as_str()))
+                    .unwrap_or_default();
+                captures[1]
+                    .split_whitespace()
+                    .flat_map(|days| days.chars())
+                    .filter_map(|day| day.to_string().parse::<Weekday>().ok())
+                    .map(|weekday| LectureTimeSlot {
+                        weekday,
+                        start,
+                        end,
+                        building: building.clone(),
+                        room: room.clone(),
+                        professor: professor.clone(),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn parse_place(place: &str) -> (Option<String>, Option<String>, Option<String>) {
+        fn non_empty(value: &str) -> Option<String> {
+            let value = value.trim();
+            (!value.is_empty()).then(|| value.to_string())
+        }
+        // 장소는 `건물 호수-교수` 형태이며, `21203-1`처럼 호수에 들어간 '-' 뒤에는 숫자가 옴
+        let separator = place
+            .char_indices()
+            .rev()
+            .find(|(index, c)| {
+                *c == '-'
+                    && !place[index + 1..]
+                        .chars()
+                        .next()
+                        .is_some_and(|next| next.is_ascii_digit())
+            })
+            .map(|(index, _)| index);
+        let (location, professor) = match separator {
+            Some(index) => (&place[..index], non_empty(&place[index + 1..])),
+            None => (place, None),
+        };
+        let (building, room) = match location.trim().split_once(char::is_whitespace) {
+            Some((building, room)) => (non_empty(building), non_empty(room)),
+            None => (non_empty(location), None),
+        };
+        (building, room, professor)
+    }
+
+    /// 요일
+    pub fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+
+    /// 시작 시각
+    pub fn start(&self) -> TimeOfDay {
+        self.start
+    }
+
+    /// 종료 시각
+    pub fn end(&self) -> TimeOfDay {
+        self.end
+    }
+
+    /// 건물명
+    pub fn building(&self) -> Option<&str> {
+        self.building.as_deref()
+    }
+
+    /// 강의실 호수
+    pub fn room(&self) -> Option<&str> {
+        self.room.as_deref()
+    }
+
+    /// 교수명
+    pub fn professor(&self) -> Option<&str> {
+        self.professor.as_deref()
+    }
+
+    /// 다른 시간과 겹치는지 여부를 반환합니다.
+    pub fn overlaps(&self, other: &LectureTimeSlot) -> bool {
+        self.weekday == other.weekday && self.start < other.end && other.start < self.end
+    }
+}
+
 impl<'body> FromSapTable<'body> for Lecture {
     fn from_table(
         header: &'body crate::webdynpro::element::complex::sap_table::SapTableHeader,
@@ -655,3 +1145,409 @@ impl Default for LectureCategoryBuilder {
         Self::new()
     }
 }
+
+/// 강의계획서
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct Syllabus {
+    code: String,
+    name: String,
+    division: Option<String>,
+    overview: Option<String>,
+    objectives: Option<String>,
+    office_hours: Option<String>,
+    weekly_plans: Vec<SyllabusWeeklyPlan>,
+    evaluations: Vec<SyllabusEvaluation>,
+    textbooks: Vec<SyllabusTextbook>,
+    fields: HashMap<String, String>,
+}
+
+/// 강의계획서의 주차별 강의 계획
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SyllabusWeeklyPlan {
+    week: u32,
+    content: String,
+    note: Option<String>,
+}
+
+/// 강의계획서의 평가 항목과 반영 비율
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SyllabusEvaluation {
+    item: String,
+    ratio: f32,
+}
+
+/// 강의계획서의 교재 정보
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SyllabusTextbook {
+    title: String,
+    author: Option<String>,
+    publisher: Option<String>,
+    year: Option<String>,
+}
+
+fn find_column<'a>(row: &'a [(String, String)], keywords: &[&str]) -> Option<&'a str> {
+    row.iter()
+        .find(|(key, _)| keywords.iter().any(|keyword| key.contains(keyword)))
+        .map(|(_, value)| value.trim())
+}
+
+fn non_empty(value: Option<&str>) -> Option<String> {
+    value.filter(|value| !value.is_empty()).map(str::to_string)
+}
+
+impl Syllabus {
+    /// 강의계획서 팝업의 `(라벨, 값)` 필드와 테이블들로 강의계획서를 만듭니다.
+    ///
+    /// 테이블은 열 제목에 따라 주차별 계획(`주차`), 평가 비율(`비율`), 교재(`교재`, `도서`)로 구분됩니다.
+    pub(crate) fn from_popup(
+        lecture: &Lecture,
+        fields: Vec<(String, String)>,
+        tables: Vec<PopupTable>,
+    ) -> Syllabus {
+        let fields: HashMap<String, String> = fields
+            .into_iter()
+            .filter(|(key, _)| !key.is_empty())
+            .collect();
+        let field = |keywords: &[&str]| {
+            fields
+                .iter()
+                .find(|(key, _)| keywords.iter().any(|keyword| key.contains(keyword)))
+                .and_then(|(_, value)| non_empty(Some(value.trim())))
+        };
+        let mut syllabus = Syllabus {
+            code: lecture.code().to_string(),
+            name: lecture.name().to_string(),
+            division: lecture.division().map(str::to_string),
+            overview: field(&["개요", "소개"]),
+            objectives: field(&["목표"]),
+            office_hours: field(&["면담", "상담", "오피스"]),
+            weekly_plans: Vec::new(),
+            evaluations: Vec::new(),
+            textbooks: Vec::new(),
+            fields: HashMap::new(),
+        };
+        for rows in tables {
+            let Some(first) = rows.first() else {
+                continue;
+            };
+            let has_column = |keywords: &[&str]| find_column(first, keywords).is_some();
+            if has_column(&["주차"]) {
+                syllabus.weekly_plans.extend(rows.iter().filter_map(|row| {
+                    let week = parse_leading_number(find_column(row, &["주차"])?)?;
+                    let content = find_column(row, &["내용", "주제", "계획"])?.to_string();
+                    let note = non_empty(find_column(row, &["비고", "과제", "방법"]));
+                    Some(SyllabusWeeklyPlan {
+                        week,
+                        content,
+                        note,
+                    })
+                }));
+            } else if has_column(&["비율", "반영"]) {
+                syllabus.evaluations.extend(rows.iter().filter_map(|row| {
+                    let ratio = find_column(row, &["비율", "반영"])?
+                        .trim_end_matches('%')
+                        .trim()
+                        .parse()
+                        .ok()?;
+                    let item = row
+                        .iter()
+                        .find(|(key, _)| !key.contains("비율") && !key.contains("반영"))?
+                        .1
+                        .trim()
+                        .to_string();
+                    (!item.is_empty()).then_some(SyllabusEvaluation { item, ratio })
+                }));
+            } else if has_column(&["교재", "도서", "서명"]) {
+                syllabus.textbooks.extend(rows.iter().filter_map(|row| {
+                    let title = non_empty(find_column(row, &["교재", "도서", "서명"]))?;
+                    Some(SyllabusTextbook {
+                        title,
+                        author: non_empty(find_column(row, &["저자"])),
+                        publisher: non_empty(find_column(row, &["출판"])),
+                        year: non_empty(find_column(row, &["연도", "년도"])),
+                    })
+                }));
+            }
+        }
+        syllabus.fields = fields;
+        syllabus
+    }
+
+    /// 과목번호
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// 과목명
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 분반
+    pub fn division(&self) -> Option<&str> {
+        self.division.as_deref()
+    }
+
+    /// 강의 개요
+    pub fn overview(&self) -> Option<&str> {
+        self.overview.as_deref()
+    }
+
+    /// 강의 목표
+    pub fn objectives(&self) -> Option<&str> {
+        self.objectives.as_deref()
+    }
+
+    /// 교수 면담 시간
+    pub fn office_hours(&self) -> Option<&str> {
+        self.office_hours.as_deref()
+    }
+
+    /// 주차별 강의 계획
+    pub fn weekly_plans(&self) -> &[SyllabusWeeklyPlan] {
+        &self.weekly_plans
+    }
+
+    /// 평가 항목별 반영 비율
+    pub fn evaluations(&self) -> &[SyllabusEvaluation] {
+        &self.evaluations
+    }
+
+    /// 교재 목록
+    pub fn textbooks(&self) -> &[SyllabusTextbook] {
+        &self.textbooks
+    }
+
+    /// 강의계획서의 모든 항목을 `항목명: 값` 형태로 반환합니다.
+    pub fn fields(&self) -> &HashMap<String, String> {
+        &self.fields
+    }
+}
+
+impl SyllabusWeeklyPlan {
+    /// 주차
+    pub fn week(&self) -> u32 {
+        self.week
+    }
+
+    /// 강의 내용
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// 비고
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+}
+
+impl SyllabusEvaluation {
+    /// 평가 항목
+    pub fn item(&self) -> &str {
+        &self.item
+    }
+
+    /// 반영 비율(%)
+    pub fn ratio(&self) -> f32 {
+        self.ratio
+    }
+}
+
+impl SyllabusTextbook {
+    /// 교재명
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// 저자
+    pub fn author(&self) -> Option<&str> {
+        self.author.as_deref()
+    }
+
+    /// 출판사
+    pub fn publisher(&self) -> Option<&str> {
+        self.publisher.as_deref()
+    }
+
+    /// 출판 연도
+    pub fn year(&self) -> Option<&str> {
+        self.year.as_deref()
+    }
+}
+
+/// 테스트에서 사용할 강의를 강의 목록 표의 열 값으로부터 만듭니다. 주어지지 않은 열은 기본값을 사용합니다.
+#[cfg(test)]
+pub(crate) fn lecture_fixture(columns: &[(&str, &str)]) -> Lecture {
+    use serde::de::{IntoDeserializer, value::MapDeserializer};
+
+    let mut map: HashMap<String, String> = [
+        ("이수구분(주전공)", "전선"),
+        ("과목번호", "2150000000"),
+        ("과목명", "과목"),
+        ("분반", "01"),
+        ("교수명", "교수"),
+        ("개설학과", "학과"),
+        ("시간/학점(설계)", "3.0/3.0(0)"),
+        ("수강인원", "40"),
+        ("여석", "0"),
+        ("강의시간(강의실)", ""),
+        ("수강대상", "전체"),
+    ]
+    .into_iter()
+    .map(|(key, value)| (key.to_string(), value.to_string()))
+    .collect();
+    map.extend(
+        columns
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string())),
+    );
+    let map_de: MapDeserializer<_, serde::de::value::Error> = map.into_deserializer();
+    Lecture::deserialize(map_de).unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        CollageCategory, DepartmentCategory, LectureCategoryTree, LectureCredits, LectureTimeSlot,
+        RemainingSeats, Syllabus, lecture_fixture,
+    };
+    use crate::application::personal_course_schedule::model::Weekday;
+
+    #[test]
+    fn parse_time_slots() {
+        let slots = LectureTimeSlot::parse_all(
+            "월 수 09:00-10:15 (정보과학관 21203-김교수)\n금 13:30-14:45 (-)",
+        );
+        assert_eq!(slots.len(), 3);
+        assert_eq!(slots[0].weekday(), Weekday::Mon);
+        assert_eq!(slots[1].weekday(), Weekday::Wed);
+        assert_eq!(slots[0].start().to_string(), "09:00");
+        assert_eq!(slots[0].end().to_string(), "10:15");
+        assert_eq!(slots[0].building(), Some("정보과학관"));
+        assert_eq!(slots[0].room(), Some("21203"));
+        assert_eq!(slots[0].professor(), Some("김교수"));
+        assert_eq!(slots[2].building(), None);
+        assert_eq!(slots[2].professor(), None);
+        assert!(LectureTimeSlot::parse_all("").is_empty());
+    }
+
+    #[test]
+    fn parse_time_slot_places() {
+        let slots = LectureTimeSlot::parse_all(
+            "화 13:30-14:45 (정보과학관 21203-1-김교수)\n목 13:30-14:45 (정보과학관 21203-1)",
+        );
+        assert_eq!(slots[0].building(), Some("정보과학관"));
+        assert_eq!(slots[0].room(), Some("21203-1"));
+        assert_eq!(slots[0].professor(), Some("김교수"));
+        assert_eq!(slots[1].room(), Some("21203-1"));
+        assert_eq!(slots[1].professor(), None);
+
+        let slots =
+            LectureTimeSlot::parse_all("화 13:30-14:45 (형남공학관 05104 (김낙경강의실)-차형태)");
+        assert_eq!(slots[0].building(), Some("형남공학관"));
+        assert_eq!(slots[0].room(), Some("05104 (김낙경강의실)"));
+        assert_eq!(slots[0].professor(), Some("차형태"));
+    }
+
+    #[test]
+    fn parse_remaining_seats() {
+        let seats = |value: &str| lecture_fixture(&[("여석", value)]).seat_availability();
+        assert_eq!(seats("3"), Some(RemainingSeats::Limited(3)));
+        assert_eq!(seats(" 0 "), Some(RemainingSeats::Limited(0)));
+        assert_eq!(seats("제한 없음"), Some(RemainingSeats::Unlimited));
+        assert_eq!(seats("-"), None);
+        assert!(RemainingSeats::Unlimited.is_available());
+        assert!(!RemainingSeats::Limited(0).is_available());
+    }
+
+    #[test]
+    fn parse_credits() {
+        let credits = LectureCredits::parse("3.0/3.0(0)").unwrap();
+        assert_eq!(credits.hours(), 3.0);
+        assert_eq!(credits.credits(), 3.0);
+        assert_eq!(credits.design_credits(), 0.0);
+        assert!(LectureCredits::parse("").is_none());
+    }
+
+    fn pairs(values: &[(&str, &str)]) -> Vec<(String, String)> {
+        values
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn syllabus_from_popup() {
+        let lecture = lecture_fixture(&[
+            ("과목번호", "2150013601"),
+            ("과목명", "컴퓨터구조"),
+            ("이수구분(주전공)", "전필"),
+            ("교수명", "김교수"),
+            ("개설학과", "컴퓨터학부"),
+        ]);
+        let syllabus = Syllabus::from_popup(
+            &lecture,
+            pairs(&[("교과목개요", "컴퓨터의 구조"), ("면담시간", "월 15:00")]),
+            vec![
+                vec![
+                    pairs(&[("주차", "1"), ("강의내용", "개요"), ("비고", "")]),
+                    pairs(&[("주차", "2주"), ("강의내용", "명령어"), ("비고", "과제")]),
+                ],
+                vec![
+                    pairs(&[("평가항목", "중간고사"), ("반영비율", "30%")]),
+                    pairs(&[("평가항목", "기말고사"), ("반영비율", "40")]),
+                ],
+                vec![pairs(&[
+                    ("교재명", "컴퓨터 구조"),
+                    ("저자", "홍길동"),
+                    ("출판사", ""),
+                ])],
+            ],
+        );
+        assert_eq!(syllabus.code(), "2150013601");
+        assert_eq!(syllabus.overview(), Some("컴퓨터의 구조"));
+        assert_eq!(syllabus.office_hours(), Some("월 15:00"));
+        assert_eq!(syllabus.weekly_plans().len(), 2);
+        assert_eq!(syllabus.weekly_plans()[1].week(), 2);
+        assert_eq!(syllabus.weekly_plans()[1].note(), Some("과제"));
+        assert_eq!(syllabus.evaluations()[0].item(), "중간고사");
+        assert_eq!(syllabus.evaluations()[0].ratio(), 30.0);
+        assert_eq!(syllabus.textbooks()[0].author(), Some("홍길동"));
+        assert_eq!(syllabus.textbooks()[0].publisher(), None);
+    }
+
+    #[test]
+    fn category_tree_flatten() {
+        let tree = LectureCategoryTree::new(
+            vec![CollageCategory::new(
+                "IT대학".to_string(),
+                vec![DepartmentCategory::new(
+                    "컴퓨터학부".to_string(),
+                    vec!["컴퓨터학부".to_string()],
+                )],
+            )],
+            vec!["대학한국어1".to_string()],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+            vec![],
+        );
+        let categories: Vec<String> = tree.categories().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            categories,
+            [
+                "전공: IT대학 컴퓨터학부 ",
+                "전공: IT대학 컴퓨터학부 컴퓨터학부",
+                "교양필수: 대학한국어1",
+                "교직",
+                "숭실사이버대",
+            ]
+        );
+    }
+}
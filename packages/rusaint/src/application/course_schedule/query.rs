@@ -0,0 +1,233 @@
+use crate::{application::personal_course_schedule::model::Weekday, model::TimeOfDay};
+
+use super::model::{Lecture, LectureCategory};
+
+/// 검색 결과 테이블에 한 번에 표시할 행 수
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LectureRows {
+    /// 10행
+    Ten,
+    /// 20행
+    Twenty,
+    /// 50행
+    Fifty,
+    /// 100행
+    Hundred,
+    /// 200행
+    TwoHundred,
+    /// 500행
+    #[default]
+    FiveHundred,
+}
+
+impl LectureRows {
+    pub(super) fn key(&self) -> &'static str {
+        match self {
+            LectureRows::Ten => "10",
+            LectureRows::Twenty => "20",
+            LectureRows::Fifty => "50",
+            LectureRows::Hundred => "100",
+            LectureRows::TwoHundred => "200",
+            LectureRows::FiveHundred => "500",
+        }
+    }
+}
+
+/// 강의 분류와 검색 화면의 필터, 추가 조건을 함께 사용하는 강의 검색 쿼리
+///
+/// 강의 분류와 학년, 요일, 시간, 영어강의 필터는 u-saint 검색 화면에 설정되고, 교수명과 여석 조건은 검색 결과에 적용됩니다.
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::model::{SemesterType, TimeOfDay};
+/// # use rusaint::application::course_schedule::{CourseScheduleApplication, model::LectureCategory, query::LectureQuery};
+/// # use rusaint::application::personal_course_schedule::model::Weekday;
+/// # use rusaint::application::USaintClientBuilder;
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseScheduleApplication>().await.unwrap();
+/// let query = LectureQuery::new(LectureCategory::major("IT대학", "컴퓨터학부", None))
+///     .grade(2)
+///     .weekday(Weekday::Mon)
+///     .start_time(TimeOfDay::new(10, 30).unwrap())
+///     .available_only(true);
+/// let lectures = app.query_lectures(2025, SemesterType::One, &query).await.unwrap();
+/// # })
+/// ```
+#[derive(Clone, Debug)]
+pub struct LectureQuery {
+    category: LectureCategory,
+    rows: LectureRows,
+    grade: Option<u32>,
+    weekday: Option<Weekday>,
+    start_time: Option<TimeOfDay>,
+    english_only: bool,
+    professor: Option<String>,
+    available_only: bool,
+}
+
+impl LectureQuery {
+    /// 주어진 강의 분류로 새로운 쿼리를 만듭니다.
+    pub fn new(category: LectureCategory) -> LectureQuery {
+        LectureQuery {
+            category,
+            rows: LectureRows::default(),
+            grade: None,
+            weekday: None,
+            start_time: None,
+            english_only: false,
+            professor: None,
+            available_only: false,
+        }
+    }
+
+    /// 검색 결과 테이블에 한 번에 표시할 행 수를 설정합니다. 기본값은 500행입니다.
+    ///
+    /// 값이 클수록 결과를 읽기 위한 스크롤 요청이 줄어듭니다.
+    pub fn rows(mut self, rows: LectureRows) -> LectureQuery {
+        self.rows = rows;
+        self
+    }
+
+    /// 검색 화면의 학년 필터를 설정합니다.
+    pub fn grade(mut self, grade: u32) -> LectureQuery {
+        self.grade = Some(grade);
+        self
+    }
+
+    /// 검색 화면의 요일 필터를 설정합니다.
+    pub fn weekday(mut self, weekday: Weekday) -> LectureQuery {
+        self.weekday = Some(weekday);
+        self
+    }
+
+    /// 검색 화면의 수업 시작 시간 필터를 설정합니다.
+    pub fn start_time(mut self, time: TimeOfDay) -> LectureQuery {
+        self.start_time = Some(time);
+        self
+    }
+
+    /// 검색 화면의 영어강의 필터를 설정합니다.
+    pub fn english_only(mut self, english_only: bool) -> LectureQuery {
+        self.english_only = english_only;
+        self
+    }
+
+    /// 교수명에 주어진 문자열이 포함된 강의만 검색합니다.
+    pub fn professor(mut self, professor: &str) -> LectureQuery {
+        self.professor = Some(professor.to_string());
+        self
+    }
+
+    /// 여석이 있는 강의만 검색할지 설정합니다. 인원 제한이 없는 강의는 여석이 있는 것으로 봅니다.
+    pub fn available_only(mut self, available_only: bool) -> LectureQuery {
+        self.available_only = available_only;
+        self
+    }
+
+    /// 쿼리의 강의 분류를 반환합니다.
+    pub fn category(&self) -> &LectureCategory {
+        &self.category
+    }
+
+    pub(super) fn row_count(&self) -> LectureRows {
+        self.rows
+    }
+
+    // 검색 화면의 학년 필터에 표시되는 값
+    pub(super) fn grade_filter(&self) -> Option<String> {
+        self.grade.map(|grade| format!("{grade}학년"))
+    }
+
+    // 검색 화면의 요일 필터에 표시되는 값
+    pub(super) fn weekday_filter(&self) -> Option<&'static str> {
+        self.weekday.map(|weekday| match weekday {
+            Weekday::Mon => "월",
+            Weekday::Tue => "화",
+            Weekday::Wed => "수",
+            Weekday::Thu => "목",
+            Weekday::Fri => "금",
+            Weekday::Sat => "토",
+            Weekday::Sun => "일",
+        })
+    }
+
+    // 검색 화면의 시간 필터에 표시되는 값
+    pub(super) fn start_time_filter(&self) -> Option<String> {
+        self.start_time.map(|time| time.to_string())
+    }
+
+    // 검색 화면의 강의언어 필터에 표시되는 값
+    pub(super) fn language_filter(&self) -> Option<&'static str> {
+        self.english_only.then_some("영어")
+    }
+
+    /// 강의가 검색 화면에서 설정할 수 없는 추가 조건(교수명, 여석)을 모두 만족하는지 여부를 반환합니다.
+    pub fn matches(&self, lecture: &Lecture) -> bool {
+        if let Some(professor) = &self.professor {
+            if !lecture.professor().contains(professor.as_str()) {
+                return false;
+            }
+        }
+        !self.available_only
+            || lecture
+                .seat_availability()
+                .is_some_and(|seats| seats.is_available())
+    }
+}
+
+impl From<LectureCategory> for LectureQuery {
+    fn from(category: LectureCategory) -> Self {
+        LectureQuery::new(category)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{LectureQuery, LectureRows};
+    use crate::{
+        application::{
+            course_schedule::model::{Lecture, LectureCategory, lecture_fixture},
+            personal_course_schedule::model::Weekday,
+        },
+        model::TimeOfDay,
+    };
+
+    fn lecture(professor: &str, remaining_seats: &str) -> Lecture {
+        lecture_fixture(&[("교수명", professor), ("여석", remaining_seats)])
+    }
+
+    #[test]
+    fn screen_filters() {
+        let query = LectureQuery::new(LectureCategory::cyber());
+        assert_eq!(query.row_count(), LectureRows::FiveHundred);
+        assert_eq!(query.grade_filter(), None);
+        assert_eq!(query.weekday_filter(), None);
+        assert_eq!(query.start_time_filter(), None);
+        assert_eq!(query.language_filter(), None);
+        let query = query
+            .rows(LectureRows::Hundred)
+            .grade(2)
+            .weekday(Weekday::Thu)
+            .start_time(TimeOfDay::new(9, 0).unwrap())
+            .english_only(true);
+        assert_eq!(query.row_count().key(), "100");
+        assert_eq!(query.grade_filter().as_deref(), Some("2학년"));
+        assert_eq!(query.weekday_filter(), Some("목"));
+        assert_eq!(query.start_time_filter().as_deref(), Some("09:00"));
+        assert_eq!(query.language_filter(), Some("영어"));
+    }
+
+    #[test]
+    fn filter_lectures() {
+        let query = LectureQuery::new(LectureCategory::cyber())
+            .professor("김")
+            .available_only(true);
+        assert!(query.matches(&lecture("김교수", "3")));
+        assert!(!query.matches(&lecture("이교수", "3")));
+        assert!(!query.matches(&lecture("김교수", "0")));
+        assert!(!query.matches(&lecture("김교수", "-")));
+        assert!(query.matches(&lecture("김교수", "제한없음")));
+        assert!(LectureQuery::new(LectureCategory::cyber()).matches(&lecture("이교수", "0")));
+    }
+}
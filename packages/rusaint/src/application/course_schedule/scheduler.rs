@@ -0,0 +1,540 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashSet},
+};
+
+use crate::{application::personal_course_schedule::model::Weekday, model::TimeOfDay};
+
+use super::model::{Lecture, LectureTimeSlot};
+
+const DEFAULT_MAX_RESULTS: usize = 20;
+const NODE_LIMIT: usize = 200_000;
+
+/// 두 강의의 시간이 겹치는지 여부를 반환합니다. 시간 정보가 없는 강의는 겹치지 않는 것으로 간주합니다.
+pub fn is_conflicting(lhs: &Lecture, rhs: &Lecture) -> bool {
+    let rhs_slots = rhs.time_slots();
+    lhs.time_slots()
+        .iter()
+        .any(|slot| rhs_slots.iter().any(|other| slot.overlaps(other)))
+}
+
+/// 주어진 강의 중 시간이 겹치는 강의의 인덱스 쌍을 반환합니다.
+pub fn find_conflicts(lectures: &[Lecture]) -> Vec<(usize, usize)> {
+    let slots: Vec<Vec<LectureTimeSlot>> = lectures.iter().map(Lecture::time_slots).collect();
+    let mut conflicts = Vec::new();
+    for (i, lhs) in slots.iter().enumerate() {
+        for (j, rhs) in slots.iter().enumerate().skip(i + 1) {
+            if lhs
+                .iter()
+                .any(|slot| rhs.iter().any(|other| slot.overlaps(other)))
+            {
+                conflicts.push((i, j));
+            }
+        }
+    }
+    conflicts
+}
+
+/// 시간표를 구성할 때 적용하는 제약 조건
+#[derive(Clone, Debug, Default)]
+pub struct ScheduleConstraints {
+    excluded_weekdays: HashSet<Weekday>,
+    earliest_start: Option<TimeOfDay>,
+    latest_end: Option<TimeOfDay>,
+    max_credits: Option<f32>,
+    min_credits: Option<f32>,
+}
+
+impl ScheduleConstraints {
+    /// 제약 조건이 없는 새로운 [`ScheduleConstraints`]를 만듭니다.
+    pub fn new() -> ScheduleConstraints {
+        ScheduleConstraints::default()
+    }
+
+    /// 수업을 듣지 않을 요일을 추가합니다.
+    pub fn exclude_weekday(mut self, weekday: Weekday) -> ScheduleConstraints {
+        self.excluded_weekdays.insert(weekday);
+        self
+    }
+
+    /// 수업이 시작할 수 있는 가장 이른 시각을 설정합니다.
+    pub fn earliest_start(mut self, time: TimeOfDay) -> ScheduleConstraints {
+        self.earliest_start = Some(time);
+        self
+    }
+
+    /// 수업이 끝나야 하는 가장 늦은 시각을 설정합니다.
+    pub fn latest_end(mut self, time: TimeOfDay) -> ScheduleConstraints {
+        self.latest_end = Some(time);
+        self
+    }
+
+    /// 시간표의 최대 학점을 설정합니다.
+    pub fn max_credits(mut self, credits: f32) -> ScheduleConstraints {
+        self.max_credits = Some(credits);
+        self
+    }
+
+    /// 시간표의 최소 학점을 설정합니다.
+    pub fn min_credits(mut self, credits: f32) -> ScheduleConstraints {
+        self.min_credits = Some(credits);
+        self
+    }
+
+    fn allows(&self, slot: &LectureTimeSlot) -> bool {
+        !self.excluded_weekdays.contains(&slot.weekday())
+            && self
+                .earliest_start
+                .is_none_or(|start| slot.start() >= start)
+            && self.latest_end.is_none_or(|end| slot.end() <= end)
+    }
+}
+
+/// 시간이 겹치지 않는 강의 조합으로 구성된 시간표 후보
+#[derive(Clone, Debug)]
+pub struct ScheduleCandidate {
+    lectures: Vec<Lecture>,
+    credits: f32,
+    days: usize,
+    idle_minutes: u32,
+}
+
+impl ScheduleCandidate {
+    fn new(lectures: Vec<Lecture>) -> ScheduleCandidate {
+        let credits = lectures
+            .iter()
+            .filter_map(Lecture::credits)
+            .map(|credits| credits.credits())
+            .sum();
+        let mut by_day: BTreeMap<Weekday, Vec<LectureTimeSlot>> = BTreeMap::new();
+        for slot in lectures.iter().flat_map(Lecture::time_slots) {
+            by_day.entry(slot.weekday()).or_default().push(slot);
+        }
+        let idle_minutes = by_day
+            .values_mut()
+            .map(|slots| {
+                slots.sort_by_key(LectureTimeSlot::start);
+                slots
+                    .windows(2)
+                    .map(|pair| {
+                        pair[1]
+                            .start()
+                            .minutes_from_midnight()
+                            .saturating_sub(pair[0].end().minutes_from_midnight())
+                    })
+                    .sum::<u32>()
+            })
+            .sum();
+        ScheduleCandidate {
+            lectures,
+            credits,
+            days: by_day.len(),
+            idle_minutes,
+        }
+    }
+
+    /// 시간표에 포함된 강의 목록
+    pub fn lectures(&self) -> &[Lecture] {
+        &self.lectures
+    }
+
+    /// 시간표의 총 학점
+    pub fn credits(&self) -> f32 {
+        self.credits
+    }
+
+    /// 수업이 있는 요일 수
+    pub fn days(&self) -> usize {
+        self.days
+    }
+
+    /// 같은 날 수업 사이의 공강 시간(분)의 합
+    pub fn idle_minutes(&self) -> u32 {
+        self.idle_minutes
+    }
+
+    fn rank(&self, other: &ScheduleCandidate) -> Ordering {
+        other
+            .credits
+            .total_cmp(&self.credits)
+            .then(self.days.cmp(&other.days))
+            .then(self.idle_minutes.cmp(&other.idle_minutes))
+    }
+}
+
+/// 시간표 탐색 중 상태
+struct ScheduleSearch<'b> {
+    builder: &'b ScheduleBuilder,
+    groups: Vec<(bool, Vec<usize>)>,
+    slots: Vec<Vec<LectureTimeSlot>>,
+    credits: Vec<f32>,
+    // 각 깊이 이후의 분류에서 더 얻을 수 있는 최대 학점
+    remaining_credits: Vec<f32>,
+    visited: usize,
+    picked: Vec<usize>,
+    best: Vec<ScheduleCandidate>,
+}
+
+impl ScheduleSearch<'_> {
+    fn max_credits(&self, depth: usize, total_credits: f32) -> f32 {
+        let bound = total_credits + self.remaining_credits[depth];
+        self.builder
+            .constraints
+            .max_credits
+            .map_or(bound, |max| bound.min(max))
+    }
+
+    // 남은 분류를 모두 채워도 최소 학점에 못 미치거나, 이미 찾은 후보보다 순위가 높아질 수 없다면 탐색하지 않습니다.
+    // 수업 요일 수는 강의를 추가할수록 늘어나기만 하므로, 학점이 같다면 요일 수로도 판단합니다.
+    fn is_hopeless(&self, depth: usize, total_credits: f32) -> bool {
+        let bound = self.max_credits(depth, total_credits);
+        if self
+            .builder
+            .constraints
+            .min_credits
+            .is_some_and(|min| bound < min)
+        {
+            return true;
+        }
+        if self.best.len() < self.builder.max_results {
+            return false;
+        }
+        let Some(worst) = self.best.last() else {
+            return false;
+        };
+        match bound.total_cmp(&worst.credits) {
+            Ordering::Less => true,
+            Ordering::Greater => false,
+            Ordering::Equal => {
+                let days = self.picked_days();
+                days > worst.days || (days == worst.days && worst.idle_minutes == 0)
+            }
+        }
+    }
+
+    fn picked_days(&self) -> usize {
+        self.picked
+            .iter()
+            .flat_map(|&idx| self.slots[idx].iter().map(LectureTimeSlot::weekday))
+            .collect::<HashSet<Weekday>>()
+            .len()
+    }
+
+    fn record(&mut self) {
+        let candidate = ScheduleCandidate::new(
+            self.picked
+                .iter()
+                .map(|&idx| self.builder.lectures[idx].clone())
+                .collect(),
+        );
+        let position = self
+            .best
+            .partition_point(|other| other.rank(&candidate) != Ordering::Greater);
+        if position < self.builder.max_results {
+            self.best.insert(position, candidate);
+            self.best.truncate(self.builder.max_results);
+        }
+    }
+
+    fn search(&mut self, depth: usize, total_credits: f32) {
+        if self.visited >= NODE_LIMIT || self.is_hopeless(depth, total_credits) {
+            return;
+        }
+        self.visited += 1;
+        let Some((required, sections)) = self.groups.get(depth) else {
+            if self
+                .builder
+                .constraints
+                .min_credits
+                .is_none_or(|min| total_credits >= min)
+            {
+                self.record();
+            }
+            return;
+        };
+        let required = *required;
+        for section in 0..sections.len() {
+            let idx = self.groups[depth].1[section];
+            let total = total_credits + self.credits[idx];
+            if self
+                .builder
+                .constraints
+                .max_credits
+                .is_some_and(|max| total > max)
+            {
+                continue;
+            }
+            let conflicts = self.picked.iter().any(|&other| {
+                self.slots[idx]
+                    .iter()
+                    .any(|slot| self.slots[other].iter().any(|rhs| slot.overlaps(rhs)))
+            });
+            if conflicts {
+                continue;
+            }
+            self.picked.push(idx);
+            self.search(depth + 1, total);
+            self.picked.pop();
+        }
+        if !required {
+            self.search(depth + 1, total_credits);
+        }
+    }
+}
+
+/// 필수, 선택 과목과 제약 조건으로 시간이 겹치지 않는 시간표 후보를 만드는 빌더
+///
+/// 후보는 학점이 많은 순, 수업 요일이 적은 순, 공강 시간이 적은 순으로 정렬됩니다.
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use rusaint::application::USaintClientBuilder;
+/// # use rusaint::application::course_schedule::{CourseScheduleApplication, model::LectureCategory, scheduler::{ScheduleBuilder, ScheduleConstraints}};
+/// # use rusaint::application::personal_course_schedule::model::Weekday;
+/// # use rusaint::model::SemesterType;
+/// let mut app = USaintClientBuilder::new().build_into::<CourseScheduleApplication>().await.unwrap();
+/// let category = LectureCategory::major("IT대학", "글로벌미디어학부", None);
+/// let lectures = app.find_lectures(2024, SemesterType::One, &category).await.unwrap();
+/// let candidates = ScheduleBuilder::new(lectures)
+///     .required("21501015")
+///     .optional("21501020")
+///     .constraints(ScheduleConstraints::new().exclude_weekday(Weekday::Fri).max_credits(18.0))
+///     .build();
+/// for candidate in candidates {
+///     println!("{:?}", candidate);
+/// }
+/// # })
+/// ```
+#[derive(Clone, Debug)]
+pub struct ScheduleBuilder {
+    lectures: Vec<Lecture>,
+    required: Vec<String>,
+    optional: Vec<String>,
+    constraints: ScheduleConstraints,
+    max_results: usize,
+}
+
+impl ScheduleBuilder {
+    /// 시간표를 구성할 강의 목록으로 새로운 빌더를 만듭니다.
+    pub fn new(lectures: impl IntoIterator<Item = Lecture>) -> ScheduleBuilder {
+        ScheduleBuilder {
+            lectures: lectures.into_iter().collect(),
+            required: Vec::new(),
+            optional: Vec::new(),
+            constraints: ScheduleConstraints::default(),
+            max_results: DEFAULT_MAX_RESULTS,
+        }
+    }
+
+    /// 반드시 포함해야 하는 과목번호를 추가합니다.
+    pub fn required(mut self, code: &str) -> ScheduleBuilder {
+        self.required.push(code.to_string());
+        self
+    }
+
+    /// 가능하면 포함할 과목번호를 추가합니다. 필수 과목으로 추가된 과목번호는 필수 과목으로 취급합니다.
+    pub fn optional(mut self, code: &str) -> ScheduleBuilder {
+        self.optional.push(code.to_string());
+        self
+    }
+
+    /// 제약 조건을 설정합니다.
+    pub fn constraints(mut self, constraints: ScheduleConstraints) -> ScheduleBuilder {
+        self.constraints = constraints;
+        self
+    }
+
+    /// 반환할 최대 후보 수를 설정합니다. 기본값은 20입니다.
+    pub fn max_results(mut self, max_results: usize) -> ScheduleBuilder {
+        self.max_results = max_results;
+        self
+    }
+
+    /// 조건을 만족하는 시간표 후보를 순위대로 반환합니다.
+    ///
+    /// 필수 과목 중 조건을 만족하는 분반이 하나도 없다면 빈 목록을 반환합니다.
+    /// 학점으로 더 높은 순위가 될 수 없는 조합은 탐색하지 않으며, 조합이 매우 많다면 일부만 탐색합니다.
+    pub fn build(self) -> Vec<ScheduleCandidate> {
+        let sections = |code: &String| -> Vec<usize> {
+            self.lectures
+                .iter()
+                .enumerate()
+                .filter(|(_, lecture)| lecture.code() == code.as_str())
+                .filter(|(_, lecture)| {
+                    lecture
+                        .time_slots()
+                        .iter()
+                        .all(|slot| self.constraints.allows(slot))
+                })
+                .map(|(idx, _)| idx)
+                .collect()
+        };
+        // 필수와 선택에 모두 있거나 여러 번 추가된 과목이 두 번 배치되지 않도록 과목번호마다 분류를 하나만 만듭니다.
+        let mut codes = HashSet::new();
+        let mut groups: Vec<(bool, Vec<usize>)> = Vec::new();
+        for (required, code) in self
+            .required
+            .iter()
+            .map(|code| (true, code))
+            .chain(self.optional.iter().map(|code| (false, code)))
+        {
+            if codes.insert(code.as_str()) {
+                groups.push((required, sections(code)));
+            }
+        }
+        if groups
+            .iter()
+            .any(|(required, sections)| *required && sections.is_empty())
+        {
+            return Vec::new();
+        }
+        let slots: Vec<Vec<LectureTimeSlot>> =
+            self.lectures.iter().map(Lecture::time_slots).collect();
+        let credits: Vec<f32> = self
+            .lectures
+            .iter()
+            .map(|lecture| lecture.credits().map_or(0.0, |credits| credits.credits()))
+            .collect();
+        let mut remaining_credits = vec![0.0; groups.len() + 1];
+        for (depth, (_, sections)) in groups.iter().enumerate().rev() {
+            let max = sections.iter().map(|&idx| credits[idx]).fold(0.0, f32::max);
+            remaining_credits[depth] = remaining_credits[depth + 1] + max;
+        }
+        let mut search = ScheduleSearch {
+            builder: &self,
+            groups,
+            slots,
+            credits,
+            remaining_credits,
+            visited: 0,
+            picked: Vec::new(),
+            best: Vec::new(),
+        };
+        search.search(0, 0.0);
+        search.best
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ScheduleBuilder, ScheduleConstraints, find_conflicts};
+    use crate::application::{
+        course_schedule::model::{Lecture, lecture_fixture},
+        personal_course_schedule::model::Weekday,
+    };
+
+    fn lecture(code: &str, division: &str, schedule: &str) -> Lecture {
+        lecture_fixture(&[
+            ("이수구분(주전공)", "전필"),
+            ("과목번호", code),
+            ("과목명", code),
+            ("분반", division),
+            ("강의시간(강의실)", schedule),
+        ])
+    }
+
+    #[test]
+    fn detect_conflicts() {
+        let lectures = [
+            lecture("A", "01", "월 09:00-10:15 (정보과학관 21203-교수)"),
+            lecture("B", "01", "월 10:00-11:15 (정보과학관 21204-교수)"),
+            lecture("C", "01", "화 10:00-11:15 (정보과학관 21204-교수)"),
+        ];
+        assert_eq!(find_conflicts(&lectures), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn build_candidates() {
+        let lectures = [
+            lecture("A", "01", "월 09:00-10:15 (정보과학관 21203-교수)"),
+            lecture("A", "02", "금 10:30-11:45 (정보과학관 21203-교수)"),
+            lecture("B", "01", "월 09:00-10:15 (정보과학관 21204-교수)"),
+            lecture("B", "02", "화 10:30-11:45 (정보과학관 21204-교수)"),
+        ];
+        let candidates = ScheduleBuilder::new(lectures)
+            .required("A")
+            .optional("B")
+            .constraints(ScheduleConstraints::new().exclude_weekday(Weekday::Fri))
+            .build();
+        let best = &candidates[0];
+        assert_eq!(best.credits(), 6.0);
+        assert_eq!(best.lectures().len(), 2);
+        assert!(
+            candidates
+                .iter()
+                .all(|candidate| candidate.lectures()[0].division() == Some("01"))
+        );
+    }
+
+    #[test]
+    fn places_each_code_once() {
+        let lectures = [
+            lecture("A", "01", "월 09:00-10:15 (정보과학관 21203-교수)"),
+            lecture("A", "02", "화 09:00-10:15 (정보과학관 21203-교수)"),
+            lecture("B", "01", "수 09:00-10:15 (정보과학관 21204-교수)"),
+        ];
+        let candidates = ScheduleBuilder::new(lectures)
+            .required("A")
+            .optional("A")
+            .optional("B")
+            .optional("B")
+            .build();
+        assert!(!candidates.is_empty());
+        for candidate in &candidates {
+            let codes: Vec<&str> = candidate.lectures().iter().map(Lecture::code).collect();
+            assert_eq!(codes.iter().filter(|code| **code == "A").count(), 1);
+            assert!(codes.iter().filter(|code| **code == "B").count() <= 1);
+        }
+        assert_eq!(candidates[0].lectures().len(), 2);
+    }
+
+    #[test]
+    fn finds_best_candidates_in_large_search() {
+        let lectures = (0..16).flat_map(|group| {
+            let code = format!("G{group:02}");
+            [
+                lecture_fixture(&[
+                    ("과목번호", &code),
+                    ("분반", "01"),
+                    ("시간/학점(설계)", "1.0/1.0(0)"),
+                ]),
+                lecture_fixture(&[
+                    ("과목번호", &code),
+                    ("분반", "02"),
+                    ("시간/학점(설계)", "3.0/3.0(0)"),
+                ]),
+            ]
+        });
+        let codes: Vec<String> = (0..16).map(|group| format!("G{group:02}")).collect();
+        let candidates = codes
+            .iter()
+            .fold(ScheduleBuilder::new(lectures), |builder, code| {
+                builder.optional(code)
+            })
+            .constraints(ScheduleConstraints::new().max_credits(19.0))
+            .max_results(3)
+            .build();
+        assert_eq!(candidates.len(), 3);
+        assert!(
+            candidates
+                .iter()
+                .all(|candidate| candidate.credits() == 19.0)
+        );
+    }
+
+    #[test]
+    fn prefers_fewer_days_on_same_credits() {
+        let lectures = [
+            lecture("A", "01", "월 09:00-10:15 (정보과학관 21203-교수)"),
+            lecture("B", "01", "화 09:00-10:15 (정보과학관 21204-교수)"),
+            lecture("B", "02", "월 10:30-11:45 (정보과학관 21204-교수)"),
+        ];
+        let candidates = ScheduleBuilder::new(lectures)
+            .required("A")
+            .required("B")
+            .max_results(1)
+            .build();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].days(), 1);
+        assert_eq!(candidates[0].lectures()[1].division(), Some("02"));
+    }
+}
@@ -0,0 +1,383 @@
+use std::{collections::HashMap, time::Duration};
+
+use crate::{
+    ApplicationError, RusaintError,
+    application::utils::poller::{PollTarget, Poller},
+    model::SemesterType,
+};
+
+use super::{
+    CourseScheduleApplication,
+    catalog::LectureKey,
+    model::{Lecture, LectureCategory, RemainingSeats},
+};
+
+/// [`SeatMonitor`]가 발생시키는 여석 변경 이벤트
+#[derive(Debug, Clone)]
+pub enum SeatEvent {
+    /// 여석이 없던 강의에 여석이 생김
+    Opened {
+        /// 여석이 생긴 강의
+        lecture: Lecture,
+        /// 현재 여석
+        remaining_seats: RemainingSeats,
+    },
+    /// 여석이 있던 강의의 여석이 모두 찼음
+    Closed(Lecture),
+    /// 여석이 있는 상태에서 여석 수가 변경됨
+    Changed {
+        /// 변경된 강의
+        lecture: Lecture,
+        /// 변경 전 여석
+        previous: RemainingSeats,
+        /// 변경 후 여석
+        current: RemainingSeats,
+    },
+    /// 감시 중인 강의를 검색 결과에서 찾을 수 없음
+    NotFound(LectureKey),
+    /// 감시 중인 강의의 여석을 읽을 수 없음, 마지막으로 확인한 여석은 유지됨
+    Unreadable(Lecture),
+}
+
+#[derive(Debug)]
+struct SeatTarget {
+    keyword: String,
+    key: LectureKey,
+    // 마지막으로 확인한 여석, 강의를 찾지 못한 경우 `None`
+    remaining_seats: Option<RemainingSeats>,
+    found: bool,
+    // 마지막으로 찾은 강의의 여석을 읽을 수 있었는지 여부
+    readable: bool,
+}
+
+#[derive(Debug)]
+struct SeatTargets {
+    year: u32,
+    semester: SemesterType,
+    polled: bool,
+    targets: Vec<SeatTarget>,
+}
+
+impl PollTarget for SeatTargets {
+    type App = CourseScheduleApplication;
+    type Event = SeatEvent;
+
+    const DEFAULT_INTERVAL: Duration = Duration::from_secs(60);
+    const MINIMUM_INTERVAL: Duration = Duration::from_secs(15);
+
+    async fn poll(
+        &mut self,
+        app: &mut CourseScheduleApplication,
+    ) -> Result<Vec<SeatEvent>, RusaintError> {
+        let mut keywords: Vec<String> = self
+            .targets
+            .iter()
+            .map(|target| target.keyword.clone())
+            .collect();
+        keywords.sort();
+        keywords.dedup();
+        let mut results: HashMap<LectureKey, Lecture> = HashMap::new();
+        for keyword in keywords {
+            let category = LectureCategory::find_by_lecture(&keyword);
+            match app.find_lectures(self.year, self.semester, &category).await {
+                Ok(lectures) => {
+                    results.extend(lectures.map(|lecture| (LectureKey::of(&lecture), lecture)))
+                }
+                Err(RusaintError::ApplicationError(ApplicationError::NoLectureResult)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        let events = diff_seats(&mut self.targets, results, !self.polled);
+        self.polled = true;
+        Ok(events)
+    }
+}
+
+/// 검색한 강의의 여석을 이전에 확인한 여석과 비교하여 변경 이벤트를 만들고, 이전 여석을 갱신합니다.
+///
+/// 여석을 읽을 수 없는 강의는 해당 강의에 대해서만 [`SeatEvent::Unreadable`]을 한 번 발생시키고 이전 여석을 유지합니다.
+fn diff_seats(
+    targets: &mut [SeatTarget],
+    mut results: HashMap<LectureKey, Lecture>,
+    initial: bool,
+) -> Vec<SeatEvent> {
+    let mut events = Vec::new();
+    for target in targets.iter_mut() {
+        let Some(lecture) = results.remove(&target.key) else {
+            if target.found || initial {
+                events.push(SeatEvent::NotFound(target.key.clone()));
+            }
+            target.found = false;
+            target.readable = true;
+            target.remaining_seats = None;
+            continue;
+        };
+        target.found = true;
+        let Some(current) = lecture.seat_availability() else {
+            if target.readable {
+                events.push(SeatEvent::Unreadable(lecture));
+            }
+            target.readable = false;
+            continue;
+        };
+        target.readable = true;
+        match target.remaining_seats.replace(current) {
+            None if current.is_available() => events.push(SeatEvent::Opened {
+                lecture,
+                remaining_seats: current,
+            }),
+            Some(previous) if !previous.is_available() && current.is_available() => {
+                events.push(SeatEvent::Opened {
+                    lecture,
+                    remaining_seats: current,
+                })
+            }
+            Some(previous) if previous.is_available() && !current.is_available() => {
+                events.push(SeatEvent::Closed(lecture))
+            }
+            Some(previous) if previous != current => events.push(SeatEvent::Changed {
+                lecture,
+                previous,
+                current,
+            }),
+            _ => {}
+        }
+    }
+    events
+}
+
+/// 특정 강의들의 여석을 주기적으로 확인하여 변경 사항을 알려주는 감시자
+///
+/// 하나의 [`CourseScheduleApplication`]을 계속 재사용하며, 같은 검색어를 사용하는 강의는 한 번의 검색으로 확인합니다.
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::model::SemesterType;
+/// # use rusaint::application::course_schedule::{ CourseScheduleApplication, seat_monitor::{SeatEvent, SeatMonitorBuilder} };
+/// # use rusaint::application::USaintClientBuilder;
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let app = USaintClientBuilder::new().session(session).build_into::<CourseScheduleApplication>().await.unwrap();
+/// let mut monitor = SeatMonitorBuilder::new(2025, SemesterType::One)
+///     .target("컴퓨터구조", "2150013601", Some("01"))
+///     .build(app);
+/// loop {
+///     for event in monitor.next().await.unwrap() {
+///         if let SeatEvent::Opened { lecture, remaining_seats } = event {
+///             println!("{} has {} seats", lecture.name(), remaining_seats);
+///         }
+///     }
+/// }
+/// # })
+/// ```
+#[derive(Debug)]
+pub struct SeatMonitor(Poller<SeatTargets>);
+
+impl SeatMonitor {
+    /// 감시 중인 강의들의 여석을 한 번 확인하고, 이전 상태와 비교하여 변경 사항을 반환합니다.
+    ///
+    /// 처음 호출될 때에는 [`SeatMonitorBuilder::emit_initial`]이 설정되지 않았다면 기준 상태만 기록하고 빈 목록을 반환합니다.
+    /// 마지막 확인으로부터 15초가 지나지 않았다면 지날 때까지 기다린 후 확인합니다.
+    pub async fn poll(&mut self) -> Result<Vec<SeatEvent>, RusaintError> {
+        self.0.poll().await
+    }
+
+    /// 변경 사항이 생길 때까지 설정된 주기마다 여석을 확인하고, 변경 사항을 반환합니다.
+    pub async fn next(&mut self) -> Result<Vec<SeatEvent>, RusaintError> {
+        self.0.next().await
+    }
+
+    /// 기준 여석을 확인했는지 여부를 반환합니다.
+    pub fn initialized(&self) -> bool {
+        self.0.initialized()
+    }
+
+    /// 감시 중인 강의와 마지막으로 확인한 여석을 반환합니다. 강의를 찾지 못했다면 여석은 `None`입니다.
+    pub fn targets(&self) -> impl Iterator<Item = (&LectureKey, Option<RemainingSeats>)> {
+        self.0
+            .target()
+            .targets
+            .iter()
+            .map(|target| (&target.key, target.remaining_seats))
+    }
+
+    /// 감시에 사용하는 애플리케이션을 반환합니다.
+    pub fn app_mut(&mut self) -> &mut CourseScheduleApplication {
+        self.0.app_mut()
+    }
+
+    /// 감시를 종료하고 내부 애플리케이션을 반환합니다.
+    pub fn into_inner(self) -> CourseScheduleApplication {
+        self.0.into_inner()
+    }
+}
+
+/// 새로운 [`SeatMonitor`]를 생성하는 빌더
+#[derive(Debug)]
+pub struct SeatMonitorBuilder {
+    year: u32,
+    semester: SemesterType,
+    interval: Duration,
+    emit_initial: bool,
+    targets: Vec<SeatTarget>,
+}
+
+impl SeatMonitorBuilder {
+    /// 감시할 학기로 새로운 빌더를 만듭니다.
+    pub fn new(year: u32, semester: SemesterType) -> SeatMonitorBuilder {
+        SeatMonitorBuilder {
+            year,
+            semester,
+            interval: SeatTargets::DEFAULT_INTERVAL,
+            emit_initial: false,
+            targets: Vec::new(),
+        }
+    }
+
+    /// 과목명 검색에 사용할 검색어와 과목번호, 분반으로 감시할 강의를 추가합니다.
+    pub fn target(
+        mut self,
+        keyword: &str,
+        code: &str,
+        division: Option<&str>,
+    ) -> SeatMonitorBuilder {
+        let key = LectureKey::new(code, division);
+        if !self.targets.iter().any(|target| target.key == key) {
+            self.targets.push(SeatTarget {
+                keyword: keyword.to_string(),
+                key,
+                remaining_seats: None,
+                found: false,
+                readable: true,
+            });
+        }
+        self
+    }
+
+    /// 검색한 강의를 감시할 강의로 추가합니다. 강의명을 검색어로 사용합니다.
+    pub fn lecture(self, lecture: &Lecture) -> SeatMonitorBuilder {
+        self.target(lecture.name(), lecture.code(), lecture.division())
+    }
+
+    /// 여석을 다시 확인하는 주기를 설정합니다. 기본값은 1분이며, 15초보다 짧게 설정할 수 없습니다.
+    pub fn interval(mut self, interval: Duration) -> SeatMonitorBuilder {
+        self.interval = interval.max(SeatTargets::MINIMUM_INTERVAL);
+        self
+    }
+
+    /// 처음 확인한 여석도 이벤트로 발생시킬지 설정합니다.
+    pub fn emit_initial(mut self, emit_initial: bool) -> SeatMonitorBuilder {
+        self.emit_initial = emit_initial;
+        self
+    }
+
+    /// 주어진 애플리케이션으로 [`SeatMonitor`]를 생성합니다.
+    pub fn build(self, app: CourseScheduleApplication) -> SeatMonitor {
+        let targets = SeatTargets {
+            year: self.year,
+            semester: self.semester,
+            polled: false,
+            targets: self.targets,
+        };
+        SeatMonitor(Poller::new(app, targets, self.interval, self.emit_initial))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::{SeatEvent, SeatTarget, diff_seats};
+    use crate::application::course_schedule::{
+        catalog::LectureKey,
+        model::{Lecture, RemainingSeats, lecture_fixture},
+    };
+
+    fn target(code: &str) -> SeatTarget {
+        SeatTarget {
+            keyword: String::new(),
+            key: LectureKey::new(code, Some("01")),
+            remaining_seats: None,
+            found: false,
+            readable: true,
+        }
+    }
+
+    fn results(lectures: &[(&str, &str)]) -> HashMap<LectureKey, Lecture> {
+        lectures
+            .iter()
+            .map(|(code, seats)| {
+                let lecture = lecture_fixture(&[("과목번호", code), ("여석", seats)]);
+                (LectureKey::of(&lecture), lecture)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn reports_seat_changes() {
+        let mut targets = vec![target("1"), target("2")];
+        let events = diff_seats(&mut targets, results(&[("1", "0"), ("2", "3")]), true);
+        assert!(matches!(
+            &events[..],
+            [SeatEvent::Opened {
+                remaining_seats: RemainingSeats::Limited(3),
+                ..
+            }]
+        ));
+        let events = diff_seats(&mut targets, results(&[("1", "2"), ("2", "0")]), false);
+        assert!(matches!(
+            &events[..],
+            [SeatEvent::Opened { remaining_seats: RemainingSeats::Limited(2), .. }, SeatEvent::Closed(lecture)]
+                if lecture.code() == "2"
+        ));
+        let events = diff_seats(&mut targets, results(&[("1", "1"), ("2", "0")]), false);
+        assert!(matches!(
+            &events[..],
+            [SeatEvent::Changed {
+                previous: RemainingSeats::Limited(2),
+                current: RemainingSeats::Limited(1),
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn unlimited_seats_are_available() {
+        let mut targets = vec![target("1")];
+        diff_seats(&mut targets, results(&[("1", "0")]), true);
+        let events = diff_seats(&mut targets, results(&[("1", "제한없음")]), false);
+        assert!(matches!(
+            &events[..],
+            [SeatEvent::Opened {
+                remaining_seats: RemainingSeats::Unlimited,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn reports_missing_lecture_once() {
+        let mut targets = vec![target("1")];
+        diff_seats(&mut targets, results(&[("1", "1")]), true);
+        let events = diff_seats(&mut targets, results(&[]), false);
+        assert!(matches!(&events[..], [SeatEvent::NotFound(key)] if key.code() == "1"));
+        assert!(diff_seats(&mut targets, results(&[]), false).is_empty());
+        assert_eq!(targets[0].remaining_seats, None);
+    }
+
+    #[test]
+    fn reports_unreadable_seats_per_target() {
+        let mut targets = vec![target("1"), target("2")];
+        diff_seats(&mut targets, results(&[("1", "2"), ("2", "0")]), true);
+        let events = diff_seats(&mut targets, results(&[("1", "-"), ("2", "1")]), false);
+        assert!(matches!(
+            &events[..],
+            [SeatEvent::Unreadable(lecture), SeatEvent::Opened { .. }] if lecture.code() == "1"
+        ));
+        assert_eq!(targets[0].remaining_seats, Some(RemainingSeats::Limited(2)));
+        assert!(targets[0].found);
+        assert!(diff_seats(&mut targets, results(&[("1", "-"), ("2", "1")]), false).is_empty());
+        let events = diff_seats(&mut targets, results(&[("1", "2"), ("2", "1")]), false);
+        assert!(events.is_empty());
+    }
+}
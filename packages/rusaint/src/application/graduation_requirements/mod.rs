@@ -165,6 +165,8 @@ impl<'a> GraduationRequirementsApplication {
 
 /// 졸업사정표 애플리케이션에서 사용되는 데이터의 모듈
 pub mod model;
+/// 부족한 졸업요건과 개설 강의를 대응시키는 졸업 계획: [`GraduationPlan`](planner::GraduationPlan)
+pub mod planner;
 
 #[cfg(test)]
 mod test {
@@ -0,0 +1,280 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::course_schedule::{catalog::LectureCatalog, model::Lecture};
+
+use super::model::{GraduationRequirement, GraduationRequirements};
+
+// (졸업요건 이수구분에 포함되는 표기, 강의 이수구분의 접두어)
+const CATEGORY_PREFIXES: [(&str, &[&str]); 8] = [
+    ("교양필수", &["교필"]),
+    ("교양선택", &["교선"]),
+    ("전공필수", &["전필"]),
+    ("전공선택", &["전선"]),
+    ("전공기초", &["전기"]),
+    ("채플", &["채플"]),
+    ("교직", &["교직"]),
+    ("전공", &["전필", "전선", "전기"]),
+];
+
+/// 졸업요건별 부족분과 이를 채울 수 있는 개설 강의를 정리한 졸업 계획
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GraduationPlan {
+    requirements: Vec<RequirementPlan>,
+}
+
+/// 충족되지 않은 졸업요건 하나에 대한 부족분 보고서
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RequirementPlan {
+    name: String,
+    category: String,
+    shortfall: f32,
+    matched: bool,
+    suggestions: Vec<Lecture>,
+}
+
+impl GraduationPlan {
+    /// 충족되지 않은 졸업요건들과 학기의 강의 카탈로그로 졸업 계획을 만듭니다.
+    ///
+    /// 이미 졸업요건에 사용된 과목과 같은 이름의 강의는 추천에서 제외되며, 같은 과목번호의 강의는 하나만 추천됩니다.
+    pub fn new(requirements: &GraduationRequirements, catalog: &LectureCatalog) -> GraduationPlan {
+        let taken: HashSet<&str> = requirements
+            .requirements()
+            .values()
+            .flat_map(|requirement| requirement.lectures())
+            .map(|lecture| lecture.trim())
+            .filter(|lecture| !lecture.is_empty())
+            .collect();
+        let mut plans: Vec<RequirementPlan> = requirements
+            .requirements()
+            .values()
+            .filter(|requirement| !requirement.result())
+            .map(|requirement| RequirementPlan::new(requirement, catalog, &taken))
+            .collect();
+        plans.sort_by(|lhs, rhs| lhs.name.cmp(&rhs.name));
+        GraduationPlan {
+            requirements: plans,
+        }
+    }
+
+    /// 충족되지 않은 졸업요건별 보고서
+    pub fn requirements(&self) -> &[RequirementPlan] {
+        &self.requirements
+    }
+
+    /// 모든 부족분을 이번 학기 개설 강의로 채울 수 있는지 여부를 반환합니다.
+    pub fn is_satisfiable(&self) -> bool {
+        self.requirements
+            .iter()
+            .all(RequirementPlan::is_satisfiable)
+    }
+}
+
+fn category_prefixes(requirement: &GraduationRequirement) -> Option<&'static [&'static str]> {
+    let text = format!("{} {}", requirement.category(), requirement.name());
+    CATEGORY_PREFIXES
+        .iter()
+        .find(|(keyword, _)| text.contains(keyword))
+        .map(|(_, prefixes)| *prefixes)
+}
+
+fn is_multiple_major(requirement: &GraduationRequirement) -> bool {
+    ["복수", "부전공", "다전공", "연계", "융합"]
+        .iter()
+        .any(|keyword| requirement.name().contains(keyword))
+}
+
+impl RequirementPlan {
+    fn new(
+        requirement: &GraduationRequirement,
+        catalog: &LectureCatalog,
+        taken: &HashSet<&str>,
+    ) -> RequirementPlan {
+        let shortfall = match (requirement.difference(), requirement.requirement()) {
+            (Some(difference), _) if difference < 0.0 => -difference,
+            (_, Some(base)) => (base as f32 - requirement.calcuation().unwrap_or(0.0)).max(0.0),
+            _ => 0.0,
+        };
+        let prefixes = category_prefixes(requirement);
+        let multiple_major = is_multiple_major(requirement);
+        let mut codes = HashSet::new();
+        let mut suggestions: Vec<Lecture> = prefixes
+            .map(|prefixes| {
+                catalog
+                    .lectures()
+                    .filter(|lecture| {
+                        let category = if multiple_major {
+                            lecture.sub_category().unwrap_or_default()
+                        } else {
+                            lecture.category()
+                        };
+                        prefixes.iter().any(|prefix| category.starts_with(prefix))
+                    })
+                    .filter(|lecture| !taken.contains(lecture.name().trim()))
+                    .filter(|lecture| codes.insert(lecture.code().to_string()))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+        suggestions.sort_by(|lhs, rhs| {
+            let credits = |lecture: &Lecture| lecture.credits().map_or(0.0, |c| c.credits());
+            credits(rhs)
+                .total_cmp(&credits(lhs))
+                .then_with(|| lhs.code().cmp(rhs.code()))
+        });
+        RequirementPlan {
+            name: requirement.name().to_string(),
+            category: requirement.category().to_string(),
+            shortfall,
+            matched: prefixes.is_some(),
+            suggestions,
+        }
+    }
+
+    /// 졸업요건
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 졸업요건의 이수구분
+    pub fn category(&self) -> &str {
+        &self.category
+    }
+
+    /// 부족한 학점(또는 횟수)
+    pub fn shortfall(&self) -> f32 {
+        self.shortfall
+    }
+
+    /// 졸업요건의 이수구분을 강의 이수구분과 대응시킬 수 있었는지 여부
+    ///
+    /// `false`라면 추천 강의가 없으며, 직접 확인이 필요한 졸업요건입니다.
+    pub fn matched(&self) -> bool {
+        self.matched
+    }
+
+    /// 부족분을 채울 수 있는 개설 강의, 학점이 높은 순서로 정렬됩니다.
+    pub fn suggestions(&self) -> &[Lecture] {
+        &self.suggestions
+    }
+
+    /// 추천 강의를 모두 수강했을 때 얻을 수 있는 학점
+    pub fn suggested_credits(&self) -> f32 {
+        self.suggestions
+            .iter()
+            .filter_map(Lecture::credits)
+            .map(|credits| credits.credits())
+            .sum()
+    }
+
+    /// 추천 강의로 부족분을 채울 수 있는지 여부를 반환합니다.
+    pub fn is_satisfiable(&self) -> bool {
+        self.matched && self.suggested_credits() >= self.shortfall
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use serde::{
+        Deserialize,
+        de::{IntoDeserializer, value::MapDeserializer},
+    };
+
+    use super::GraduationPlan;
+    use crate::application::{
+        course_schedule::{
+            catalog::LectureCatalog,
+            model::{Lecture, LectureCategory, lecture_fixture},
+        },
+        graduation_requirements::model::{GraduationRequirement, GraduationRequirements},
+    };
+
+    fn deserialize<T: for<'de> Deserialize<'de>>(values: &[(&str, &str)]) -> T {
+        let map: HashMap<String, String> = values
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map.into_deserializer();
+        T::deserialize(map_de).unwrap()
+    }
+
+    fn requirement(
+        name: &str,
+        category: &str,
+        base: &str,
+        calc: &str,
+        diff: &str,
+    ) -> GraduationRequirement {
+        deserialize(&[
+            ("졸업요건", name),
+            ("기준값", base),
+            ("계산값", calc),
+            ("계산값 - 기준값", diff),
+            (
+                "결과",
+                if diff.starts_with('-') {
+                    "부족"
+                } else {
+                    "충족"
+                },
+            ),
+            ("이수구분", category),
+            ("과목사용", "자료구조, 운영체제"),
+        ])
+    }
+
+    fn lecture(code: &str, name: &str, category: &str, credits: &str) -> Lecture {
+        lecture_fixture(&[
+            ("이수구분(주전공)", category),
+            ("과목번호", code),
+            ("과목명", name),
+            ("시간/학점(설계)", credits),
+            ("여석", "3"),
+        ])
+    }
+
+    #[test]
+    fn plan_shortfalls() {
+        let requirements = GraduationRequirements::new(
+            false,
+            HashMap::from([
+                (
+                    "전공선택".to_string(),
+                    requirement("학부-전공선택", "전공선택", "30", "24", "-6"),
+                ),
+                (
+                    "교양필수".to_string(),
+                    requirement("학부-교양필수", "교양필수", "19", "19", "0"),
+                ),
+                (
+                    "기타".to_string(),
+                    requirement("학부-봉사", "기타", "1", "0", "-1"),
+                ),
+            ]),
+        );
+        let mut catalog = LectureCatalog::new();
+        catalog.insert(
+            &LectureCategory::cyber(),
+            [
+                lecture("A", "컴퓨터그래픽스", "전선-컴퓨터", "3.0/3.0(0)"),
+                lecture("B", "운영체제", "전선-컴퓨터", "3.0/3.0(0)"),
+                lecture("C", "인공지능", "전선-컴퓨터", "3.0/3.0(0)"),
+                lecture("D", "대학글쓰기", "교필", "2.0/2.0"),
+            ],
+        );
+        let plan = GraduationPlan::new(&requirements, &catalog);
+        assert_eq!(plan.requirements().len(), 2);
+        let major = &plan.requirements()[1];
+        assert_eq!(major.name(), "학부-전공선택");
+        assert_eq!(major.shortfall(), 6.0);
+        let codes: Vec<&str> = major.suggestions().iter().map(Lecture::code).collect();
+        assert_eq!(codes, ["A", "C"]);
+        assert!(major.is_satisfiable());
+        let other = &plan.requirements()[0];
+        assert!(!other.matched());
+        assert!(!plan.is_satisfiable());
+    }
+}
@@ -0,0 +1,174 @@
+use self::model::{
+    AssessmentAnswer, AssessmentQuestion, PendingAssessment, QuestionInput, Questionnaire,
+    submission_error,
+};
+use super::{USaintApplication, USaintClient};
+use crate::application::utils::popup::{close_popups, confirm_popup, popup_error};
+use crate::application::utils::sap_table::{
+    is_empty_table, select_row_with_scroll, try_table_into_with_scroll,
+};
+use crate::webdynpro::command::WebDynproCommandExecutor;
+use crate::webdynpro::element::parser::ElementParser;
+use crate::{
+    RusaintError, define_elements,
+    error::ApplicationError,
+    webdynpro::{
+        client::body::Body,
+        command::element::{
+            action::ButtonPressEventCommand, selection::RadioButtonSelectEventCommand,
+            text::InputFieldChangeEventCommand,
+        },
+        element::{
+            action::{Button, ButtonDef},
+            complex::SapTable,
+            definition::ElementDefinition,
+            selection::RadioButtonDef,
+            text::InputFieldDef,
+        },
+        event::Event,
+    },
+};
+
+/// [강의평가입력](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMB2W1020)
+#[derive(Debug)]
+pub struct LectureAssessmentSubmissionApplication {
+    client: USaintClient,
+    questionnaire: Option<(PendingAssessment, Questionnaire)>,
+}
+
+impl USaintApplication for LectureAssessmentSubmissionApplication {
+    const APP_NAME: &'static str = "ZCMB2W1020";
+
+    fn from_client(client: USaintClient) -> Result<Self, RusaintError> {
+        if client.name() != Self::APP_NAME {
+            Err(RusaintError::InvalidClientError)
+        } else {
+            Ok(Self {
+                client,
+                questionnaire: None,
+            })
+        }
+    }
+}
+
+impl<'a> LectureAssessmentSubmissionApplication {
+    define_elements! {
+        // 강의평가 대상 강의 표
+        TABLE: SapTable<'a> = "ZCMB2W1020.ID_0001:VIW_MAIN.TABLE";
+        // 강의평가 입력 버튼
+        BTN_EVALUATE: Button<'a> = "ZCMB2W1020.ID_0001:VIW_MAIN.BTN_EVAL";
+        // 강의평가 저장 버튼
+        BTN_SUBMIT: Button<'a> = "ZCMB2W1020.ID_0001:VIW_EVAL.BTN_SAVE";
+    }
+
+    const CONFIRM_LABELS: [&'static str; 2] = ["예", "확인"];
+
+    fn body(&self) -> &Body {
+        self.client.body()
+    }
+
+    async fn press(&mut self, button: ButtonDef) -> Result<(), RusaintError> {
+        let event = ElementParser::new(self.body()).read(ButtonPressEventCommand::new(button))?;
+        self.client.process_event(false, event).await?;
+        Ok(())
+    }
+
+    /// 강의평가 대상 강의 목록을 가져옵니다. 이미 평가를 완료한 강의도 포함됩니다.
+    pub async fn pending_assessments(&mut self) -> Result<Vec<PendingAssessment>, RusaintError> {
+        self.questionnaire = None;
+        close_popups(&mut self.client).await?;
+        let parser = ElementParser::new(self.body());
+        if is_empty_table(&parser, Self::TABLE)? {
+            return Ok(Vec::new());
+        }
+        Ok(
+            try_table_into_with_scroll::<PendingAssessment>(&mut self.client, parser, Self::TABLE)
+                .await?,
+        )
+    }
+
+    /// 주어진 강의의 강의평가 설문 문항을 가져옵니다.
+    pub async fn questionnaire(
+        &mut self,
+        assessment: &PendingAssessment,
+    ) -> Result<Vec<AssessmentQuestion>, RusaintError> {
+        self.questionnaire = None;
+        close_popups(&mut self.client).await?;
+        let selected =
+            select_row_with_scroll(&mut self.client, Self::TABLE, |row: &PendingAssessment| {
+                row.is_same(assessment)
+            })
+            .await?;
+        if !selected {
+            return Err(ApplicationError::NoLectureResult.into());
+        }
+        self.press(Self::BTN_EVALUATE).await?;
+        let questionnaire = Questionnaire::from_parser(&ElementParser::new(self.body()));
+        if questionnaire.is_empty() {
+            let error = popup_error(&ElementParser::new(self.body()));
+            close_popups(&mut self.client).await?;
+            if let Some(message) = error {
+                return Err(submission_error(message).into());
+            }
+            return Err(ApplicationError::NoLectureAssessments.into());
+        }
+        let questions = questionnaire.questions().to_vec();
+        self.questionnaire = Some((assessment.clone(), questionnaire));
+        Ok(questions)
+    }
+
+    /// 주어진 강의의 강의평가를 제출하고, 제출 후의 강의평가 대상 강의 목록을 반환합니다.
+    /// `answers`는 [`questionnaire()`](Self::questionnaire)가 반환한 문항 순서대로 주어져야 하며,
+    /// 응답이 부족하거나 문항의 종류와 맞지 않으면 [`ApplicationError::IncompleteAssessment`]를 반환합니다.
+    pub async fn submit(
+        &mut self,
+        assessment: &PendingAssessment,
+        answers: &[AssessmentAnswer],
+    ) -> Result<Vec<PendingAssessment>, RusaintError> {
+        if !self
+            .questionnaire
+            .as_ref()
+            .is_some_and(|(opened, _)| opened.is_same(assessment))
+        {
+            self.questionnaire(assessment).await?;
+        }
+        let Some((_, questionnaire)) = self.questionnaire.take() else {
+            return Err(ApplicationError::NoLectureAssessments.into());
+        };
+        questionnaire.validate(answers)?;
+        let events = {
+            let parser = ElementParser::new(self.body());
+            questionnaire
+                .inputs()
+                .iter()
+                .zip(answers)
+                .map(|(input, answer)| match (input, answer) {
+                    (QuestionInput::Likert(ids), AssessmentAnswer::Likert { option }) => parser
+                        .read(RadioButtonSelectEventCommand::new(
+                            RadioButtonDef::new_dynamic(ids[*option as usize].clone()),
+                        )),
+                    (QuestionInput::FreeText(id), AssessmentAnswer::FreeText { text }) => parser
+                        .read(InputFieldChangeEventCommand::new(
+                            InputFieldDef::new_dynamic(id.clone()),
+                            text,
+                        )),
+                    _ => unreachable!("answers are validated against the questionnaire"),
+                })
+                .collect::<Result<Vec<Event>, _>>()?
+        };
+        for event in events {
+            self.client.process_event(false, event).await?;
+        }
+        self.press(Self::BTN_SUBMIT).await?;
+        confirm_popup(&mut self.client, &Self::CONFIRM_LABELS).await?;
+        let error = popup_error(&ElementParser::new(self.body()));
+        close_popups(&mut self.client).await?;
+        if let Some(message) = error {
+            return Err(submission_error(message).into());
+        }
+        self.pending_assessments().await
+    }
+}
+
+/// [`LectureAssessmentSubmissionApplication`] 애플리케이션에 사용되는 데이터
+pub mod model;
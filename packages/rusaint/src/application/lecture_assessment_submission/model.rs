@@ -0,0 +1,462 @@
+use std::{collections::HashMap, sync::LazyLock};
+
+use regex_lite::Regex;
+use scraper::Selector;
+use serde::{
+    Deserialize, Serialize,
+    de::{IntoDeserializer, value::MapDeserializer},
+};
+
+use crate::application::utils::de_with::deserialize_optional_string;
+use crate::error::ApplicationError;
+use crate::webdynpro::{
+    element::{
+        Element, ElementWrapper,
+        complex::sap_table::{FromSapTable, SapTableHeader, SapTableRow},
+        definition::ElementDefinition,
+        layout::PopupWindow,
+        parser::ElementParser,
+        selection::RadioButton,
+        text::{InputField, TextView},
+    },
+    error::{ElementError, WebDynproError},
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 강의평가 대상 강의
+pub struct PendingAssessment {
+    #[serde(rename(deserialize = "과목번호"))]
+    code: String,
+    #[serde(rename(deserialize = "과목명"))]
+    name: String,
+    #[serde(
+        rename(deserialize = "분반"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    division: Option<String>,
+    #[serde(
+        rename(deserialize = "교수명"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    professor: Option<String>,
+    #[serde(
+        rename(deserialize = "평가여부"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    status: Option<String>,
+}
+
+impl PendingAssessment {
+    /// 과목번호를 반환합니다.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// 과목명을 반환합니다.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 분반을 반환합니다.
+    pub fn division(&self) -> Option<&str> {
+        self.division.as_deref()
+    }
+
+    /// 교수명을 반환합니다.
+    pub fn professor(&self) -> Option<&str> {
+        self.professor.as_deref()
+    }
+
+    /// 평가 여부를 나타내는 문자열을 반환합니다.
+    pub fn status(&self) -> Option<&str> {
+        self.status.as_deref()
+    }
+
+    /// 강의평가를 이미 완료했는지 여부를 반환합니다.
+    pub fn completed(&self) -> bool {
+        self.status.as_deref().is_some_and(|status| {
+            let status = status.trim();
+            status.contains("완료") || status == "Y"
+        })
+    }
+
+    pub(crate) fn is_same(&self, other: &PendingAssessment) -> bool {
+        self.code.trim() == other.code.trim()
+            && self.division.as_deref().map(str::trim) == other.division.as_deref().map(str::trim)
+    }
+}
+
+impl<'body> FromSapTable<'body> for PendingAssessment {
+    fn from_table(
+        header: &'body SapTableHeader,
+        row: &'body SapTableRow,
+        parser: &'body ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let map_string = row.try_row_into::<HashMap<String, String>>(header, parser)?;
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map_string.into_deserializer();
+        Ok(
+            PendingAssessment::deserialize(map_de).map_err(|e| ElementError::InvalidContent {
+                element: row.table_def().id().to_string(),
+                content: e.to_string(),
+            })?,
+        )
+    }
+}
+
+/// 강의평가 문항의 종류
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum AssessmentQuestionKind {
+    /// 주어진 보기 중 하나를 선택하는 척도형 문항
+    Likert {
+        /// 선택할 수 있는 보기
+        options: Vec<String>,
+    },
+    /// 자유롭게 서술하는 문항
+    FreeText,
+}
+
+/// 강의평가 문항
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct AssessmentQuestion {
+    number: u32,
+    text: String,
+    kind: AssessmentQuestionKind,
+}
+
+impl AssessmentQuestion {
+    /// 문항 번호를 반환합니다.
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    /// 문항 내용을 반환합니다.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// 문항의 종류를 반환합니다.
+    pub fn kind(&self) -> &AssessmentQuestionKind {
+        &self.kind
+    }
+}
+
+/// 강의평가 문항에 대한 응답
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum AssessmentAnswer {
+    /// 척도형 문항에서 선택한 보기의 순서(0부터 시작)
+    Likert {
+        /// 선택한 보기의 순서
+        option: u32,
+    },
+    /// 서술형 문항의 응답
+    FreeText {
+        /// 응답 내용
+        text: String,
+    },
+}
+
+/// 문항에 응답하기 위한 입력 엘리먼트의 Id
+#[derive(Clone, Debug)]
+pub(crate) enum QuestionInput {
+    Likert(Vec<String>),
+    FreeText(String),
+}
+
+/// 화면에 표시된 강의평가 설문지
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Questionnaire {
+    questions: Vec<AssessmentQuestion>,
+    inputs: Vec<QuestionInput>,
+}
+
+// 번호가 매겨진 문항 텍스트
+static QUESTION_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*(\d+)\s*[.)]\s*(.+)$").unwrap());
+
+// 설문지를 읽는 도중의 문항과 뒤따르는 `(라디오 버튼 id, 라벨)` 목록, 입력 필드 id
+struct PendingQuestion {
+    number: u32,
+    text: String,
+    options: Vec<(String, String)>,
+    text_input: Option<String>,
+}
+
+impl Questionnaire {
+    /// 페이지에서 번호가 매겨진 문항과 그 뒤에 오는 라디오 버튼, 입력 필드를 묶어 설문지를 읽습니다.
+    /// 팝업 창이 열려 있다면 팝업 창 안의 문항만 읽습니다.
+    pub(crate) fn from_parser(parser: &ElementParser) -> Self {
+        let scope = if parser
+            .document()
+            .select(&Selector::parse(&format!(r#"[ct="{}"]"#, PopupWindow::CONTROL_ID)).unwrap())
+            .next()
+            .is_some()
+        {
+            format!(r#"[ct="{}"] "#, PopupWindow::CONTROL_ID)
+        } else {
+            String::new()
+        };
+        let selector = Selector::parse(
+            &[
+                TextView::CONTROL_ID,
+                RadioButton::CONTROL_ID,
+                InputField::CONTROL_ID,
+            ]
+            .iter()
+            .map(|control_id| format!(r#"{scope}[ct="{control_id}"]"#))
+            .collect::<Vec<String>>()
+            .join(", "),
+        )
+        .unwrap();
+        let mut pending: Vec<PendingQuestion> = Vec::new();
+        for elem_ref in parser.document().select(&selector) {
+            let Ok(element) = ElementWrapper::from_ref(elem_ref) else {
+                continue;
+            };
+            match element {
+                ElementWrapper::TextView(tv) => {
+                    if let Some(captures) = QUESTION_REGEX.captures(tv.text()) {
+                        let Ok(number) = captures[1].parse::<u32>() else {
+                            continue;
+                        };
+                        pending.push(PendingQuestion {
+                            number,
+                            text: captures[2].trim().to_string(),
+                            options: Vec::new(),
+                            text_input: None,
+                        });
+                    }
+                }
+                ElementWrapper::RadioButton(rb) => {
+                    if let Some(PendingQuestion { options, .. }) = pending.last_mut() {
+                        let label = rb
+                            .lsdata()
+                            .text()
+                            .map(|text| text.trim().to_string())
+                            .filter(|text| !text.is_empty())
+                            .unwrap_or_else(|| (options.len() + 1).to_string());
+                        options.push((rb.id().to_string(), label));
+                    }
+                }
+                ElementWrapper::InputField(input) => {
+                    if let Some(PendingQuestion {
+                        options,
+                        text_input,
+                        ..
+                    }) = pending.last_mut()
+                    {
+                        if options.is_empty() && text_input.is_none() {
+                            *text_input = Some(input.id().to_string());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        let mut questionnaire = Self::default();
+        for PendingQuestion {
+            number,
+            text,
+            options,
+            text_input,
+        } in pending
+        {
+            let (kind, input) = if !options.is_empty() {
+                let (ids, labels): (Vec<String>, Vec<String>) = options.into_iter().unzip();
+                (
+                    AssessmentQuestionKind::Likert { options: labels },
+                    QuestionInput::Likert(ids),
+                )
+            } else if let Some(id) = text_input {
+                (
+                    AssessmentQuestionKind::FreeText,
+                    QuestionInput::FreeText(id),
+                )
+            } else {
+                continue;
+            };
+            questionnaire
+                .questions
+                .push(AssessmentQuestion { number, text, kind });
+            questionnaire.inputs.push(input);
+        }
+        questionnaire
+    }
+
+    pub(crate) fn questions(&self) -> &[AssessmentQuestion] {
+        &self.questions
+    }
+
+    pub(crate) fn inputs(&self) -> &[QuestionInput] {
+        &self.inputs
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.questions.is_empty()
+    }
+
+    /// 모든 문항에 알맞은 종류의 응답이 주어졌는지 확인합니다.
+    pub(crate) fn validate(&self, answers: &[AssessmentAnswer]) -> Result<(), ApplicationError> {
+        if answers.len() != self.questions.len() {
+            return Err(ApplicationError::IncompleteAssessment(format!(
+                "expected {} answers, got {}",
+                self.questions.len(),
+                answers.len()
+            )));
+        }
+        for (question, answer) in self.questions.iter().zip(answers) {
+            match (&question.kind, answer) {
+                (
+                    AssessmentQuestionKind::Likert { options },
+                    AssessmentAnswer::Likert { option },
+                ) => {
+                    if *option as usize >= options.len() {
+                        return Err(ApplicationError::IncompleteAssessment(format!(
+                            "question {} has no option {}",
+                            question.number, option
+                        )));
+                    }
+                }
+                (AssessmentQuestionKind::FreeText, AssessmentAnswer::FreeText { .. }) => {}
+                _ => {
+                    return Err(ApplicationError::IncompleteAssessment(format!(
+                        "question {} expects {} answer",
+                        question.number,
+                        match question.kind {
+                            AssessmentQuestionKind::Likert { .. } => "likert",
+                            AssessmentQuestionKind::FreeText => "free text",
+                        }
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 강의평가 요청 후 표시된 오류 메시지를 거부 사유에 맞는 오류로 변환합니다.
+pub(crate) fn submission_error(message: String) -> ApplicationError {
+    if (message.contains("문항") || message.contains("입력") || message.contains("선택"))
+        && [
+            "하십시오",
+            "해주십시오",
+            "해 주십시오",
+            "해주세요",
+            "필수",
+            "않았",
+        ]
+        .iter()
+        .any(|keyword| message.contains(keyword))
+    {
+        ApplicationError::IncompleteAssessment(message)
+    } else {
+        ApplicationError::RequestRejected(message)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        AssessmentAnswer, AssessmentQuestion, AssessmentQuestionKind, QuestionInput, Questionnaire,
+        submission_error,
+    };
+    use crate::{
+        error::ApplicationError,
+        webdynpro::{client::body::body_fixture, element::parser::ElementParser},
+    };
+
+    fn questionnaire() -> Questionnaire {
+        Questionnaire {
+            questions: vec![
+                AssessmentQuestion {
+                    number: 1,
+                    text: "강의 내용이 충실하였다.".to_string(),
+                    kind: AssessmentQuestionKind::Likert {
+                        options: vec!["그렇다".to_string(), "아니다".to_string()],
+                    },
+                },
+                AssessmentQuestion {
+                    number: 2,
+                    text: "건의사항".to_string(),
+                    kind: AssessmentQuestionKind::FreeText,
+                },
+            ],
+            inputs: vec![
+                QuestionInput::Likert(vec!["RB1".to_string(), "RB2".to_string()]),
+                QuestionInput::FreeText("I1".to_string()),
+            ],
+        }
+    }
+
+    #[test]
+    fn validate_answers() {
+        let questionnaire = questionnaire();
+        let text = AssessmentAnswer::FreeText {
+            text: String::new(),
+        };
+        assert!(
+            questionnaire
+                .validate(&[AssessmentAnswer::Likert { option: 1 }, text.clone()])
+                .is_ok()
+        );
+        assert!(matches!(
+            questionnaire.validate(&[AssessmentAnswer::Likert { option: 0 }]),
+            Err(ApplicationError::IncompleteAssessment(_))
+        ));
+        assert!(matches!(
+            questionnaire.validate(&[AssessmentAnswer::Likert { option: 2 }, text.clone()]),
+            Err(ApplicationError::IncompleteAssessment(_))
+        ));
+        assert!(matches!(
+            questionnaire.validate(&[text.clone(), text]),
+            Err(ApplicationError::IncompleteAssessment(_))
+        ));
+    }
+
+    #[test]
+    fn classify_submission_messages() {
+        let classify = |message: &str| submission_error(message.to_string());
+        assert!(matches!(
+            classify("모든 문항을 선택하십시오."),
+            ApplicationError::IncompleteAssessment(_)
+        ));
+        assert!(matches!(
+            classify("강의평가 기간이 아니므로 입력할 수 없습니다."),
+            ApplicationError::RequestRejected(message) if message.contains("기간")
+        ));
+    }
+
+    #[test]
+    fn questionnaire_from_elements() {
+        let body = body_fixture(concat!(
+            r#"<span id="WD01" ct="TV" lsdata="{}">1. 강의 내용이 충실하였다.</span>"#,
+            r#"<span id="RB1" ct="RB" lsdata="{0:'5',1:false,2:true,4:'매우 그렇다',8:'RBG1'}"></span>"#,
+            r#"<span id="RB2" ct="RB" lsdata="{0:'4',1:false,2:true,4:'그렇다',8:'RBG1'}"></span>"#,
+            r#"<span id="WD02" ct="TV" lsdata="{}">2) 건의사항</span>"#,
+            r#"<input id="I1" ct="I" lsdata="{}">"#,
+            r#"<span id="WD03" ct="TV" lsdata="{}">3. 응답할 수 없는 문항</span>"#,
+        ));
+        let questionnaire = Questionnaire::from_parser(&ElementParser::new(&body));
+        assert_eq!(questionnaire.questions().len(), 2);
+        assert_eq!(questionnaire.questions()[0].text, "강의 내용이 충실하였다.");
+        assert!(matches!(
+            &questionnaire.questions()[0].kind,
+            AssessmentQuestionKind::Likert { options } if options == &["매우 그렇다", "그렇다"]
+        ));
+        assert!(matches!(
+            &questionnaire.inputs()[0],
+            QuestionInput::Likert(ids) if ids == &["RB1", "RB2"]
+        ));
+        assert_eq!(questionnaire.questions()[1].number, 2);
+        assert!(matches!(
+            &questionnaire.inputs()[1],
+            QuestionInput::FreeText(id) if id == "I1"
+        ));
+    }
+}
@@ -8,7 +8,10 @@ use crate::{
     session::USaintSession,
     utils::DEFAULT_USER_AGENT,
     webdynpro::{
-        client::{EventProcessResult, WebDynproClient, WebDynproClientBuilder, body::Body},
+        client::{
+            EventProcessResult, WebDynproClient, WebDynproClientBuilder, WebDynproDownload,
+            body::Body,
+        },
         command::element::system::{
             ClientInspectorNotifyEventCommand, CustomClientInfoEventCommand,
             LoadingPlaceholderLoadEventCommand,
@@ -73,6 +76,16 @@ impl<'a> USaintClient {
         self.0.process_event(force_send, event).await
     }
 
+    /// 마지막 이벤트 응답이 열거나 내려받으려는 URL 목록을 반환합니다. [`script_urls()`](WebDynproClient::script_urls)를 참조하세요.
+    pub fn script_urls(&self) -> Vec<Url> {
+        self.0.script_urls()
+    }
+
+    /// 주어진 URL의 파일을 현재 세션으로 내려받습니다.
+    pub async fn download(&self, url: &Url) -> Result<WebDynproDownload, WebDynproError> {
+        Ok(self.0.download(url).await?)
+    }
+
     async fn load_placeholder(&mut self) -> Result<(), WebDynproError> {
         let parser = ElementParser::new(self.body());
         let notify_wd01 = parser.read(ClientInspectorNotifyEventCommand::new(
@@ -177,6 +190,30 @@ pub mod personal_course_schedule;
 /// 강의평가 조회: [`LectureAssessmentApplication`](lecture_assessment::LectureAssessmentApplication)
 pub mod lecture_assessment;
 
-/// 장학금 수혜내역 조회: [`ScholarshipsApplication`](scholarships::ScholarshipsApplication)
+/// 강의평가 입력: [`LectureAssessmentSubmissionApplication`](lecture_assessment_submission::LectureAssessmentSubmissionApplication)
+pub mod lecture_assessment_submission;
+
+/// 장학금 수혜내역 및 신청 현황 조회: [`ScholarshipsApplication`](scholarships::ScholarshipsApplication)
 pub mod scholarships;
+
+/// 수강신청: [`CourseRegistrationApplication`](course_registration::CourseRegistrationApplication)
+pub mod course_registration;
+
+/// 등록금 고지 및 납부 내역 조회: [`TuitionApplication`](tuition::TuitionApplication)
+pub mod tuition;
+
+/// 학적변동 신청 및 내역 조회: [`AcademicStatusApplication`](academic_status::AcademicStatusApplication)
+pub mod academic_status;
+
+/// 증명서 발급 내역 조회 및 출력: [`CertificateApplication`](certificate::CertificateApplication)
+pub mod certificate;
+
+/// 다전공 신청 현황 조회: [`MultipleMajorApplication`](multiple_major::MultipleMajorApplication)
+pub mod multiple_major;
+
+/// 지도교수 및 상담 내역 조회: [`AdvisingApplication`](advising::AdvisingApplication)
+pub mod advising;
+
+/// 학사일정 조회: [`AcademicCalendarApplication`](academic_calendar::AcademicCalendarApplication)
+pub mod academic_calendar;
 pub(crate) mod utils;
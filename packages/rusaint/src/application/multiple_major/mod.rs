@@ -0,0 +1,87 @@
+use self::model::{
+    MajorApplication, MajorApplicationWindow, MajorEligibilityCriterion, MajorProgram,
+};
+use super::{USaintApplication, USaintClient};
+use crate::application::utils::popup::close_popups;
+use crate::application::utils::sap_table::{is_empty_table, try_table_into_with_scroll};
+use crate::webdynpro::element::parser::ElementParser;
+use crate::{
+    RusaintError, define_elements,
+    webdynpro::{
+        client::body::Body,
+        element::complex::{SapTable, SapTableDef, sap_table::FromSapTable},
+    },
+};
+
+/// [다전공신청](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW2440)
+#[derive(Debug)]
+pub struct MultipleMajorApplication {
+    client: USaintClient,
+}
+
+impl USaintApplication for MultipleMajorApplication {
+    const APP_NAME: &'static str = "ZCMW2440";
+
+    fn from_client(client: USaintClient) -> Result<Self, RusaintError> {
+        if client.name() != Self::APP_NAME {
+            Err(RusaintError::InvalidClientError)
+        } else {
+            Ok(Self { client })
+        }
+    }
+}
+
+impl<'a> MultipleMajorApplication {
+    // 아래 ID는 캡처한 ZCMW2440 페이지와 대조하지 못한 추정값이며, 표의 열 구성은 단위 테스트의 픽스처로만 확인함
+    define_elements! {
+        // 신청 가능 과정 표
+        TABLE_PROGRAMS: SapTable<'a> = "ZCMW2440.ID_0001:VIW_MAIN.TABLE_PROGRAM";
+        // 신청 내역 표
+        TABLE_APPLICATIONS: SapTable<'a> = "ZCMW2440.ID_0001:VIW_MAIN.TABLE_APPLY";
+        // 신청 자격 기준 표
+        TABLE_ELIGIBILITY: SapTable<'a> = "ZCMW2440.ID_0001:VIW_MAIN.TABLE_QUALIF";
+    }
+
+    fn body(&self) -> &Body {
+        self.client.body()
+    }
+
+    async fn read_table<T: for<'body> FromSapTable<'body>>(
+        &mut self,
+        table: SapTableDef,
+    ) -> Result<Vec<T>, RusaintError> {
+        close_popups(&mut self.client).await?;
+        let parser = ElementParser::new(self.body());
+        if is_empty_table(&parser, table.clone())? {
+            return Ok(Vec::new());
+        }
+        Ok(try_table_into_with_scroll::<T>(&mut self.client, parser, table).await?)
+    }
+
+    /// 현재 다전공 신청 기간을 가져옵니다. 신청 기간이 아니라면 `None`을 반환합니다.
+    pub async fn application_window(
+        &mut self,
+    ) -> Result<Option<MajorApplicationWindow>, RusaintError> {
+        close_popups(&mut self.client).await?;
+        let parser = ElementParser::new(self.body());
+        Ok(MajorApplicationWindow::with_parser(&parser))
+    }
+
+    /// 현재 신청 기간에 신청 가능한 다전공 과정 목록을 가져옵니다.
+    pub async fn programs(&mut self) -> Result<Vec<MajorProgram>, RusaintError> {
+        self.read_table(Self::TABLE_PROGRAMS).await
+    }
+
+    /// 학생의 다전공 신청 내역을 가져옵니다.
+    pub async fn applications(&mut self) -> Result<Vec<MajorApplication>, RusaintError> {
+        self.read_table(Self::TABLE_APPLICATIONS).await
+    }
+
+    /// 페이지에 표시된 다전공 신청 자격 기준과 학생의 충족 여부를 가져옵니다.
+    pub async fn eligibility(&mut self) -> Result<Vec<MajorEligibilityCriterion>, RusaintError> {
+        self.read_table(Self::TABLE_ELIGIBILITY).await
+    }
+}
+
+/// [`MultipleMajorApplication`] 애플리케이션에 사용되는 데이터
+pub mod model;
@@ -0,0 +1,486 @@
+use std::collections::HashMap;
+
+use serde::{
+    Deserialize, Deserializer, Serialize,
+    de::{IntoDeserializer, value::MapDeserializer},
+};
+
+use crate::application::utils::de_with::deserialize_optional_string;
+use crate::webdynpro::{
+    command::element::text::InputFieldValueCommand,
+    element::{
+        complex::sap_table::{FromSapTable, SapTableHeader, SapTableRow},
+        definition::ElementDefinition,
+        parser::ElementParser,
+        text::{InputField, InputFieldDef},
+    },
+    error::{ElementError, WebDynproError},
+};
+use crate::{define_elements, webdynpro::command::WebDynproCommandExecutor};
+
+/// 다전공 과정의 종류
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum MajorProgramKind {
+    /// 복수전공
+    DoubleMajor,
+    /// 부전공
+    Minor,
+    /// 연계전공
+    ConnectedMajor,
+    /// 융합전공
+    ConvergenceMajor,
+    /// 기타
+    Other,
+}
+
+impl MajorProgramKind {
+    pub(crate) fn from_text(text: &str) -> Self {
+        if text.contains("부전공") {
+            Self::Minor
+        } else if text.contains("연계") {
+            Self::ConnectedMajor
+        } else if text.contains("융합") {
+            Self::ConvergenceMajor
+        } else if text.contains("복수") || text.contains("다전공") {
+            Self::DoubleMajor
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// 다전공 신청의 선발 상태
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum MajorApplicationState {
+    /// 신청 후 선발 대기 중
+    Applied,
+    /// 선발됨
+    Selected,
+    /// 선발되지 않음
+    NotSelected,
+    /// 신청 취소 또는 포기
+    Cancelled,
+    /// 알 수 없는 상태
+    Unknown,
+}
+
+impl MajorApplicationState {
+    pub(crate) fn from_text(text: &str) -> Self {
+        if text.contains("취소") || text.contains("포기") {
+            Self::Cancelled
+        } else if text.contains("불합격") || text.contains("미선발") || text.contains("탈락")
+        {
+            Self::NotSelected
+        } else if text.contains("합격") || text.contains("선발") || text.contains("승인") {
+            Self::Selected
+        } else if text.contains("신청") || text.contains("대기") || text.contains("심사") {
+            Self::Applied
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+/// 다전공 신청 기간
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct MajorApplicationWindow {
+    start: String,
+    end: String,
+}
+
+impl<'a> MajorApplicationWindow {
+    // 캡처한 ZCMW2440 페이지와 대조하지 못한 추정 ID
+    define_elements! {
+        // 신청기간 시작일
+        PERIOD_START: InputField<'a> = "ZCMW2440.ID_0001:VIW_MAIN.APPLY_BEGDA";
+        // 신청기간 종료일
+        PERIOD_END: InputField<'a> = "ZCMW2440.ID_0001:VIW_MAIN.APPLY_ENDDA";
+    }
+
+    pub(crate) fn with_parser(parser: &ElementParser) -> Option<Self> {
+        let text = |def: InputFieldDef| {
+            parser
+                .read(InputFieldValueCommand::new(def))
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        Some(Self {
+            start: text(Self::PERIOD_START)?,
+            end: text(Self::PERIOD_END)?,
+        })
+    }
+
+    /// 신청기간 시작일을 반환합니다.
+    pub fn start(&self) -> &str {
+        &self.start
+    }
+
+    /// 신청기간 종료일을 반환합니다.
+    pub fn end(&self) -> &str {
+        &self.end
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 신청 가능한 다전공 과정
+pub struct MajorProgram {
+    #[serde(rename(deserialize = "과정구분"))]
+    kind_text: String,
+    #[serde(
+        rename(deserialize = "대학"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    college: Option<String>,
+    #[serde(rename(deserialize = "전공명"))]
+    name: String,
+    #[serde(
+        rename(deserialize = "모집인원"),
+        default,
+        deserialize_with = "deserialize_optional_u32"
+    )]
+    quota: Option<u32>,
+}
+
+impl MajorProgram {
+    /// 과정구분 문자열을 반환합니다.
+    pub fn kind_text(&self) -> &str {
+        &self.kind_text
+    }
+
+    /// 과정의 종류를 반환합니다.
+    pub fn kind(&self) -> MajorProgramKind {
+        MajorProgramKind::from_text(&self.kind_text)
+    }
+
+    /// 과정을 운영하는 대학을 반환합니다.
+    pub fn college(&self) -> Option<&str> {
+        self.college.as_deref()
+    }
+
+    /// 전공명을 반환합니다.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 모집인원을 반환합니다.
+    pub fn quota(&self) -> Option<u32> {
+        self.quota
+    }
+}
+
+impl<'body> FromSapTable<'body> for MajorProgram {
+    fn from_table(
+        header: &'body SapTableHeader,
+        row: &'body SapTableRow,
+        parser: &'body ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let map_string = row.try_row_into::<HashMap<String, String>>(header, parser)?;
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map_string.into_deserializer();
+        Ok(
+            MajorProgram::deserialize(map_de).map_err(|e| ElementError::InvalidContent {
+                element: row.table_def().id().to_string(),
+                content: e.to_string(),
+            })?,
+        )
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 학생의 다전공 신청 내역
+pub struct MajorApplication {
+    #[serde(rename(deserialize = "신청일자"))]
+    applied_at: String,
+    #[serde(rename(deserialize = "과정구분"))]
+    kind_text: String,
+    #[serde(rename(deserialize = "전공명"))]
+    name: String,
+    #[serde(
+        rename(deserialize = "학년도"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    year: Option<String>,
+    #[serde(
+        rename(deserialize = "학기"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    semester: Option<String>,
+    #[serde(rename(deserialize = "진행상태"))]
+    state_text: String,
+}
+
+impl MajorApplication {
+    /// 신청일자를 반환합니다.
+    pub fn applied_at(&self) -> &str {
+        &self.applied_at
+    }
+
+    /// 과정구분 문자열을 반환합니다.
+    pub fn kind_text(&self) -> &str {
+        &self.kind_text
+    }
+
+    /// 과정의 종류를 반환합니다.
+    pub fn kind(&self) -> MajorProgramKind {
+        MajorProgramKind::from_text(&self.kind_text)
+    }
+
+    /// 신청한 전공명을 반환합니다.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 신청 학년도를 반환합니다.
+    pub fn year(&self) -> Option<&str> {
+        self.year.as_deref()
+    }
+
+    /// 신청 학기를 반환합니다.
+    pub fn semester(&self) -> Option<&str> {
+        self.semester.as_deref()
+    }
+
+    /// 진행상태 문자열을 반환합니다.
+    pub fn state_text(&self) -> &str {
+        &self.state_text
+    }
+
+    /// 선발 상태를 반환합니다.
+    pub fn state(&self) -> MajorApplicationState {
+        MajorApplicationState::from_text(&self.state_text)
+    }
+}
+
+impl<'body> FromSapTable<'body> for MajorApplication {
+    fn from_table(
+        header: &'body SapTableHeader,
+        row: &'body SapTableRow,
+        parser: &'body ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let map_string = row.try_row_into::<HashMap<String, String>>(header, parser)?;
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map_string.into_deserializer();
+        Ok(
+            MajorApplication::deserialize(map_de).map_err(|e| ElementError::InvalidContent {
+                element: row.table_def().id().to_string(),
+                content: e.to_string(),
+            })?,
+        )
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 다전공 신청 자격 기준
+pub struct MajorEligibilityCriterion {
+    #[serde(rename(deserialize = "기준항목"))]
+    name: String,
+    #[serde(rename(deserialize = "기준"))]
+    requirement: String,
+    #[serde(
+        rename(deserialize = "본인현황"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    current: Option<String>,
+    #[serde(
+        rename(deserialize = "충족여부"),
+        default,
+        deserialize_with = "deserialize_satisfied"
+    )]
+    satisfied: Option<bool>,
+}
+
+impl MajorEligibilityCriterion {
+    /// 기준 항목명을 반환합니다.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 기준 내용을 반환합니다.
+    pub fn requirement(&self) -> &str {
+        &self.requirement
+    }
+
+    /// 학생의 현재 현황을 반환합니다.
+    pub fn current(&self) -> Option<&str> {
+        self.current.as_deref()
+    }
+
+    /// 기준을 충족하는지 여부를 반환합니다. 페이지에 표시되지 않았다면 `None`을 반환합니다.
+    pub fn satisfied(&self) -> Option<bool> {
+        self.satisfied
+    }
+}
+
+impl<'body> FromSapTable<'body> for MajorEligibilityCriterion {
+    fn from_table(
+        header: &'body SapTableHeader,
+        row: &'body SapTableRow,
+        parser: &'body ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let map_string = row.try_row_into::<HashMap<String, String>>(header, parser)?;
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map_string.into_deserializer();
+        Ok(MajorEligibilityCriterion::deserialize(map_de).map_err(|e| {
+            ElementError::InvalidContent {
+                element: row.table_def().id().to_string(),
+                content: e.to_string(),
+            }
+        })?)
+    }
+}
+
+fn deserialize_optional_u32<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<u32>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    let value = value.trim().trim_end_matches('명').replace(',', "");
+    if value.is_empty() {
+        Ok(None)
+    } else {
+        value.parse().map(Some).map_err(serde::de::Error::custom)
+    }
+}
+
+fn deserialize_satisfied<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<bool>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    Ok(parse_satisfied(&value))
+}
+
+fn parse_satisfied(value: &str) -> Option<bool> {
+    match value.trim() {
+        "" => None,
+        "N" | "X" | "미충족" | "불충족" | "부적격" => Some(false),
+        "Y" | "O" | "충족" | "적격" | "true" => Some(true),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        MajorApplication, MajorApplicationState, MajorApplicationWindow, MajorEligibilityCriterion,
+        MajorProgram, MajorProgramKind, parse_satisfied,
+    };
+    use crate::webdynpro::{
+        client::body::{body_fixture, sap_table_fixture},
+        command::{WebDynproCommandExecutor, element::complex::SapTableBodyCommand},
+        element::{
+            complex::{SapTableDef, sap_table::FromSapTable},
+            definition::ElementDefinition,
+            parser::ElementParser,
+        },
+    };
+
+    const TABLE: SapTableDef = SapTableDef::new("ZCMW2440.ID_0001:VIW_MAIN.TABLE");
+
+    fn parse_table<T: for<'body> FromSapTable<'body>>(
+        headers: &[&str],
+        rows: &[&[&str]],
+    ) -> Vec<T> {
+        let body = body_fixture(&sap_table_fixture(TABLE.id(), headers, rows));
+        let parser = ElementParser::new(&body);
+        let table = parser.read(SapTableBodyCommand::new(TABLE)).unwrap();
+        table.try_table_into::<T>(&parser).unwrap()
+    }
+
+    #[test]
+    fn read_application_window() {
+        let body = body_fixture(concat!(
+            r#"<input id="ZCMW2440.ID_0001:VIW_MAIN.APPLY_BEGDA" ct="I" lsdata="{}" value="2025.05.01">"#,
+            r#"<input id="ZCMW2440.ID_0001:VIW_MAIN.APPLY_ENDDA" ct="I" lsdata="{}" value="2025.05.09">"#,
+        ));
+        let window = MajorApplicationWindow::with_parser(&ElementParser::new(&body)).unwrap();
+        assert_eq!(window.start(), "2025.05.01");
+        assert_eq!(window.end(), "2025.05.09");
+        let body = body_fixture(concat!(
+            r#"<input id="ZCMW2440.ID_0001:VIW_MAIN.APPLY_BEGDA" ct="I" lsdata="{}" value="">"#,
+            r#"<input id="ZCMW2440.ID_0001:VIW_MAIN.APPLY_ENDDA" ct="I" lsdata="{}" value="">"#,
+        ));
+        assert!(MajorApplicationWindow::with_parser(&ElementParser::new(&body)).is_none());
+    }
+
+    #[test]
+    fn read_major_tables() {
+        let programs = parse_table::<MajorProgram>(
+            &["과정구분", "대학", "전공명", "모집인원"],
+            &[
+                &["복수전공", "IT대학", "컴퓨터학부", "30명"],
+                &["부전공", "", "경영학부", ""],
+            ],
+        );
+        assert_eq!(programs.len(), 2);
+        assert_eq!(programs[0].kind(), MajorProgramKind::DoubleMajor);
+        assert_eq!(programs[0].college(), Some("IT대학"));
+        assert_eq!(programs[0].quota(), Some(30));
+        assert_eq!(programs[1].kind(), MajorProgramKind::Minor);
+        assert_eq!(programs[1].college(), None);
+        assert_eq!(programs[1].quota(), None);
+
+        let applications = parse_table::<MajorApplication>(
+            &[
+                "신청일자",
+                "과정구분",
+                "전공명",
+                "학년도",
+                "학기",
+                "진행상태",
+            ],
+            &[&["2025.05.02", "복수전공", "컴퓨터학부", "2025", "", "합격"]],
+        );
+        assert_eq!(applications[0].applied_at(), "2025.05.02");
+        assert_eq!(applications[0].name(), "컴퓨터학부");
+        assert_eq!(applications[0].year(), Some("2025"));
+        assert_eq!(applications[0].semester(), None);
+        assert_eq!(applications[0].state(), MajorApplicationState::Selected);
+
+        let criteria = parse_table::<MajorEligibilityCriterion>(
+            &["기준항목", "기준", "본인현황", "충족여부"],
+            &[&["평점평균", "3.0 이상", "3.5", "충족"]],
+        );
+        assert_eq!(criteria[0].name(), "평점평균");
+        assert_eq!(criteria[0].requirement(), "3.0 이상");
+        assert_eq!(criteria[0].current(), Some("3.5"));
+        assert_eq!(criteria[0].satisfied(), Some(true));
+    }
+
+    #[test]
+    fn classify_texts() {
+        assert_eq!(
+            MajorProgramKind::from_text("복수전공"),
+            MajorProgramKind::DoubleMajor
+        );
+        assert_eq!(
+            MajorProgramKind::from_text("부전공"),
+            MajorProgramKind::Minor
+        );
+        assert_eq!(
+            MajorProgramKind::from_text("연계전공"),
+            MajorProgramKind::ConnectedMajor
+        );
+        assert_eq!(
+            MajorApplicationState::from_text("불합격"),
+            MajorApplicationState::NotSelected
+        );
+        assert_eq!(
+            MajorApplicationState::from_text("합격"),
+            MajorApplicationState::Selected
+        );
+        assert_eq!(
+            MajorApplicationState::from_text("신청완료"),
+            MajorApplicationState::Applied
+        );
+        assert_eq!(parse_satisfied("충족"), Some(true));
+        assert_eq!(parse_satisfied("미충족"), Some(false));
+        assert_eq!(parse_satisfied(""), None);
+    }
+}
@@ -0,0 +1,318 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{
+    application::{
+        chapel::model::GeneralChapelInformation, course_schedule::model::LectureTimeSlot,
+    },
+    model::{Date, TimeOfDay},
+};
+
+use super::model::{PersonalCourseSchedule, Weekday};
+
+const PRODUCT_ID: &str = "-//EATSTEAK//rusaint//KO";
+const TIMEZONE: &str = "Asia/Seoul";
+
+#[derive(Clone, Debug)]
+struct WeeklyEvent {
+    // 일정을 구분하는 키, 강의는 과목명, 채플은 분반을 사용
+    key: String,
+    summary: String,
+    weekday: Weekday,
+    start: TimeOfDay,
+    end: TimeOfDay,
+    location: Option<String>,
+    description: Option<String>,
+}
+
+/// 개인 시간표와 채플 일정을 iCalendar(.ics) 형식으로 내보내는 빌더
+///
+/// 학기 시작일부터 종료일까지 매주 반복되는 일정(VEVENT)을 생성합니다.
+/// 각 일정의 UID는 학번, 강의, 요일과 시작 시각으로 만들어지므로, 같은 시간표를 다시 내보내도 캘린더 앱에서 같은 일정으로 인식됩니다.
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::application::USaintClientBuilder;
+/// # use rusaint::application::personal_course_schedule::{PersonalCourseScheduleApplication, ics::ScheduleCalendar};
+/// # use rusaint::model::{Date, SemesterType};
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let mut app = USaintClientBuilder::new().session(session).build_into::<PersonalCourseScheduleApplication>().await.unwrap();
+/// let schedule = app.schedule(2024, SemesterType::One).await.unwrap();
+/// let ics = ScheduleCalendar::new("20212345", Date::new(2024, 3, 4).unwrap(), Date::new(2024, 6, 21).unwrap())
+///     .add_course_schedule(&schedule)
+///     .to_ics();
+/// println!("{ics}");
+/// # })
+/// ```
+#[derive(Clone, Debug)]
+pub struct ScheduleCalendar {
+    student_id: String,
+    start: Date,
+    end: Date,
+    events: Vec<WeeklyEvent>,
+}
+
+impl ScheduleCalendar {
+    /// 학번과 학기 시작일, 종료일로 빈 캘린더를 만듭니다.
+    pub fn new(student_id: &str, start: Date, end: Date) -> ScheduleCalendar {
+        ScheduleCalendar {
+            student_id: student_id.trim().to_string(),
+            start,
+            end,
+            events: Vec::new(),
+        }
+    }
+
+    /// 개인 시간표의 강의들을 캘린더에 추가합니다. 시간이 정해지지 않은 온라인 강의는 제외됩니다.
+    pub fn add_course_schedule(&mut self, schedule: &PersonalCourseSchedule) -> &mut Self {
+        for (weekday, courses) in schedule.schedule() {
+            for course in courses {
+                let (Some(start), Some(end)) = (course.start(), course.end()) else {
+                    continue;
+                };
+                self.events.push(WeeklyEvent {
+                    key: course.name().to_string(),
+                    summary: course.name().to_string(),
+                    weekday: *weekday,
+                    start,
+                    end,
+                    location: non_empty(course.classroom()),
+                    description: non_empty(course.professor())
+                        .map(|professor| format!("교수: {professor}")),
+                });
+            }
+        }
+        self
+    }
+
+    /// 채플 시간과 강의실을 캘린더에 추가합니다.
+    pub fn add_chapel(&mut self, chapel: &GeneralChapelInformation) -> &mut Self {
+        let slots = LectureTimeSlot::parse_all(chapel.chapel_time());
+        if slots.is_empty() {
+            log::warn!("Cannot parse chapel time {}, skip.", chapel.chapel_time());
+        }
+        for slot in slots {
+            self.events.push(WeeklyEvent {
+                key: format!("chapel-{}", chapel.division()),
+                summary: "채플".to_string(),
+                weekday: slot.weekday(),
+                start: slot.start(),
+                end: slot.end(),
+                location: non_empty(chapel.chapel_room()),
+                description: Some(format!(
+                    "분반: {}, 좌석: {}",
+                    chapel.division(),
+                    chapel.seat_number()
+                )),
+            });
+        }
+        self
+    }
+
+    /// 캘린더를 iCalendar 형식의 문자열로 변환합니다.
+    pub fn to_ics(&self) -> String {
+        self.to_ics_at(SystemTime::now())
+    }
+
+    fn to_ics_at(&self, generated_at: SystemTime) -> String {
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            format!("PRODID:{PRODUCT_ID}"),
+            "CALSCALE:GREGORIAN".to_string(),
+            "METHOD:PUBLISH".to_string(),
+            "BEGIN:VTIMEZONE".to_string(),
+            format!("TZID:{TIMEZONE}"),
+            "BEGIN:STANDARD".to_string(),
+            "DTSTART:19700101T000000".to_string(),
+            "TZOFFSETFROM:+0900".to_string(),
+            "TZOFFSETTO:+0900".to_string(),
+            "TZNAME:KST".to_string(),
+            "END:STANDARD".to_string(),
+            "END:VTIMEZONE".to_string(),
+        ];
+        // KST 기준 종료일 23:59:59 를 UTC로 표현
+        let until = format!("{}T145959Z", format_date(&self.end));
+        let stamp = format_timestamp(generated_at);
+        for event in &self.events {
+            let first = first_occurrence(&self.start, event.weekday);
+            if first > self.end {
+                continue;
+            }
+            lines.push("BEGIN:VEVENT".to_string());
+            lines.push(format!("UID:{}", self.uid(event)));
+            lines.push(format!("DTSTAMP:{stamp}"));
+            lines.push(format!(
+                "DTSTART;TZID={TIMEZONE}:{}",
+                format_date_time(&first, &event.start)
+            ));
+            lines.push(format!(
+                "DTEND;TZID={TIMEZONE}:{}",
+                format_date_time(&first, &event.end)
+            ));
+            lines.push(format!("RRULE:FREQ=WEEKLY;UNTIL={until}"));
+            lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+            if let Some(location) = &event.location {
+                lines.push(format!("LOCATION:{}", escape_text(location)));
+            }
+            if let Some(description) = &event.description {
+                lines.push(format!("DESCRIPTION:{}", escape_text(description)));
+            }
+            lines.push("END:VEVENT".to_string());
+        }
+        lines.push("END:VCALENDAR".to_string());
+        lines.iter().fold(String::new(), |mut output, line| {
+            fold_line(&mut output, line);
+            output
+        })
+    }
+}
+
+impl ScheduleCalendar {
+    fn uid(&self, event: &WeeklyEvent) -> String {
+        format!(
+            "{}-{:016x}-{}{:02}{:02}@rusaint",
+            self.student_id,
+            fnv1a(&event.key),
+            event.weekday as u32,
+            event.start.hour(),
+            event.start.minute()
+        )
+    }
+}
+
+// UID에 사용할 수 있도록 강의 키를 실행 환경과 무관하게 항상 같은 값으로 해싱
+fn fnv1a(value: &str) -> u64 {
+    value.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+fn first_occurrence(start: &Date, weekday: Weekday) -> Date {
+    let offset = (weekday as i64 - i64::from(start.weekday_index())).rem_euclid(7);
+    start.add_days(offset)
+}
+
+fn format_date(date: &Date) -> String {
+    format!("{:04}{:02}{:02}", date.year(), date.month(), date.day())
+}
+
+fn format_date_time(date: &Date, time: &TimeOfDay) -> String {
+    format!(
+        "{}T{:02}{:02}00",
+        format_date(date),
+        time.hour(),
+        time.minute()
+    )
+}
+
+fn format_timestamp(time: SystemTime) -> String {
+    let seconds = time
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    let date = Date::from_days_from_epoch((seconds / 86400) as i64);
+    let seconds = seconds % 86400;
+    format!(
+        "{}T{:02}{:02}{:02}Z",
+        format_date(&date),
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+// RFC 5545 3.1: 75 옥텟을 넘는 줄은 접어서 출력
+fn fold_line(output: &mut String, line: &str) {
+    let mut width = 0;
+    for ch in line.chars() {
+        if width + ch.len_utf8() > 75 {
+            output.push_str("\r\n ");
+            width = 1;
+        }
+        output.push(ch);
+        width += ch.len_utf8();
+    }
+    output.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        collections::HashMap,
+        time::{Duration, UNIX_EPOCH},
+    };
+
+    use super::ScheduleCalendar;
+    use crate::{
+        application::personal_course_schedule::model::{
+            CourseScheduleInformation, PersonalCourseSchedule, Weekday,
+        },
+        model::Date,
+    };
+
+    #[test]
+    fn weekly_events() {
+        let courses = CourseScheduleInformation::parse_cell(
+            "컴퓨터구조\n김교수\n09:00-10:15\n정보과학관 21203",
+            Some(1),
+        )
+        .unwrap();
+        let schedule = PersonalCourseSchedule::new(HashMap::from([(Weekday::Wed, courses)]));
+        // 2024-03-04 is Monday
+        let mut calendar = ScheduleCalendar::new(
+            "20212345",
+            Date::new(2024, 3, 4).unwrap(),
+            Date::new(2024, 6, 21).unwrap(),
+        );
+        calendar.add_course_schedule(&schedule);
+        // 2024-02-20T03:04:05Z
+        let ics = calendar.to_ics_at(UNIX_EPOCH + Duration::from_secs(1_708_398_245));
+        assert!(ics.contains("DTSTAMP:20240220T030405Z\r\n"));
+        assert!(ics.contains("DTSTART;TZID=Asia/Seoul:20240306T090000\r\n"));
+        assert!(ics.contains("DTEND;TZID=Asia/Seoul:20240306T101500\r\n"));
+        assert!(ics.contains("RRULE:FREQ=WEEKLY;UNTIL=20240621T145959Z\r\n"));
+        assert!(ics.contains("LOCATION:정보과학관 21203\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    }
+
+    #[test]
+    fn stable_unique_uids() {
+        let courses = CourseScheduleInformation::parse_cell(
+            "컴퓨터구조\n김교수\n09:00-10:15\n정보과학관 21203\n운영체제\n이교수\n10:30-11:45\n정보과학관 21204",
+            Some(1),
+        )
+        .unwrap();
+        let schedule = PersonalCourseSchedule::new(HashMap::from([(Weekday::Wed, courses)]));
+        let uids = |student_id: &str, start: Date| {
+            let mut calendar =
+                ScheduleCalendar::new(student_id, start, Date::new(2024, 6, 21).unwrap());
+            calendar.add_course_schedule(&schedule);
+            calendar
+                .to_ics()
+                .lines()
+                .filter(|line| line.starts_with("UID:"))
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+        let first = uids("20212345", Date::new(2024, 3, 4).unwrap());
+        assert_eq!(first.len(), 2);
+        assert_ne!(first[0], first[1]);
+        assert_eq!(first[0], "UID:20212345-0ff93e4d23aaeb03-20900@rusaint");
+        assert_eq!(first, uids("20212345", Date::new(2024, 3, 11).unwrap()));
+        assert_ne!(first, uids("20201234", Date::new(2024, 3, 4).unwrap()));
+    }
+}
@@ -1,8 +1,10 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr};
+
+use crate::model::TimeOfDay;
 
 /// 한 주의 요일을 표현합니다.
-#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum Weekday {
     /// 월요일
@@ -21,6 +23,24 @@ pub enum Weekday {
     Sun = 6,
 }
 
+impl FromStr for Weekday {
+    type Err = String;
+
+    /// `월`, `화` 등 한글 요일 표기를 [`Weekday`]로 변환합니다.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "월" | "월요일" => Ok(Weekday::Mon),
+            "화" | "화요일" => Ok(Weekday::Tue),
+            "수" | "수요일" => Ok(Weekday::Wed),
+            "목" | "목요일" => Ok(Weekday::Thu),
+            "금" | "금요일" => Ok(Weekday::Fri),
+            "토" | "토요일" => Ok(Weekday::Sat),
+            "일" | "일요일" => Ok(Weekday::Sun),
+            other => Err(format!("Unknown weekday: {other}")),
+        }
+    }
+}
+
 /// 개인의 수업 시간표 정보를 조회합니다.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
@@ -35,21 +55,87 @@ pub struct CourseScheduleInformation {
     name: String,
     professor: String,
     time: String,
+    #[serde(default)]
+    start: Option<TimeOfDay>,
+    #[serde(default)]
+    end: Option<TimeOfDay>,
     classroom: String,
+    #[serde(default)]
+    periods: Vec<u32>,
+}
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+fn is_time_line(line: &str) -> bool {
+    line.starts_with(|c: char| c.is_ascii_digit()) && line.contains(':') && line.contains('-')
+}
+
+fn parse_time_range(line: &str) -> Result<(TimeOfDay, TimeOfDay), String> {
+    let (start, end) = line
+        .split_once('-')
+        .ok_or_else(|| format!("Invalid time range: {line}"))?;
+    let start: TimeOfDay = start.trim().parse()?;
+    let end: TimeOfDay = end.trim().parse()?;
+    if start >= end {
+        return Err(format!("Time range ends before it starts: {line}"));
+    }
+    Ok((start, end))
 }
 
 impl CourseScheduleInformation {
-    pub(crate) fn from_iter<'a>(
-        iter: &mut impl Iterator<Item = &'a str>,
-    ) -> CourseScheduleInformation {
-        let mut iter = iter.skip_while(|s| s.is_empty());
-        // Consume empty strings at start
-        CourseScheduleInformation {
-            name: iter.next().unwrap().to_string(),
-            professor: iter.next().unwrap().to_string(),
-            time: iter.next().unwrap().to_string(),
-            classroom: iter.next().unwrap_or("").to_string(),
+    /// 시간표 셀 하나에 들어있는 강의들을 읽습니다.
+    ///
+    /// 셀은 강의마다 `강의명`, `교수`, `hh:mm-hh:mm`, `강의실` 네 줄로 구성되며, 강의 사이에는 빈 줄이 올 수 있습니다.
+    /// 강의실 줄은 비어 있을 수 있으며, 시간이 없는 온라인 강의는 강의명과 교수 두 줄만 존재합니다.
+    pub(crate) fn parse_cell(
+        cell: &str,
+        period: Option<u32>,
+    ) -> Result<Vec<CourseScheduleInformation>, String> {
+        let mut lines = cell.split('\n').map(str::trim).peekable();
+        let mut courses = Vec::new();
+        loop {
+            while lines.next_if(|line| line.is_empty()).is_some() {}
+            let Some(name) = lines.next() else {
+                break;
+            };
+            if is_time_line(name) {
+                return Err(format!("Expected lecture name but found time: {name}"));
+            }
+            let professor = lines.next().unwrap_or_default();
+            let (time, range, classroom) = match lines.next_if(|line| is_time_line(line)) {
+                Some(time) => (
+                    time,
+                    Some(parse_time_range(time)?),
+                    lines.next().unwrap_or_default(),
+                ),
+                None => ("", None, ""),
+            };
+            courses.push(CourseScheduleInformation {
+                name: name.to_string(),
+                professor: professor.to_string(),
+                time: time.to_string(),
+                start: range.map(|(start, _)| start),
+                end: range.map(|(_, end)| end),
+                classroom: classroom.to_string(),
+                periods: period.into_iter().collect(),
+            });
         }
+        Ok(courses)
+    }
+
+    fn is_same_course(&self, other: &CourseScheduleInformation) -> bool {
+        self.name == other.name
+            && self.professor == other.professor
+            && self.time == other.time
+            && self.classroom == other.classroom
     }
 
     /// 강의명을 반환합니다.
@@ -62,15 +148,35 @@ impl CourseScheduleInformation {
         &self.professor
     }
 
-    /// 강의 시간을 반환합니다. hh:mm-hh:mm 형태입니다.
+    /// 강의 시간 문자열을 반환합니다. hh:mm-hh:mm 형태이며, 시간이 없는 강의는 빈 문자열입니다.
     pub fn time(&self) -> &str {
         &self.time
     }
 
+    /// 강의 시작 시각을 반환합니다.
+    pub fn start(&self) -> Option<TimeOfDay> {
+        self.start
+    }
+
+    /// 강의 종료 시각을 반환합니다.
+    pub fn end(&self) -> Option<TimeOfDay> {
+        self.end
+    }
+
     /// 강의실을 반환합니다.
     pub fn classroom(&self) -> &str {
         &self.classroom
     }
+
+    /// 강의가 차지하는 교시 목록을 반환합니다.
+    pub fn periods(&self) -> &[u32] {
+        &self.periods
+    }
+
+    /// 시간이 정해지지 않은 강의(온라인, 사이버 강의 등)인지 여부를 반환합니다.
+    pub fn is_online(&self) -> bool {
+        self.start.is_none()
+    }
 }
 
 impl PersonalCourseSchedule {
@@ -78,8 +184,180 @@ impl PersonalCourseSchedule {
         Self { schedule }
     }
 
+    /// 시간표 테이블의 행들로 시간표를 만듭니다. 첫 행은 요일 헤더, 각 행의 첫 열은 교시입니다.
+    ///
+    /// 여러 교시에 걸친 강의는 하나로 합쳐지며, 차지하는 교시가 [`CourseScheduleInformation::periods`]에 기록됩니다.
+    pub(crate) fn from_rows(rows: Vec<Vec<Option<String>>>) -> Result<Self, String> {
+        let mut rows = rows.into_iter();
+        let weekdays: Vec<Weekday> = match rows.next() {
+            Some(header) => header
+                .into_iter()
+                .skip(1)
+                .enumerate()
+                .map(|(idx, cell)| {
+                    cell.and_then(|cell| cell.parse().ok())
+                        .or_else(|| WEEKDAYS.get(idx).copied())
+                        .ok_or_else(|| format!("Unknown weekday column: {idx}"))
+                })
+                .collect::<Result<_, _>>()?,
+            None => return Ok(Self::new(HashMap::new())),
+        };
+        let mut schedule: HashMap<Weekday, Vec<CourseScheduleInformation>> = HashMap::new();
+        for row in rows {
+            let mut cells = row.into_iter();
+            let period = cells.next().flatten().and_then(|label| {
+                let digits: String = label
+                    .trim()
+                    .chars()
+                    .take_while(char::is_ascii_digit)
+                    .collect();
+                digits.parse().ok()
+            });
+            for (idx, cell) in cells.enumerate() {
+                let Some(cell) = cell else {
+                    continue;
+                };
+                let weekday = *weekdays
+                    .get(idx)
+                    .ok_or_else(|| format!("Unknown weekday column: {idx}"))?;
+                let courses = schedule.entry(weekday).or_default();
+                for course in CourseScheduleInformation::parse_cell(&cell, period)? {
+                    match courses
+                        .iter_mut()
+                        .find(|existing| existing.is_same_course(&course))
+                    {
+                        Some(existing) => existing.periods.extend(course.periods),
+                        None => courses.push(course),
+                    }
+                }
+            }
+        }
+        Ok(Self::new(schedule))
+    }
+
     /// 시간표 배열을 반환합니다.
     pub fn schedule(&self) -> &HashMap<Weekday, Vec<CourseScheduleInformation>> {
         &self.schedule
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{CourseScheduleInformation, PersonalCourseSchedule, Weekday};
+    use crate::model::TimeOfDay;
+
+    fn cell(value: &str) -> Option<String> {
+        Some(value.to_string())
+    }
+
+    #[test]
+    fn parse_multiple_courses_in_cell() {
+        let courses = CourseScheduleInformation::parse_cell(
+            "\n컴퓨터구조\n김교수\n09:00-10:15\n정보과학관 21203\n운영체제\n이교수\n10:30-11:45\n정보과학관 21204",
+            Some(1),
+        )
+        .unwrap();
+        assert_eq!(courses.len(), 2);
+        assert_eq!(courses[0].start(), Some(TimeOfDay::new(9, 0).unwrap()));
+        assert_eq!(courses[0].end(), Some(TimeOfDay::new(10, 15).unwrap()));
+        assert_eq!(courses[0].classroom(), "정보과학관 21203");
+        assert_eq!(courses[1].name(), "운영체제");
+        assert_eq!(courses[1].periods(), &[1]);
+    }
+
+    #[test]
+    fn parse_course_without_classroom() {
+        let courses = CourseScheduleInformation::parse_cell(
+            "컴퓨터구조\n김교수\n09:00-10:15\n\n운영체제\n이교수\n10:30-11:45\n정보과학관 21204",
+            Some(1),
+        )
+        .unwrap();
+        assert_eq!(courses.len(), 2);
+        assert_eq!(courses[0].classroom(), "");
+        assert_eq!(courses[1].name(), "운영체제");
+        assert_eq!(courses[1].classroom(), "정보과학관 21204");
+    }
+
+    #[test]
+    fn parse_online_course_before_timed_course() {
+        let courses = CourseScheduleInformation::parse_cell(
+            "식품과건강(숭실사이버대)\n김교수\n운영체제\n이교수\n10:30-11:45\n정보과학관 21204",
+            None,
+        )
+        .unwrap();
+        assert_eq!(courses.len(), 2);
+        assert!(courses[0].is_online());
+        assert_eq!(courses[1].professor(), "이교수");
+        assert_eq!(courses[1].classroom(), "정보과학관 21204");
+    }
+
+    #[test]
+    fn deserialize_without_parsed_fields() {
+        let course: CourseScheduleInformation = serde_json::from_str(
+            r#"{"name":"컴퓨터구조","professor":"김교수","time":"09:00-10:15","classroom":"정보과학관 21203"}"#,
+        )
+        .unwrap();
+        assert_eq!(course.start(), None);
+        assert!(course.periods().is_empty());
+    }
+
+    #[test]
+    fn parse_online_course() {
+        let courses =
+            CourseScheduleInformation::parse_cell("식품과건강(숭실사이버대)\n김교수", None)
+                .unwrap();
+        assert_eq!(courses.len(), 1);
+        assert!(courses[0].is_online());
+        assert_eq!(courses[0].classroom(), "");
+    }
+
+    #[test]
+    fn malformed_cell() {
+        assert!(CourseScheduleInformation::parse_cell("09:00-10:15", None).is_err());
+        assert!(
+            CourseScheduleInformation::parse_cell("컴퓨터구조\n김교수\n10:15-09:00", None).is_err()
+        );
+    }
+
+    #[test]
+    fn merge_spanning_course_and_sunday() {
+        let course = "컴퓨터구조\n김교수\n09:00-10:15\n정보과학관 21203";
+        let rows = vec![
+            vec![
+                None,
+                cell("월"),
+                cell("화"),
+                cell("수"),
+                cell("목"),
+                cell("금"),
+                cell("토"),
+                cell("일"),
+            ],
+            vec![
+                cell("1교시"),
+                cell(course),
+                None,
+                None,
+                None,
+                None,
+                None,
+                cell("봉사활동\n박교수\n13:00-15:00\n학생회관"),
+            ],
+            vec![
+                cell("2교시"),
+                cell(course),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ],
+        ];
+        let schedule = PersonalCourseSchedule::from_rows(rows).unwrap();
+        let monday = &schedule.schedule()[&Weekday::Mon];
+        assert_eq!(monday.len(), 1);
+        assert_eq!(monday[0].periods(), &[1, 2]);
+        assert_eq!(schedule.schedule()[&Weekday::Sun][0].name(), "봉사활동");
+    }
+}
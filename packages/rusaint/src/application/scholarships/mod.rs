@@ -1,7 +1,16 @@
 use super::{USaintApplication, USaintClient};
-use crate::application::scholarships::model::Scholarship;
+use crate::application::scholarships::model::{
+    OpenScholarship, Scholarship, ScholarshipApplication, ScholarshipDocument,
+};
+use crate::application::utils::sap_table::{is_empty_table, try_table_into_with_scroll};
 use crate::webdynpro::element::parser::ElementParser;
-use crate::{RusaintError, webdynpro::client::body::Body};
+use crate::{
+    RusaintError, define_elements,
+    webdynpro::{
+        client::body::Body,
+        element::complex::{SapTable, SapTableDef, sap_table::FromSapTable},
+    },
+};
 
 /// [장학금수혜내역조회](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW7530n)
 pub struct ScholarshipsApplication {
@@ -20,16 +29,52 @@ impl USaintApplication for ScholarshipsApplication {
     }
 }
 
-impl ScholarshipsApplication {
+impl<'a> ScholarshipsApplication {
+    // 아래 ID는 캡처한 ZCMW7530 페이지와 대조하지 못한 추정값이며, 표의 열 구성은 단위 테스트의 픽스처로만 확인함
+    define_elements! {
+        // 신청 가능 장학금 표
+        TABLE_OPEN: SapTable<'a> = "ZCMW7530.ID_0001:VIW_MAIN.TABLE_OPEN";
+        // 장학금 신청 내역 표
+        TABLE_APPLICATIONS: SapTable<'a> = "ZCMW7530.ID_0001:VIW_MAIN.TABLE_APPLY";
+        // 제출 서류 표
+        TABLE_DOCUMENTS: SapTable<'a> = "ZCMW7530.ID_0001:VIW_MAIN.TABLE_DOCU";
+    }
+
     fn body(&self) -> &Body {
         self.client.body()
     }
 
+    async fn read_table<T: for<'body> FromSapTable<'body>>(
+        &mut self,
+        table: SapTableDef,
+    ) -> Result<Vec<T>, RusaintError> {
+        let parser = ElementParser::new(self.body());
+        if is_empty_table(&parser, table.clone())? {
+            return Ok(Vec::new());
+        }
+        Ok(try_table_into_with_scroll::<T>(&mut self.client, parser, table).await?)
+    }
+
     /// 장학금 수혜 내역을 가져옵니다.
     pub async fn scholarships(&mut self) -> Result<Vec<Scholarship>, RusaintError> {
         let parser = ElementParser::new(self.body());
         Scholarship::with_parser(&parser)
     }
+
+    /// 현재 신청 가능한 장학금 목록을 가져옵니다.
+    pub async fn open_scholarships(&mut self) -> Result<Vec<OpenScholarship>, RusaintError> {
+        self.read_table(Self::TABLE_OPEN).await
+    }
+
+    /// 학생이 신청한 장학금과 선발 상태를 가져옵니다.
+    pub async fn applications(&mut self) -> Result<Vec<ScholarshipApplication>, RusaintError> {
+        self.read_table(Self::TABLE_APPLICATIONS).await
+    }
+
+    /// 장학금 신청에 필요한 제출 서류 목록을 가져옵니다.
+    pub async fn required_documents(&mut self) -> Result<Vec<ScholarshipDocument>, RusaintError> {
+        self.read_table(Self::TABLE_DOCUMENTS).await
+    }
 }
 
 /// [`ScholarshipsApplication`] 애플리케이션에 사용되는 데이터
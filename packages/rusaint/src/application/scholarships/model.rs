@@ -7,24 +7,17 @@ use serde::{
     de::{IntoDeserializer, value::MapDeserializer},
 };
 
-use crate::application::utils::de_with::deserialize_u32_string;
+use crate::application::utils::de_with::{deserialize_optional_string, deserialize_u32_string};
+use crate::application::utils::sap_table::is_empty_table;
 use crate::webdynpro::command::WebDynproCommandExecutor;
 use crate::webdynpro::element::parser::ElementParser;
 use crate::{
     RusaintError, define_elements,
-    error::ApplicationError,
     model::SemesterType,
     webdynpro::{
         command::element::complex::SapTableBodyCommand,
         element::{
-            ElementDefWrapper,
-            complex::{
-                SapTable,
-                sap_table::{
-                    FromSapTable,
-                    cell::{SapTableCell, SapTableCellWrapper},
-                },
-            },
+            complex::{SapTable, sap_table::FromSapTable},
             definition::ElementDefinition,
         },
         error::{ElementError, WebDynproError},
@@ -88,19 +81,10 @@ impl Scholarship {
         define_elements! {
             TABLE: SapTable<'_> = "ZCMW7530.ID_0001:VIW_MAIN.TABLE_2";
         }
-        let table = parser.read(SapTableBodyCommand::new(TABLE))?;
-        let Some(first_row) = table.iter().next() else {
-            return Err(ApplicationError::NoChapelInformation.into());
-        };
-        if let Some(Ok(SapTableCellWrapper::Normal(cell))) = first_row.iter_value(parser).next() {
-            if let Some(ElementDefWrapper::TextView(tv_def)) = cell.content() {
-                if let Ok(tv) = parser.element_from_def(&tv_def) {
-                    if tv.text().contains("없습니다.") {
-                        return Err(ApplicationError::NoChapelInformation.into());
-                    }
-                }
-            }
+        if is_empty_table(parser, TABLE)? {
+            return Ok(Vec::new());
         }
+        let table = parser.read(SapTableBodyCommand::new(TABLE))?;
         Ok(table.try_table_into::<Self>(parser)?)
     }
 
@@ -191,3 +175,425 @@ impl<'body> FromSapTable<'body> for Scholarship {
         )
     }
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 현재 신청 가능한 장학금 정보
+pub struct OpenScholarship {
+    #[serde(rename(deserialize = "장학금명"))]
+    name: String,
+    #[serde(
+        rename(deserialize = "장학구분"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    category: Option<String>,
+    #[serde(rename(deserialize = "신청시작일"))]
+    apply_start: String,
+    #[serde(rename(deserialize = "신청종료일"))]
+    apply_end: String,
+    #[serde(
+        rename(deserialize = "선발인원"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    quota: Option<String>,
+    #[serde(
+        rename(deserialize = "비고"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    note: Option<String>,
+}
+
+impl OpenScholarship {
+    /// 장학금명을 반환합니다.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 장학구분을 반환합니다.
+    pub fn category(&self) -> Option<&str> {
+        self.category.as_deref()
+    }
+
+    /// 신청 시작일을 반환합니다.
+    pub fn apply_start(&self) -> &str {
+        &self.apply_start
+    }
+
+    /// 신청 종료일을 반환합니다.
+    pub fn apply_end(&self) -> &str {
+        &self.apply_end
+    }
+
+    /// 선발인원을 반환합니다.
+    pub fn quota(&self) -> Option<&str> {
+        self.quota.as_deref()
+    }
+
+    /// 비고를 반환합니다.
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+}
+
+impl<'body> FromSapTable<'body> for OpenScholarship {
+    fn from_table(
+        header: &'body crate::webdynpro::element::complex::sap_table::SapTableHeader,
+        row: &'body crate::webdynpro::element::complex::sap_table::SapTableRow,
+        parser: &'body ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let map_string = row.try_row_into::<HashMap<String, String>>(header, parser)?;
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map_string.into_deserializer();
+        Ok(
+            Self::deserialize(map_de).map_err(|e| ElementError::InvalidContent {
+                element: row.table_def().id().to_string(),
+                content: e.to_string(),
+            })?,
+        )
+    }
+}
+
+/// 장학금 신청의 선발 상태
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ScholarshipSelectionState {
+    /// 신청 후 심사 중
+    Applied,
+    /// 선발됨
+    Selected,
+    /// 선발되지 않음
+    NotSelected,
+    /// 신청 취소됨
+    Cancelled,
+    /// 알 수 없는 상태
+    Unknown,
+}
+
+impl ScholarshipSelectionState {
+    pub(crate) fn from_text(text: &str) -> Self {
+        if text.contains("취소") || text.contains("포기") {
+            Self::Cancelled
+        } else if text.contains("탈락") || text.contains("미선발") || text.contains("불합격")
+        {
+            Self::NotSelected
+        } else if text.contains("선발") || text.contains("합격") || text.contains("확정") {
+            Self::Selected
+        } else if text.contains("신청") || text.contains("심사") || text.contains("접수") {
+            Self::Applied
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 학생이 신청한 장학금 정보
+pub struct ScholarshipApplication {
+    #[serde(
+        rename(deserialize = "학년도"),
+        deserialize_with = "deserialize_u32_string"
+    )]
+    year: u32,
+    #[serde(
+        rename(deserialize = "학기"),
+        deserialize_with = "deserialize_semester_type"
+    )]
+    semester: SemesterType,
+    #[serde(rename(deserialize = "장학금명"))]
+    name: String,
+    #[serde(rename(deserialize = "신청일자"))]
+    applied_at: String,
+    #[serde(rename(deserialize = "선발상태"))]
+    state_text: String,
+    #[serde(
+        rename(deserialize = "비고"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    note: Option<String>,
+}
+
+impl ScholarshipApplication {
+    /// 신청 학년도를 반환합니다.
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 신청 학기를 반환합니다.
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 장학금명을 반환합니다.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 신청일자를 반환합니다.
+    pub fn applied_at(&self) -> &str {
+        &self.applied_at
+    }
+
+    /// 선발상태 문자열을 반환합니다.
+    pub fn state_text(&self) -> &str {
+        &self.state_text
+    }
+
+    /// 선발상태를 반환합니다.
+    pub fn state(&self) -> ScholarshipSelectionState {
+        ScholarshipSelectionState::from_text(&self.state_text)
+    }
+
+    /// 비고를 반환합니다.
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+}
+
+impl<'body> FromSapTable<'body> for ScholarshipApplication {
+    fn from_table(
+        header: &'body crate::webdynpro::element::complex::sap_table::SapTableHeader,
+        row: &'body crate::webdynpro::element::complex::sap_table::SapTableRow,
+        parser: &'body ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let map_string = row.try_row_into::<HashMap<String, String>>(header, parser)?;
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map_string.into_deserializer();
+        Ok(
+            Self::deserialize(map_de).map_err(|e| ElementError::InvalidContent {
+                element: row.table_def().id().to_string(),
+                content: e.to_string(),
+            })?,
+        )
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 장학금 신청에 필요한 제출 서류
+pub struct ScholarshipDocument {
+    #[serde(rename(deserialize = "장학금명"))]
+    scholarship: String,
+    #[serde(rename(deserialize = "서류명"))]
+    name: String,
+    #[serde(
+        rename(deserialize = "제출여부"),
+        default,
+        deserialize_with = "deserialize_submitted"
+    )]
+    submitted: bool,
+    #[serde(
+        rename(deserialize = "비고"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    note: Option<String>,
+}
+
+impl ScholarshipDocument {
+    /// 서류가 필요한 장학금명을 반환합니다.
+    pub fn scholarship(&self) -> &str {
+        &self.scholarship
+    }
+
+    /// 서류명을 반환합니다.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 서류를 제출했는지 여부를 반환합니다.
+    pub fn submitted(&self) -> bool {
+        self.submitted
+    }
+
+    /// 비고를 반환합니다.
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+}
+
+impl<'body> FromSapTable<'body> for ScholarshipDocument {
+    fn from_table(
+        header: &'body crate::webdynpro::element::complex::sap_table::SapTableHeader,
+        row: &'body crate::webdynpro::element::complex::sap_table::SapTableRow,
+        parser: &'body ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let map_string = row.try_row_into::<HashMap<String, String>>(header, parser)?;
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map_string.into_deserializer();
+        Ok(
+            Self::deserialize(map_de).map_err(|e| ElementError::InvalidContent {
+                element: row.table_def().id().to_string(),
+                content: e.to_string(),
+            })?,
+        )
+    }
+}
+
+fn deserialize_submitted<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<bool, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    Ok(matches!(
+        value.trim(),
+        "Y" | "O" | "제출" | "제출완료" | "확인" | "true"
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        OpenScholarship, Scholarship, ScholarshipApplication, ScholarshipDocument,
+        ScholarshipSelectionState,
+    };
+    use crate::{
+        model::SemesterType,
+        webdynpro::{
+            client::body::{body_fixture, sap_table_fixture},
+            command::{WebDynproCommandExecutor, element::complex::SapTableBodyCommand},
+            element::{
+                complex::{SapTableDef, sap_table::FromSapTable},
+                definition::ElementDefinition,
+                parser::ElementParser,
+            },
+        },
+    };
+
+    const TABLE: SapTableDef = SapTableDef::new("ZCMW7530.ID_0001:VIW_MAIN.TABLE_OPEN");
+
+    fn parse_table<T: for<'body> FromSapTable<'body>>(
+        headers: &[&str],
+        rows: &[&[&str]],
+    ) -> Vec<T> {
+        let body = body_fixture(&sap_table_fixture(TABLE.id(), headers, rows));
+        let parser = ElementParser::new(&body);
+        let table = parser.read(SapTableBodyCommand::new(TABLE)).unwrap();
+        table.try_table_into::<T>(&parser).unwrap()
+    }
+
+    #[test]
+    fn read_received_scholarships() {
+        const TABLE_2: &str = "ZCMW7530.ID_0001:VIW_MAIN.TABLE_2";
+        let headers = [
+            "학년",
+            "학기",
+            "장학금명",
+            "실수혜금액",
+            "지급방법",
+            "처리상태",
+            "처리일자",
+            "선발금액",
+            "환수금액",
+            "교체금액",
+            "교체장학금명",
+            "탈락사유",
+            "비고",
+            "근로부서",
+        ];
+        let body = body_fixture(&sap_table_fixture(
+            TABLE_2,
+            &headers,
+            &[&[
+                "2025",
+                "1 학기",
+                "성적우수장학금",
+                "1,500,000",
+                "등록금감면",
+                "지급완료",
+                "2025.02.20",
+                "1,500,000",
+                "0",
+                "0",
+                "",
+                "",
+                "",
+                "",
+            ]],
+        ));
+        let scholarships = Scholarship::with_parser(&ElementParser::new(&body)).unwrap();
+        assert_eq!(scholarships.len(), 1);
+        assert_eq!(scholarships[0].year(), 2025);
+        assert_eq!(scholarships[0].semester(), SemesterType::One);
+        assert_eq!(scholarships[0].received_amount(), 1_500_000);
+
+        let body = body_fixture(&sap_table_fixture(
+            TABLE_2,
+            &headers,
+            &[&["장학금 수혜 내역이 없습니다."]],
+        ));
+        assert!(
+            Scholarship::with_parser(&ElementParser::new(&body))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn read_scholarship_tables() {
+        let open = parse_table::<OpenScholarship>(
+            &[
+                "장학금명",
+                "장학구분",
+                "신청시작일",
+                "신청종료일",
+                "선발인원",
+                "비고",
+            ],
+            &[&["교내근로장학금", "교내", "2025.03.01", "2025.03.10", "", ""]],
+        );
+        assert_eq!(open[0].name(), "교내근로장학금");
+        assert_eq!(open[0].category(), Some("교내"));
+        assert_eq!(open[0].apply_end(), "2025.03.10");
+        assert_eq!(open[0].quota(), None);
+
+        let applications = parse_table::<ScholarshipApplication>(
+            &["학년도", "학기", "장학금명", "신청일자", "선발상태", "비고"],
+            &[&[
+                "2025",
+                "2 학기",
+                "교내근로장학금",
+                "2025.08.20",
+                "미선발",
+                "",
+            ]],
+        );
+        assert_eq!(applications[0].year(), 2025);
+        assert_eq!(applications[0].semester(), SemesterType::Two);
+        assert_eq!(
+            applications[0].state(),
+            ScholarshipSelectionState::NotSelected
+        );
+
+        let documents = parse_table::<ScholarshipDocument>(
+            &["장학금명", "서류명", "제출여부", "비고"],
+            &[
+                &["교내근로장학금", "통장사본", "Y", ""],
+                &["교내근로장학금", "소득분위 확인서", "N", "원본 제출"],
+            ],
+        );
+        assert!(documents[0].submitted());
+        assert!(!documents[1].submitted());
+        assert_eq!(documents[1].note(), Some("원본 제출"));
+    }
+
+    #[test]
+    fn classify_selection_state() {
+        assert_eq!(
+            ScholarshipSelectionState::from_text("선발"),
+            ScholarshipSelectionState::Selected
+        );
+        assert_eq!(
+            ScholarshipSelectionState::from_text("미선발"),
+            ScholarshipSelectionState::NotSelected
+        );
+        assert_eq!(
+            ScholarshipSelectionState::from_text("신청취소"),
+            ScholarshipSelectionState::Cancelled
+        );
+        assert_eq!(
+            ScholarshipSelectionState::from_text("심사중"),
+            ScholarshipSelectionState::Applied
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use self::model::{TuitionBill, TuitionPayment};
+use super::{USaintApplication, USaintClient};
+use crate::application::utils::sap_table::{is_empty_table, try_table_into_with_scroll};
+use crate::application::utils::semester::get_selected_semester;
+use crate::webdynpro::command::WebDynproCommandExecutor;
+use crate::webdynpro::element::parser::ElementParser;
+use crate::{
+    RusaintError, define_elements,
+    model::SemesterType,
+    webdynpro::{
+        client::body::Body,
+        command::element::{
+            action::ButtonPressEventCommand,
+            selection::{ComboBoxLSDataCommand, ComboBoxSelectEventCommand},
+        },
+        element::{action::Button, complex::SapTable, selection::ComboBox},
+    },
+};
+
+/// [등록금고지서조회](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW7710)
+#[derive(Debug)]
+pub struct TuitionApplication {
+    client: USaintClient,
+}
+
+impl USaintApplication for TuitionApplication {
+    const APP_NAME: &'static str = "ZCMW7710";
+
+    fn from_client(client: USaintClient) -> Result<Self, RusaintError> {
+        if client.name() != Self::APP_NAME {
+            Err(RusaintError::InvalidClientError)
+        } else {
+            Ok(Self { client })
+        }
+    }
+}
+
+impl<'a> TuitionApplication {
+    define_elements! {
+        SEL_PERYR: ComboBox<'a> = "ZCMW7710.ID_0001:VIW_MAIN.PERYR";
+        SEL_PERID: ComboBox<'a> = "ZCMW7710.ID_0001:VIW_MAIN.PERID";
+        BTN_SEL: Button<'a> = "ZCMW7710.ID_0001:VIW_MAIN.BTN_SEL";
+        PAYMENT_TABLE: SapTable<'a> = "ZCMW7710.ID_0001:VIW_MAIN.TABLE_PAY";
+    }
+
+    fn semester_to_key(semester: SemesterType) -> &'static str {
+        match semester {
+            SemesterType::One => "090",
+            SemesterType::Summer => "091",
+            SemesterType::Two => "092",
+            SemesterType::Winter => "093",
+        }
+    }
+
+    fn body(&self) -> &Body {
+        self.client.body()
+    }
+
+    async fn select_semester(
+        &mut self,
+        year: &str,
+        semester: SemesterType,
+    ) -> Result<(), RusaintError> {
+        let semester = Self::semester_to_key(semester);
+        let parser = ElementParser::new(self.body());
+        let year_combobox_lsdata = parser.read(ComboBoxLSDataCommand::new(Self::SEL_PERYR))?;
+        let semester_combobox_lsdata = parser.read(ComboBoxLSDataCommand::new(Self::SEL_PERID))?;
+        if year_combobox_lsdata.key().map(String::as_str) != Some(year) {
+            let year_select_event = parser.read(ComboBoxSelectEventCommand::new(
+                Self::SEL_PERYR,
+                year,
+                false,
+            ))?;
+            self.client.process_event(false, year_select_event).await?;
+        }
+        if semester_combobox_lsdata.key().map(String::as_str) != Some(semester) {
+            let semester_select_event = parser.read(ComboBoxSelectEventCommand::new(
+                Self::SEL_PERID,
+                semester,
+                false,
+            ))?;
+            self.client
+                .process_event(false, semester_select_event)
+                .await?;
+        }
+        let button_press_event = parser.read(ButtonPressEventCommand::new(Self::BTN_SEL))?;
+        self.client.process_event(false, button_press_event).await?;
+        Ok(())
+    }
+
+    /// 해당 학기의 등록금 고지 정보를 가져옵니다.
+    pub async fn bill(
+        &mut self,
+        year: u32,
+        semester: SemesterType,
+    ) -> Result<TuitionBill, RusaintError> {
+        self.select_semester(&year.to_string(), semester).await?;
+        let parser = ElementParser::new(self.body());
+        TuitionBill::with_parser(&parser, year, semester)
+    }
+
+    /// 등록금 납부 내역을 가져옵니다.
+    pub async fn payments(&mut self) -> Result<Vec<TuitionPayment>, RusaintError> {
+        let parser = ElementParser::new(self.body());
+        if is_empty_table(&parser, Self::PAYMENT_TABLE)? {
+            return Ok(Vec::new());
+        }
+        Ok(try_table_into_with_scroll::<TuitionPayment>(
+            &mut self.client,
+            parser,
+            Self::PAYMENT_TABLE,
+        )
+        .await?)
+    }
+
+    /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
+    /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
+    pub fn get_selected_semester(&self) -> Result<(u32, SemesterType), RusaintError> {
+        Ok(get_selected_semester(
+            &self.client,
+            &Self::SEL_PERYR,
+            &Self::SEL_PERID,
+        )?)
+    }
+}
+
+/// [`TuitionApplication`] 애플리케이션에 사용되는 데이터
+pub mod model;
@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+
+use serde::{
+    Deserialize, Serialize,
+    de::{IntoDeserializer, value::MapDeserializer},
+};
+
+use crate::application::utils::de_with::{
+    deserialize_comma_u64_string, deserialize_optional_string, deserialize_semester_type,
+    deserialize_u32_string,
+};
+use crate::webdynpro::command::WebDynproCommandExecutor;
+use crate::webdynpro::element::parser::ElementParser;
+use crate::{
+    RusaintError, define_elements,
+    error::ApplicationError,
+    model::SemesterType,
+    webdynpro::{
+        command::element::text::InputFieldValueCommand,
+        element::{
+            complex::sap_table::{FromSapTable, SapTableHeader, SapTableRow},
+            definition::ElementDefinition,
+            text::{InputField, InputFieldDef},
+        },
+        error::{ElementError, WebDynproError},
+    },
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 학기별 등록금 고지 정보
+pub struct TuitionBill {
+    year: u32,
+    semester: SemesterType,
+    entrance_fee: u64,
+    tuition_fee: u64,
+    scholarship_deduction: u64,
+    amount_due: u64,
+    bank: Option<String>,
+    virtual_account: Option<String>,
+    due_start: Option<String>,
+    due_end: Option<String>,
+}
+
+impl<'a> TuitionBill {
+    define_elements! {
+        // 입학금
+        ENTRANCE_FEE: InputField<'a> = "ZCMW7710.ID_0001:VIW_MAIN.ADM_FEE";
+        // 수업료
+        TUITION_FEE: InputField<'a> = "ZCMW7710.ID_0001:VIW_MAIN.TUIT_FEE";
+        // 장학감면액
+        SCHOLARSHIP_DEDUCTION: InputField<'a> = "ZCMW7710.ID_0001:VIW_MAIN.SCHO_AMT";
+        // 납부할 금액
+        AMOUNT_DUE: InputField<'a> = "ZCMW7710.ID_0001:VIW_MAIN.PAY_AMT";
+        // 가상계좌 은행
+        BANK: InputField<'a> = "ZCMW7710.ID_0001:VIW_MAIN.BANK_NAME";
+        // 가상계좌번호
+        VIRTUAL_ACCOUNT: InputField<'a> = "ZCMW7710.ID_0001:VIW_MAIN.VACCOUNT";
+        // 납부기간 시작일
+        DUE_START: InputField<'a> = "ZCMW7710.ID_0001:VIW_MAIN.PAY_BEGDA";
+        // 납부기간 종료일
+        DUE_END: InputField<'a> = "ZCMW7710.ID_0001:VIW_MAIN.PAY_ENDDA";
+    }
+
+    pub(crate) fn with_parser(
+        parser: &ElementParser,
+        year: u32,
+        semester: SemesterType,
+    ) -> Result<Self, RusaintError> {
+        let tuition_fee = parser
+            .element_from_def(&Self::TUITION_FEE)
+            .and_then(|field| field.value_into_comma_u64())
+            .map_err(|_| ApplicationError::NoTuitionBill)?;
+        let amount = |def: &InputFieldDef| -> Result<u64, WebDynproError> {
+            let field = parser.element_from_def(def)?;
+            if field.value().is_none_or(|value| value.trim().is_empty()) {
+                Ok(0)
+            } else {
+                field.value_into_comma_u64()
+            }
+        };
+        let text = |def: InputFieldDef| {
+            parser
+                .read(InputFieldValueCommand::new(def))
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        Ok(Self {
+            year,
+            semester,
+            entrance_fee: amount(&Self::ENTRANCE_FEE)?,
+            tuition_fee,
+            scholarship_deduction: amount(&Self::SCHOLARSHIP_DEDUCTION)?,
+            amount_due: amount(&Self::AMOUNT_DUE)?,
+            bank: text(Self::BANK),
+            virtual_account: text(Self::VIRTUAL_ACCOUNT),
+            due_start: text(Self::DUE_START),
+            due_end: text(Self::DUE_END),
+        })
+    }
+
+    /// 고지 학년도를 반환합니다.
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 고지 학기를 반환합니다.
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 입학금을 반환합니다.
+    pub fn entrance_fee(&self) -> u64 {
+        self.entrance_fee
+    }
+
+    /// 수업료를 반환합니다.
+    pub fn tuition_fee(&self) -> u64 {
+        self.tuition_fee
+    }
+
+    /// 장학금으로 감면된 금액을 반환합니다.
+    pub fn scholarship_deduction(&self) -> u64 {
+        self.scholarship_deduction
+    }
+
+    /// 납부할 금액을 반환합니다.
+    pub fn amount_due(&self) -> u64 {
+        self.amount_due
+    }
+
+    /// 가상계좌 은행을 반환합니다.
+    pub fn bank(&self) -> Option<&str> {
+        self.bank.as_deref()
+    }
+
+    /// 가상계좌번호를 반환합니다.
+    pub fn virtual_account(&self) -> Option<&str> {
+        self.virtual_account.as_deref()
+    }
+
+    /// 납부기간 시작일을 반환합니다.
+    pub fn due_start(&self) -> Option<&str> {
+        self.due_start.as_deref()
+    }
+
+    /// 납부기간 종료일을 반환합니다.
+    pub fn due_end(&self) -> Option<&str> {
+        self.due_end.as_deref()
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+/// 등록금 납부 내역
+pub struct TuitionPayment {
+    #[serde(
+        rename(deserialize = "학년도"),
+        deserialize_with = "deserialize_u32_string"
+    )]
+    year: u32,
+    #[serde(
+        rename(deserialize = "학기"),
+        deserialize_with = "deserialize_semester_type"
+    )]
+    semester: SemesterType,
+    #[serde(rename(deserialize = "납부일자"))]
+    paid_at: String,
+    #[serde(
+        rename(deserialize = "납부금액"),
+        deserialize_with = "deserialize_comma_u64_string"
+    )]
+    amount: u64,
+    #[serde(rename(deserialize = "납부구분"))]
+    payment_type: String,
+    #[serde(
+        rename(deserialize = "수납은행"),
+        default,
+        deserialize_with = "deserialize_optional_string"
+    )]
+    bank: Option<String>,
+}
+
+impl TuitionPayment {
+    /// 납부 학년도를 반환합니다.
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 납부 학기를 반환합니다.
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 납부일자를 반환합니다.
+    pub fn paid_at(&self) -> &str {
+        &self.paid_at
+    }
+
+    /// 납부금액을 반환합니다.
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// 납부구분을 반환합니다.
+    pub fn payment_type(&self) -> &str {
+        &self.payment_type
+    }
+
+    /// 수납은행을 반환합니다.
+    pub fn bank(&self) -> Option<&str> {
+        self.bank.as_deref()
+    }
+}
+
+impl<'body> FromSapTable<'body> for TuitionPayment {
+    fn from_table(
+        header: &'body SapTableHeader,
+        row: &'body SapTableRow,
+        parser: &'body ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let map_string = row.try_row_into::<HashMap<String, String>>(header, parser)?;
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map_string.into_deserializer();
+        Ok(
+            Self::deserialize(map_de).map_err(|e| ElementError::InvalidContent {
+                element: row.table_def().id().to_string(),
+                content: e.to_string(),
+            })?,
+        )
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Deserializer};
 
-use crate::model::SemesterType;
+use crate::model::{Date, SemesterType};
 
 pub(crate) fn deserialize_u32_string<'de, D: Deserializer<'de>>(
     deserializer: D,
@@ -53,15 +53,34 @@ pub(crate) fn deserialize_semester_type<'de, D: Deserializer<'de>>(
     deserializer: D,
 ) -> Result<SemesterType, D::Error> {
     let value = String::deserialize(deserializer)?;
+    parse_semester_type(&value)
+        .ok_or_else(|| serde::de::Error::custom("Unknown SemesterType variant"))
+}
+
+fn parse_semester_type(value: &str) -> Option<SemesterType> {
     match value.trim() {
-        "1 학기" | "1학기" => Ok(SemesterType::One),
-        "여름학기" | "여름 학기" => Ok(SemesterType::Summer),
-        "2 학기" | "2학기" => Ok(SemesterType::Two),
-        "겨울학기" | "겨울 학기" => Ok(SemesterType::Winter),
-        _ => Err(serde::de::Error::custom("Unknown SemesterType variant")),
+        "1 학기" | "1학기" => Some(SemesterType::One),
+        "여름학기" | "여름 학기" => Some(SemesterType::Summer),
+        "2 학기" | "2학기" => Some(SemesterType::Two),
+        "겨울학기" | "겨울 학기" => Some(SemesterType::Winter),
+        _ => None,
     }
 }
 
+pub(crate) fn deserialize_optional_semester_type<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<SemesterType>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    Ok(parse_semester_type(&value))
+}
+
+pub(crate) fn deserialize_date<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Date, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    value.parse().map_err(serde::de::Error::custom)
+}
+
 pub(crate) fn deserialize_comma_u64_string<'de, D: Deserializer<'de>>(
     deserializer: D,
 ) -> Result<u64, D::Error> {
@@ -34,4 +34,18 @@ impl InputField<'_> {
             .into()
         })
     }
+
+    pub(crate) fn value_into_comma_u64(&self) -> Result<u64, WebDynproError> {
+        self.value_string()?
+            .replace(",", "")
+            .trim()
+            .parse::<u64>()
+            .map_err(|_| {
+                ElementError::InvalidContent {
+                    element: self.id().to_owned(),
+                    content: "value is not correct u64".to_string(),
+                }
+                .into()
+            })
+    }
 }
@@ -1,4 +1,6 @@
 pub(crate) mod de_with;
 pub(crate) mod input_field;
+pub(crate) mod poller;
+pub(crate) mod popup;
 pub(crate) mod sap_table;
 pub(crate) mod semester;
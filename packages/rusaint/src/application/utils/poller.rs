@@ -0,0 +1,146 @@
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use crate::RusaintError;
+
+/// 주기적으로 애플리케이션의 상태를 다시 읽어 이전 상태와 비교하는 감시 대상
+pub(crate) trait PollTarget {
+    /// 상태를 읽는 데 사용하는 애플리케이션
+    type App;
+    /// 상태 변경 이벤트
+    type Event;
+
+    /// 기본 확인 주기
+    const DEFAULT_INTERVAL: Duration;
+    /// 허용하는 가장 짧은 확인 주기
+    const MINIMUM_INTERVAL: Duration;
+
+    /// 상태를 한 번 다시 읽고, 이전 상태와 비교하여 변경 이벤트를 반환합니다.
+    async fn poll(&mut self, app: &mut Self::App) -> Result<Vec<Self::Event>, RusaintError>;
+}
+
+/// [`PollTarget`]을 주기적으로 확인하는 감시자들의 공통 구현
+#[derive(Debug)]
+pub(crate) struct Poller<T: PollTarget> {
+    app: T::App,
+    target: T,
+    interval: Duration,
+    emit_initial: bool,
+    initialized: bool,
+    last_polled: Option<Instant>,
+}
+
+impl<T: PollTarget> Poller<T> {
+    pub(crate) fn new(app: T::App, target: T, interval: Duration, emit_initial: bool) -> Self {
+        Poller {
+            app,
+            target,
+            interval: interval.max(T::MINIMUM_INTERVAL),
+            emit_initial,
+            initialized: false,
+            last_polled: None,
+        }
+    }
+
+    /// 상태를 한 번 확인합니다. 마지막 확인으로부터 최소 주기가 지나지 않았다면 지날 때까지 기다립니다.
+    ///
+    /// 처음 호출될 때에는 `emit_initial`이 설정되지 않았다면 기준 상태만 기록하고 빈 목록을 반환합니다.
+    pub(crate) async fn poll(&mut self) -> Result<Vec<T::Event>, RusaintError> {
+        if let Some(last_polled) = self.last_polled {
+            tokio::time::sleep_until(last_polled + T::MINIMUM_INTERVAL).await;
+        }
+        self.last_polled = Some(Instant::now());
+        let events = self.target.poll(&mut self.app).await?;
+        if !self.initialized {
+            self.initialized = true;
+            if !self.emit_initial {
+                return Ok(Vec::new());
+            }
+        }
+        Ok(events)
+    }
+
+    /// 변경 사항이 생길 때까지 설정된 주기마다 상태를 확인하고, 변경 사항을 반환합니다.
+    pub(crate) async fn next(&mut self) -> Result<Vec<T::Event>, RusaintError> {
+        if !self.initialized {
+            let events = self.poll().await?;
+            if !events.is_empty() {
+                return Ok(events);
+            }
+        }
+        loop {
+            if let Some(last_polled) = self.last_polled {
+                tokio::time::sleep_until(last_polled + self.interval).await;
+            }
+            let events = self.poll().await?;
+            if !events.is_empty() {
+                return Ok(events);
+            }
+        }
+    }
+
+    /// 기준 상태를 기록했는지 여부를 반환합니다.
+    pub(crate) fn initialized(&self) -> bool {
+        self.initialized
+    }
+
+    pub(crate) fn target(&self) -> &T {
+        &self.target
+    }
+
+    pub(crate) fn app_mut(&mut self) -> &mut T::App {
+        &mut self.app
+    }
+
+    pub(crate) fn into_inner(self) -> T::App {
+        self.app
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use tokio::time::Instant;
+
+    use super::{PollTarget, Poller};
+    use crate::RusaintError;
+
+    #[derive(Debug)]
+    struct Counter;
+
+    impl PollTarget for Counter {
+        type App = Vec<Instant>;
+        type Event = u32;
+
+        const DEFAULT_INTERVAL: Duration = Duration::from_secs(60);
+        const MINIMUM_INTERVAL: Duration = Duration::from_secs(15);
+
+        async fn poll(&mut self, app: &mut Self::App) -> Result<Vec<u32>, RusaintError> {
+            app.push(Instant::now());
+            Ok(vec![app.len() as u32])
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn poll_waits_for_minimum_interval() {
+        let mut poller = Poller::new(Vec::new(), Counter, Duration::from_secs(1), false);
+        assert!(poller.poll().await.unwrap().is_empty());
+        assert!(poller.initialized());
+        assert_eq!(poller.poll().await.unwrap(), vec![2]);
+        assert_eq!(poller.poll().await.unwrap(), vec![3]);
+        let polled = poller.into_inner();
+        assert!(polled[1] - polled[0] >= Counter::MINIMUM_INTERVAL);
+        assert!(polled[2] - polled[1] >= Counter::MINIMUM_INTERVAL);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn next_emits_initial_state_when_requested() {
+        let mut poller = Poller::new(Vec::new(), Counter, Counter::DEFAULT_INTERVAL, true);
+        assert_eq!(poller.next().await.unwrap(), vec![1]);
+        assert_eq!(poller.next().await.unwrap(), vec![2]);
+        let polled = poller.into_inner();
+        assert!(polled[1] - polled[0] >= Counter::DEFAULT_INTERVAL);
+    }
+}
@@ -0,0 +1,310 @@
+use scraper::{ElementRef, Selector};
+
+use crate::application::USaintClient;
+use crate::webdynpro::client::body::Body;
+use crate::webdynpro::element::action::Button;
+use crate::webdynpro::element::complex::SapTable;
+use crate::webdynpro::element::graphic::Image;
+use crate::webdynpro::element::layout::PopupWindow;
+use crate::webdynpro::element::parser::ElementParser;
+use crate::webdynpro::element::text::{Label, TextView};
+use crate::webdynpro::element::{Element, ElementWrapper};
+use crate::webdynpro::error::WebDynproError;
+use crate::webdynpro::event::Event;
+
+fn popup_selector() -> Selector {
+    Selector::parse(format!(r#"[ct="{}"]"#, PopupWindow::CONTROL_ID).as_str()).unwrap()
+}
+
+/// 현재 열려 있는 팝업 창이 있는지 확인합니다.
+pub(crate) fn has_popup(body: &Body) -> bool {
+    let parser = ElementParser::new(body);
+    parser.document().select(&popup_selector()).next().is_some()
+}
+
+/// 열려 있는 모든 팝업 창을 닫습니다.
+pub(crate) async fn close_popups(client: &mut USaintClient) -> Result<(), WebDynproError> {
+    fn make_close_event(body: &Body) -> Option<Event> {
+        let parser = ElementParser::new(body);
+        let selector = popup_selector();
+        let mut popup_iter = parser.document().select(&selector);
+        popup_iter.next().and_then(|elem| {
+            let elem_wrapped = ElementWrapper::from_ref(elem).ok()?;
+            if let ElementWrapper::PopupWindow(popup) = elem_wrapped {
+                popup.close().ok()
+            } else {
+                None
+            }
+        })
+    }
+    while let Some(event) = make_close_event(client.body()) {
+        client.process_event(false, event).await?;
+    }
+    Ok(())
+}
+
+/// 열려 있는 팝업 창에서 `labels` 중 하나를 텍스트로 가진 버튼을 누릅니다. 버튼을 눌렀다면 `true`를 반환합니다.
+pub(crate) async fn press_popup_button(
+    client: &mut USaintClient,
+    labels: &[&str],
+) -> Result<bool, WebDynproError> {
+    let event = {
+        let parser = ElementParser::new(client.body());
+        let button_selector = Selector::parse(&format!(
+            r#"[ct="{}"] [ct="{}"]"#,
+            PopupWindow::CONTROL_ID,
+            Button::CONTROL_ID
+        ))
+        .unwrap();
+        parser
+            .document()
+            .select(&button_selector)
+            .filter_map(|button_ref| {
+                let ElementWrapper::Button(button) = ElementWrapper::from_ref(button_ref).ok()?
+                else {
+                    return None;
+                };
+                if !labels.contains(&button.lsdata().text()?.trim()) {
+                    return None;
+                }
+                button.press().ok()
+            })
+            .next()
+    };
+    match event {
+        Some(event) => {
+            client.process_event(false, event).await?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// 팝업 창 안에 표시된 메시지 목록을 반환합니다.
+pub(crate) fn popup_messages(parser: &ElementParser) -> Vec<String> {
+    let text_selector = Selector::parse(&format!(
+        r#"[ct="{}"] [ct="{}"]"#,
+        PopupWindow::CONTROL_ID,
+        TextView::CONTROL_ID
+    ))
+    .unwrap();
+    parser
+        .document()
+        .select(&text_selector)
+        .map(element_text)
+        .filter(|text| !text.is_empty())
+        .collect()
+}
+
+/// 팝업 창에 표시된 메시지의 유형
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum PopupMessageType {
+    /// 오류
+    Error,
+    /// 경고
+    Warning,
+    /// 성공
+    Success,
+    /// 정보
+    Information,
+    /// 확인 질문
+    Question,
+}
+
+impl PopupMessageType {
+    // 메시지 아이콘의 이미지 경로 또는 클래스 이름에 포함된 유형 표시를 읽습니다.
+    fn from_marker(marker: &str) -> Option<PopupMessageType> {
+        let marker = marker.to_ascii_lowercase();
+        if marker.contains("error") || marker.contains("stop") {
+            Some(PopupMessageType::Error)
+        } else if marker.contains("warning") {
+            Some(PopupMessageType::Warning)
+        } else if marker.contains("question") {
+            Some(PopupMessageType::Question)
+        } else if marker.contains("success") || marker.contains("okay") {
+            Some(PopupMessageType::Success)
+        } else if marker.contains("info") {
+            Some(PopupMessageType::Information)
+        } else {
+            None
+        }
+    }
+}
+
+/// 팝업 창에 표시된 메시지 아이콘으로부터 메시지의 유형을 반환합니다.
+pub(crate) fn popup_message_type(parser: &ElementParser) -> Option<PopupMessageType> {
+    let image_selector = Selector::parse(&format!(
+        r#"[ct="{}"] [ct="{}"]"#,
+        PopupWindow::CONTROL_ID,
+        Image::CONTROL_ID
+    ))
+    .unwrap();
+    parser
+        .document()
+        .select(&image_selector)
+        .find_map(|image_ref| {
+            let src = match ElementWrapper::from_ref(image_ref) {
+                Ok(ElementWrapper::Image(image)) => image.lsdata().src().cloned(),
+                _ => None,
+            };
+            src.as_deref()
+                .and_then(PopupMessageType::from_marker)
+                .or_else(|| {
+                    image_ref
+                        .value()
+                        .attr("class")
+                        .and_then(PopupMessageType::from_marker)
+                })
+        })
+}
+
+/// 팝업 창이 오류 메시지를 표시하고 있다면 메시지 내용을 반환합니다.
+pub(crate) fn popup_error(parser: &ElementParser) -> Option<String> {
+    (popup_message_type(parser) == Some(PopupMessageType::Error))
+        .then(|| popup_messages(parser).join(" "))
+}
+
+/// 요청 후 열려 있는 팝업 창의 메시지를 읽습니다.
+///
+/// 팝업 창이 없다면 `None`을, 성공 또는 정보 메시지라면 메시지 내용을 반환합니다. 그 외의 팝업 창은 메시지 내용을 오류로 반환합니다.
+pub(crate) fn popup_result(parser: &ElementParser) -> Result<Option<String>, String> {
+    if parser.document().select(&popup_selector()).next().is_none() {
+        return Ok(None);
+    }
+    let message = popup_messages(parser).join(" ");
+    match popup_message_type(parser) {
+        Some(PopupMessageType::Success | PopupMessageType::Information) => Ok(Some(message)),
+        _ => Err(message),
+    }
+}
+
+/// 열려 있는 팝업 창이 확인 질문일 때에만 `labels` 중 하나를 텍스트로 가진 버튼을 누릅니다. 버튼을 눌렀다면 `true`를 반환합니다.
+pub(crate) async fn confirm_popup(
+    client: &mut USaintClient,
+    labels: &[&str],
+) -> Result<bool, WebDynproError> {
+    if popup_message_type(&ElementParser::new(client.body())) != Some(PopupMessageType::Question) {
+        return Ok(false);
+    }
+    press_popup_button(client, labels).await
+}
+
+fn element_text(elem: ElementRef<'_>) -> String {
+    ElementWrapper::from_ref(elem)
+        .and_then(|wrapped| wrapped.textise())
+        .unwrap_or_else(|_| elem.text().collect::<Vec<&str>>().join(" "))
+        .trim()
+        .to_string()
+}
+
+/// 팝업 창 안의 라벨과 라벨이 가리키는 필드의 값을 `(라벨, 값)` 목록으로 반환합니다.
+pub(crate) fn popup_fields(parser: &ElementParser) -> Vec<(String, String)> {
+    let label_selector = Selector::parse(&format!(
+        r#"[ct="{}"] [ct="{}"]"#,
+        PopupWindow::CONTROL_ID,
+        Label::CONTROL_ID
+    ))
+    .unwrap();
+    parser
+        .document()
+        .select(&label_selector)
+        .filter_map(|label_ref| {
+            let ElementWrapper::Label(label) = ElementWrapper::from_ref(label_ref).ok()? else {
+                return None;
+            };
+            let target = label.lsdata().label_for()?;
+            let target_selector = Selector::parse(&format!(r#"[id="{target}"]"#)).ok()?;
+            let target_ref = parser.document().select(&target_selector).next()?;
+            let key = label
+                .lsdata()
+                .text()
+                .cloned()
+                .unwrap_or_else(|| element_text(label_ref));
+            Some((
+                key.trim().trim_end_matches(':').trim().to_string(),
+                element_text(target_ref),
+            ))
+        })
+        .collect()
+}
+
+/// 팝업 창 안 테이블의 행별 `(열 제목, 값)` 목록
+pub(crate) type PopupTable = Vec<Vec<(String, String)>>;
+
+/// 팝업 창 안의 모든 테이블을 행별 `(열 제목, 값)` 목록으로 반환합니다.
+pub(crate) fn popup_tables(parser: &ElementParser) -> Result<Vec<PopupTable>, WebDynproError> {
+    let table_selector = Selector::parse(&format!(
+        r#"[ct="{}"] [ct="{}"]"#,
+        PopupWindow::CONTROL_ID,
+        SapTable::CONTROL_ID
+    ))
+    .unwrap();
+    parser
+        .document()
+        .select(&table_selector)
+        .map(|table_ref| {
+            let table: SapTable<'_> = ElementWrapper::from_ref(table_ref)?.try_into()?;
+            let body = table.table()?;
+            body.iter()
+                .map(|row| row.try_row_into::<Vec<(String, String)>>(body.header(), parser))
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{PopupMessageType, popup_message_type, popup_messages, popup_result};
+    use crate::webdynpro::{
+        client::body::{Body, body_fixture},
+        element::parser::ElementParser,
+    };
+
+    fn popup_body(content: &str) -> Body {
+        body_fixture(&format!(
+            r#"<div id="WD01" ct="PW" lsdata="{{}}">{content}</div>"#
+        ))
+    }
+
+    #[test]
+    fn read_popup_message_type() {
+        let body = popup_body(
+            r#"<img id="WD02" ct="IMG" lsdata="{3:'~Icon/ErrorMessage'}"/><span id="WD03" ct="TV" lsdata="{}">수강신청 기간이 아닙니다.</span>"#,
+        );
+        let parser = ElementParser::new(&body);
+        assert_eq!(popup_message_type(&parser), Some(PopupMessageType::Error));
+        assert_eq!(popup_messages(&parser), vec!["수강신청 기간이 아닙니다."]);
+        let body = popup_body(r#"<img id="WD02" ct="IMG" class="lsIcon--QuestionMessage"/>"#);
+        assert_eq!(
+            popup_message_type(&ElementParser::new(&body)),
+            Some(PopupMessageType::Question)
+        );
+        let body = popup_body(r#"<span id="WD03" ct="TV" lsdata="{}">완료되었습니다.</span>"#);
+        assert_eq!(popup_message_type(&ElementParser::new(&body)), None);
+    }
+
+    #[test]
+    fn read_popup_result() {
+        let body = popup_body(
+            r#"<img id="WD02" ct="IMG" lsdata="{3:'~Icon/SuccessMessage'}"/><span id="WD03" ct="TV" lsdata="{}">신청되었습니다.</span>"#,
+        );
+        assert_eq!(
+            popup_result(&ElementParser::new(&body)),
+            Ok(Some("신청되었습니다.".to_string()))
+        );
+        let body = popup_body(
+            r#"<img id="WD02" ct="IMG" lsdata="{3:'~Icon/WarningMessage'}"/><span id="WD03" ct="TV" lsdata="{}">이미 신청한 과목입니다.</span>"#,
+        );
+        assert_eq!(
+            popup_result(&ElementParser::new(&body)),
+            Err("이미 신청한 과목입니다.".to_string())
+        );
+        let body = popup_body(r#"<span id="WD03" ct="TV" lsdata="{}">처리되었습니다.</span>"#);
+        assert_eq!(
+            popup_result(&ElementParser::new(&body)),
+            Err("처리되었습니다.".to_string())
+        );
+        let body = body_fixture("");
+        assert_eq!(popup_result(&ElementParser::new(&body)), Ok(None));
+    }
+}
@@ -1,14 +1,37 @@
 use crate::application::USaintClient;
 use crate::webdynpro::command::WebDynproCommandExecutor;
 use crate::webdynpro::command::element::complex::{
-    SapTableBodyCommand, SapTableLSDataCommand, SapTableVerticalScrollEventCommand,
+    SapTableBodyCommand, SapTableLSDataCommand, SapTableRowSelectEventCommand,
+    SapTableVerticalScrollEventCommand,
 };
+use crate::webdynpro::element::ElementDefWrapper;
 use crate::webdynpro::element::complex::SapTableDef;
-use crate::webdynpro::element::complex::sap_table::FromSapTable;
+use crate::webdynpro::element::complex::sap_table::cell::{SapTableCell, SapTableCellWrapper};
+use crate::webdynpro::element::complex::sap_table::property::AccessType;
+use crate::webdynpro::element::complex::sap_table::{FromSapTable, SapTableBody, SapTableRow};
 use crate::webdynpro::element::definition::ElementDefinition;
 use crate::webdynpro::element::parser::ElementParser;
 use crate::webdynpro::error::{ElementError, WebDynproError};
 
+/// 테이블에 행이 없거나, 첫 행에 내역이 "없습니다." 라는 안내 문구가 표시되어 있다면 `true`를 반환합니다.
+pub(crate) fn is_empty_table(
+    parser: &ElementParser,
+    table: SapTableDef,
+) -> Result<bool, WebDynproError> {
+    let body = parser.read(SapTableBodyCommand::new(table))?;
+    let Some(first_row) = body.iter().next() else {
+        return Ok(true);
+    };
+    if let Some(Ok(SapTableCellWrapper::Normal(cell))) = first_row.iter_value(parser).next() {
+        if let Some(ElementDefWrapper::TextView(tv_def)) = cell.content() {
+            if let Ok(tv) = parser.element_from_def(&tv_def) {
+                return Ok(tv.text().contains("없습니다."));
+            }
+        }
+    }
+    Ok(false)
+}
+
 pub(crate) async fn try_table_into_with_scroll<T: for<'body> FromSapTable<'body>>(
     client: &mut USaintClient,
     mut parser: ElementParser,
@@ -53,3 +76,107 @@ pub(crate) async fn try_table_into_with_scroll<T: for<'body> FromSapTable<'body>
     }
     Ok(results)
 }
+
+/// 테이블을 스크롤하며 각 행에 `find`를 호출하고, 처음으로 `Some`을 반환한 값을 반환합니다. 끝까지 찾지 못했다면 `None`을 반환합니다.
+pub(crate) async fn find_row_with_scroll<R, E: From<WebDynproError>>(
+    client: &mut USaintClient,
+    table: SapTableDef,
+    mut find: impl FnMut(&SapTableBody, &SapTableRow, &ElementParser) -> Result<Option<R>, E>,
+) -> Result<Option<R>, E> {
+    let mut parser = ElementParser::new(client.body());
+    let row_count = parser
+        .read(SapTableLSDataCommand::new(table.clone()))?
+        .row_count()
+        .and_then(|count| usize::try_from(*count).ok())
+        .unwrap_or(0);
+    let mut scanned = 0;
+    loop {
+        let page_len = {
+            let body = parser.read(SapTableBodyCommand::new(table.clone()))?;
+            for row in body.iter() {
+                if let Some(found) = find(&body, row, &parser)? {
+                    return Ok(Some(found));
+                }
+            }
+            body.len()
+        };
+        scanned += page_len;
+        if page_len == 0 || scanned >= row_count {
+            return Ok(None);
+        }
+        let first_visible = u32::try_from(scanned).map_err(|_| {
+            WebDynproError::Element(ElementError::InvalidContent {
+                element: table.id().to_string(),
+                content: format!("Row index {scanned} is out of range"),
+            })
+        })?;
+        let event = parser.read(SapTableVerticalScrollEventCommand::new(
+            table.clone(),
+            first_visible,
+            "",
+            "SCROLLBAR",
+            false,
+            false,
+            false,
+            false,
+        ))?;
+        client.process_event(false, event).await?;
+        parser = ElementParser::new(client.body());
+    }
+}
+
+/// 테이블을 스크롤하며 `predicate`를 만족하는 첫 행을 찾아 선택합니다. 행을 선택했다면 `true`를 반환합니다.
+pub(crate) async fn select_row_with_scroll<T: for<'body> FromSapTable<'body>>(
+    client: &mut USaintClient,
+    table: SapTableDef,
+    predicate: impl Fn(&T) -> bool,
+) -> Result<bool, WebDynproError> {
+    let event = find_row_with_scroll(client, table.clone(), |body, row, parser| {
+        if !row
+            .try_row_into::<T>(body.header(), parser)
+            .is_ok_and(|value| predicate(&value))
+        {
+            return Ok(None);
+        }
+        parser
+            .read(SapTableRowSelectEventCommand::new(
+                table.clone(),
+                row.row_index()
+                    .and_then(|idx| i32::try_from(idx).ok())
+                    .unwrap_or(-1),
+                row.user_data().unwrap_or(""),
+                "",
+                AccessType::Standard,
+                "",
+            ))
+            .map(Some)
+    })
+    .await?;
+    let Some(event) = event else {
+        return Ok(false);
+    };
+    client.process_event(false, event).await?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::is_empty_table;
+    use crate::webdynpro::{
+        client::body::{body_fixture, sap_table_fixture},
+        element::{complex::SapTableDef, definition::ElementDefinition, parser::ElementParser},
+    };
+
+    const TABLE: SapTableDef = SapTableDef::new("TEST.ID_0001:VIW_MAIN.TABLE");
+
+    #[test]
+    fn detect_empty_table() {
+        let is_empty = |rows: &[&[&str]]| {
+            let body = body_fixture(&sap_table_fixture(TABLE.id(), &["과목명", "성적"], rows));
+            is_empty_table(&ElementParser::new(&body), TABLE).unwrap()
+        };
+        assert!(is_empty(&[]));
+        assert!(is_empty(&[&["조회된 내역이 없습니다.", ""]]));
+        assert!(!is_empty(&[&["컴퓨터구조", "A+"]]));
+    }
+}
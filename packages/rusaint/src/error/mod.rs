@@ -31,6 +31,9 @@ pub enum SsuSsoError {
     /// 페이지 로그인이 실패하여 토큰이 응답에 포함되지 않음
     #[error("Token is not included in response: {0}")]
     CantFindToken(String),
+    /// 로그인에 사용할 학번(또는 교직원 번호)이 올바르지 않음
+    #[error("Invalid account id: {0:?}")]
+    InvalidId(String),
 }
 
 /// 특정 애플리케이션에서 반환하는 오류
@@ -48,4 +51,43 @@ pub enum ApplicationError {
     /// 조건에 맞는 강의를 찾을 수 없음
     #[error("No lecture found")]
     NoLectureResult,
+    /// 강의의 강의계획서가 없음
+    #[error("No syllabus provided for lecture")]
+    NoSyllabus,
+    /// 해당 학기의 등록금 고지 정보가 없음
+    #[error("No tuition bill provided")]
+    NoTuitionBill,
+    /// 주어진 날짜에 해당하는 학사일정이 없음
+    #[error("No academic calendar provided")]
+    NoAcademicCalendar,
+    /// 학생의 지도교수 정보가 없음
+    #[error("No advisor information provided")]
+    NoAdvisorInformation,
+    /// 계정의 학위과정으로 포털 역할을 판단할 수 없음(교직원 계정이거나 여러 역할의 학위과정을 가진 경우 등)
+    #[error("Cannot determine the portal role of this account")]
+    UnknownPortalRole,
+    /// 채플 결석신청 내용이 올바르지 않음
+    #[error("Invalid chapel absence request: {0}")]
+    InvalidAbsenceRequest(String),
+    /// 강의평가 응답이 모든 문항을 알맞게 채우지 않음
+    #[error("Incomplete lecture assessment: {0}")]
+    IncompleteAssessment(String),
+    /// 내려받을 수 있는 증명서 문서가 없음
+    #[error("No downloadable certificate document provided")]
+    NoCertificateDocument,
+    /// 신청하려는 강의의 수강 인원이 가득 참
+    #[error("Lecture is full: {0}")]
+    LectureFull(String),
+    /// 신청하려는 강의가 이미 신청한 강의와 시간이 겹침
+    #[error("Lecture time conflicts with registered lectures: {0}")]
+    TimeConflict(String),
+    /// 신청 가능한 최대 학점을 초과함
+    #[error("Credit limit exceeded: {0}")]
+    CreditLimitExceeded(String),
+    /// 기타 사유로 수강신청 요청이 거부됨
+    #[error("Course registration rejected: {0}")]
+    RegistrationRejected(String),
+    /// 학사 신청 요청이 거부됨
+    #[error("Request rejected: {0}")]
+    RequestRejected(String),
 }
@@ -67,6 +67,9 @@ pub use session::obtain_ssu_sso_token;
 #[cfg(feature = "application")]
 pub use session::USaintSession;
 
+#[cfg(feature = "application")]
+pub use session::manager::{Credential, CredentialProvider, SessionManager, SessionManagerBuilder};
+
 #[cfg(feature = "application")]
 /// u-saint 애플리케이션에서 공통으로 사용하는 데이터
 pub mod model;
@@ -1,11 +1,11 @@
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
 /// 학기 종류
 ///
 /// 각 애플리케이션에서의 변환은 애플리케이션 내에서 직접 처리하여야 합니다.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize, ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, ValueEnum)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum SemesterType {
     /// 1학기
@@ -29,3 +29,179 @@ impl Display for SemesterType {
         write!(f, "{str}")
     }
 }
+
+/// u-saint 포털에서의 계정 역할
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum PortalRole {
+    /// 학부생
+    Undergraduate,
+    /// 대학원생
+    Graduate,
+}
+
+/// 하루 중의 시각(시:분)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct TimeOfDay {
+    hour: u32,
+    minute: u32,
+}
+
+impl TimeOfDay {
+    /// 시와 분으로 시각을 만듭니다. 올바르지 않은 시각이라면 `None`을 반환합니다.
+    pub fn new(hour: u32, minute: u32) -> Option<TimeOfDay> {
+        if hour < 24 && minute < 60 {
+            Some(TimeOfDay { hour, minute })
+        } else {
+            None
+        }
+    }
+
+    /// 시를 반환합니다.
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// 분을 반환합니다.
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+
+    /// 자정으로부터 지난 분을 반환합니다.
+    pub fn minutes_from_midnight(&self) -> u32 {
+        self.hour * 60 + self.minute
+    }
+}
+
+impl FromStr for TimeOfDay {
+    type Err = String;
+
+    /// `hh:mm` 형태의 문자열을 시각으로 변환합니다.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (hour, minute) = s
+            .trim()
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid time: {s}"))?;
+        let hour = hour.parse().map_err(|_| format!("Invalid hour: {s}"))?;
+        let minute = minute.parse().map_err(|_| format!("Invalid minute: {s}"))?;
+        TimeOfDay::new(hour, minute).ok_or_else(|| format!("Time out of range: {s}"))
+    }
+}
+
+impl Display for TimeOfDay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+/// 달력 상의 날짜
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// 연, 월, 일로 날짜를 만듭니다. 올바르지 않은 날짜라면 `None`을 반환합니다.
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Date> {
+        if (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month) {
+            Some(Date { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// 연도를 반환합니다.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// 월을 반환합니다.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// 일을 반환합니다.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// 1970년 1월 1일로부터 지난 일 수를 반환합니다.
+    pub fn days_from_epoch(&self) -> i64 {
+        // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let yoe = year - era * 400;
+        let month = i64::from(self.month);
+        let doy =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146097 + doe - 719468
+    }
+
+    /// 1970년 1월 1일로부터 지난 일 수로 날짜를 만듭니다.
+    pub fn from_days_from_epoch(days: i64) -> Date {
+        // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let doe = days - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+        Date { year, month, day }
+    }
+
+    /// 주어진 일 수 만큼 지난 날짜를 반환합니다.
+    pub fn add_days(&self, days: i64) -> Date {
+        Date::from_days_from_epoch(self.days_from_epoch() + days)
+    }
+
+    /// 월요일을 0으로 하는 요일 번호(0~6)를 반환합니다.
+    pub fn weekday_index(&self) -> u32 {
+        // 1970-01-01 is Thursday
+        (self.days_from_epoch() + 3).rem_euclid(7) as u32
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    /// `yyyy-mm-dd`, `yyyy.mm.dd`, `yyyy/mm/dd`, `yyyymmdd` 형태의 문자열을 날짜로 변환합니다.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim().trim_end_matches('.');
+        let parts: Vec<&str> = if value.len() == 8 && value.chars().all(|c| c.is_ascii_digit()) {
+            vec![&value[0..4], &value[4..6], &value[6..8]]
+        } else {
+            value.split(['-', '.', '/']).map(str::trim).collect()
+        };
+        let [year, month, day] = parts.as_slice() else {
+            return Err(format!("Invalid date: {s}"));
+        };
+        let (Ok(year), Ok(month), Ok(day)) = (year.parse(), month.parse(), day.parse()) else {
+            return Err(format!("Invalid date: {s}"));
+        };
+        Date::new(year, month, day).ok_or_else(|| format!("Date out of range: {s}"))
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
@@ -0,0 +1,399 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex, Weak},
+    time::Duration,
+};
+
+use tokio::{
+    sync::{Mutex as AsyncMutex, Semaphore},
+    task::JoinHandle,
+    time::Instant,
+};
+
+use crate::error::RusaintError;
+
+use super::USaintSession;
+
+const DEFAULT_SESSION_LIFETIME: Duration = Duration::from_secs(60 * 60);
+const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(5 * 60);
+const DEFAULT_MAX_CONCURRENT_LOGINS: usize = 4;
+
+/// [`SessionManager`]가 로그인 시 사용하는 인증 정보
+#[derive(Clone)]
+pub enum Credential {
+    /// 학번과 함께 사용할 비밀번호
+    Password(String),
+    /// 학번과 함께 사용할 SSO 토큰
+    Token(String),
+    /// 로그인 없이 그대로 사용할 세션(저장된 쿠키로부터 불러온 세션 등)
+    Session(Arc<USaintSession>),
+}
+
+impl std::fmt::Debug for Credential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never expose secrets in logs
+        match self {
+            Credential::Password(_) => f.write_str("Password(..)"),
+            Credential::Token(_) => f.write_str("Token(..)"),
+            Credential::Session(_) => f.write_str("Session(..)"),
+        }
+    }
+}
+
+/// 학번에 해당하는 인증 정보를 제공하는 트레이트
+pub trait CredentialProvider: Send + Sync {
+    /// 주어진 학번의 인증 정보를 반환합니다.
+    fn credential(&self, id: &str)
+    -> impl Future<Output = Result<Credential, RusaintError>> + Send;
+}
+
+#[derive(Debug)]
+struct CachedSession {
+    session: Arc<USaintSession>,
+    created_at: Instant,
+}
+
+type SessionSlot = Arc<AsyncMutex<Option<CachedSession>>>;
+
+/// 여러 계정의 [`USaintSession`]을 학번별로 관리하는 세션 관리자
+///
+/// 세션이 필요할 때 [`CredentialProvider`]를 통해 로그인하고, 만료되기 전까지 세션을 재사용합니다.
+/// 동시에 진행되는 SSO 로그인 수는 [`SessionManagerBuilder::max_concurrent_logins`]로 제한되며,
+/// 한 학번에 대한 로그인은 항상 하나만 진행됩니다.
+/// [`spawn_background_refresh()`](SessionManager::spawn_background_refresh)로 만료가 임박한 세션을 백그라운드에서 미리 갱신할 수 있습니다.
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// use rusaint::{Credential, CredentialProvider, RusaintError, SessionManagerBuilder};
+/// use rusaint::application::{USaintClientBuilder, chapel::ChapelApplication};
+///
+/// struct Provider;
+///
+/// impl CredentialProvider for Provider {
+///     async fn credential(&self, id: &str) -> Result<Credential, RusaintError> {
+///         Ok(Credential::Password(format!("password-of-{id}")))
+///     }
+/// }
+///
+/// let manager = SessionManagerBuilder::new().build(Provider);
+/// let session = manager.session("20211561").await.unwrap();
+/// let app = USaintClientBuilder::new().session(session).build_into::<ChapelApplication>().await.unwrap();
+/// # })
+/// ```
+#[derive(Debug)]
+pub struct SessionManager<P: CredentialProvider> {
+    provider: P,
+    slots: Mutex<HashMap<String, SessionSlot>>,
+    login_permits: Semaphore,
+    session_lifetime: Duration,
+    refresh_margin: Duration,
+}
+
+impl<P: CredentialProvider> SessionManager<P> {
+    /// 학번에 해당하는 세션을 반환합니다.
+    ///
+    /// 캐시된 세션이 없거나 만료가 임박한 경우 새로 로그인합니다.
+    pub async fn session(&self, id: &str) -> Result<Arc<USaintSession>, RusaintError> {
+        let slot = self.slot(id);
+        let mut cached = slot.lock().await;
+        if let Some(cached) = cached.as_ref() {
+            if !self.is_stale(cached) {
+                return Ok(cached.session.clone());
+            }
+        }
+        let session = self.login(id).await?;
+        *cached = Some(CachedSession {
+            session: session.clone(),
+            created_at: Instant::now(),
+        });
+        Ok(session)
+    }
+
+    /// 캐시 여부와 관계 없이 새로 로그인하여 세션을 갱신합니다.
+    pub async fn refresh(&self, id: &str) -> Result<Arc<USaintSession>, RusaintError> {
+        let slot = self.slot(id);
+        let mut cached = slot.lock().await;
+        let session = self.login(id).await?;
+        *cached = Some(CachedSession {
+            session: session.clone(),
+            created_at: Instant::now(),
+        });
+        Ok(session)
+    }
+
+    /// 만료가 임박한 캐시된 세션을 모두 갱신하고, 갱신한 세션의 수를 반환합니다.
+    ///
+    /// 다른 작업에서 로그인 중인 세션은 건너뛰며, 갱신에 실패한 세션은 기존 세션을 유지하고 다음 요청 시 다시 로그인합니다.
+    pub async fn refresh_stale(&self) -> usize {
+        let mut refreshed = 0;
+        for (id, slot) in self.slot_entries() {
+            let Ok(mut cached) = slot.try_lock() else {
+                continue;
+            };
+            if !cached.as_ref().is_some_and(|cached| self.is_stale(cached)) {
+                continue;
+            }
+            match self.login(&id).await {
+                Ok(session) => {
+                    *cached = Some(CachedSession {
+                        session,
+                        created_at: Instant::now(),
+                    });
+                    refreshed += 1;
+                }
+                Err(err) => log::warn!("Failed to refresh u-saint session for {id}: {err}"),
+            }
+        }
+        refreshed
+    }
+
+    /// 주어진 간격마다 [`refresh_stale()`](Self::refresh_stale)을 실행하는 백그라운드 작업을 시작합니다.
+    ///
+    /// 작업은 세션 관리자가 해제되면 종료되며, 반환된 [`JoinHandle`]로 직접 중단할 수도 있습니다.
+    pub fn spawn_background_refresh(self: &Arc<Self>, interval: Duration) -> JoinHandle<()>
+    where
+        P: 'static,
+    {
+        let manager: Weak<Self> = Arc::downgrade(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let Some(manager) = manager.upgrade() else {
+                    break;
+                };
+                manager.refresh_stale().await;
+            }
+        })
+    }
+
+    /// 학번에 해당하는 세션을 캐시에서 제거합니다. 다음 요청 시 새로 로그인합니다.
+    ///
+    /// 해당 학번의 로그인이 진행 중이라면 로그인이 끝날 때까지 기다린 후 제거합니다.
+    pub async fn invalidate(&self, id: &str) {
+        let slot = self.slots.lock().unwrap().get(id).cloned();
+        if let Some(slot) = slot {
+            *slot.lock().await = None;
+        }
+    }
+
+    /// 학번을 관리 대상에서 제거하고, 캐시되어 있던 세션을 반환합니다.
+    ///
+    /// [`invalidate()`](Self::invalidate)와 달리 학번 항목 자체를 삭제하므로 로그아웃한 계정처럼 더 이상 사용하지 않는 학번에 사용합니다.
+    /// 해당 학번의 로그인이 진행 중이라면 로그인이 끝날 때까지 기다린 후 제거합니다.
+    pub async fn remove(&self, id: &str) -> Option<Arc<USaintSession>> {
+        let slot = self.slots.lock().unwrap().remove(id)?;
+        slot.lock().await.take().map(|cached| cached.session)
+    }
+
+    /// 캐시된 모든 세션을 제거합니다.
+    pub async fn clear(&self) {
+        for (_, slot) in self.slot_entries() {
+            *slot.lock().await = None;
+        }
+    }
+
+    /// 현재 세션이 캐시되어 있는 학번 목록을 반환합니다.
+    pub async fn cached_ids(&self) -> Vec<String> {
+        let mut ids = Vec::new();
+        for (id, slot) in self.slot_entries() {
+            if slot.lock().await.is_some() {
+                ids.push(id);
+            }
+        }
+        ids
+    }
+
+    fn slot_entries(&self) -> Vec<(String, SessionSlot)> {
+        self.slots
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(id, slot)| (id.clone(), slot.clone()))
+            .collect()
+    }
+
+    fn slot(&self, id: &str) -> SessionSlot {
+        self.slots
+            .lock()
+            .unwrap()
+            .entry(id.to_string())
+            .or_default()
+            .clone()
+    }
+
+    fn is_stale(&self, cached: &CachedSession) -> bool {
+        cached.created_at.elapsed() + self.refresh_margin >= self.session_lifetime
+    }
+
+    async fn login(&self, id: &str) -> Result<Arc<USaintSession>, RusaintError> {
+        let credential = self.provider.credential(id).await?;
+        // Semaphore is never closed, so acquiring always succeeds
+        let _permit = self.login_permits.acquire().await.unwrap();
+        log::debug!("Logging in u-saint session for {id}");
+        match credential {
+            Credential::Password(password) => {
+                Ok(Arc::new(USaintSession::with_password(id, &password).await?))
+            }
+            Credential::Token(token) => Ok(Arc::new(USaintSession::with_token(id, &token).await?)),
+            Credential::Session(session) => Ok(session),
+        }
+    }
+}
+
+/// 새로운 [`SessionManager`]를 생성하는 빌더
+#[derive(Debug)]
+pub struct SessionManagerBuilder {
+    session_lifetime: Duration,
+    refresh_margin: Duration,
+    max_concurrent_logins: usize,
+}
+
+impl SessionManagerBuilder {
+    /// 새로운 빌더를 만듭니다.
+    pub fn new() -> SessionManagerBuilder {
+        SessionManagerBuilder {
+            session_lifetime: DEFAULT_SESSION_LIFETIME,
+            refresh_margin: DEFAULT_REFRESH_MARGIN,
+            max_concurrent_logins: DEFAULT_MAX_CONCURRENT_LOGINS,
+        }
+    }
+
+    /// 로그인한 세션을 유효하다고 간주할 시간을 설정합니다. 기본값은 1시간입니다.
+    pub fn session_lifetime(mut self, lifetime: Duration) -> SessionManagerBuilder {
+        self.session_lifetime = lifetime;
+        self
+    }
+
+    /// 세션 만료 전 미리 갱신할 시간을 설정합니다. 기본값은 5분입니다.
+    pub fn refresh_margin(mut self, margin: Duration) -> SessionManagerBuilder {
+        self.refresh_margin = margin;
+        self
+    }
+
+    /// 동시에 진행할 수 있는 최대 SSO 로그인 수를 설정합니다. 기본값은 4입니다.
+    pub fn max_concurrent_logins(mut self, count: usize) -> SessionManagerBuilder {
+        self.max_concurrent_logins = count.max(1);
+        self
+    }
+
+    /// 주어진 [`CredentialProvider`]로 [`SessionManager`]를 생성합니다.
+    pub fn build<P: CredentialProvider>(self, provider: P) -> SessionManager<P> {
+        SessionManager {
+            provider,
+            slots: Mutex::new(HashMap::new()),
+            login_permits: Semaphore::new(self.max_concurrent_logins),
+            session_lifetime: self.session_lifetime,
+            refresh_margin: self.refresh_margin,
+        }
+    }
+}
+
+impl Default for SessionManagerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Duration,
+    };
+
+    use super::{Credential, CredentialProvider, SessionManagerBuilder};
+    use crate::{RusaintError, USaintSession};
+
+    #[derive(Default)]
+    struct CountingProvider {
+        logins: AtomicUsize,
+        active: AtomicUsize,
+        max_active: AtomicUsize,
+    }
+
+    impl CredentialProvider for Arc<CountingProvider> {
+        async fn credential(&self, _id: &str) -> Result<Credential, RusaintError> {
+            self.logins.fetch_add(1, Ordering::SeqCst);
+            let active = self.active.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_active.fetch_max(active, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.active.fetch_sub(1, Ordering::SeqCst);
+            Ok(Credential::Session(Arc::new(USaintSession::anonymous())))
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn reuses_cached_session() {
+        let provider = Arc::new(CountingProvider::default());
+        let manager = SessionManagerBuilder::new().build(provider.clone());
+        let first = manager.session("20211561").await.unwrap();
+        let second = manager.session("20211561").await.unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(provider.logins.load(Ordering::SeqCst), 1);
+        assert_eq!(manager.cached_ids().await, vec!["20211561".to_string()]);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn invalidate_never_runs_logins_concurrently() {
+        let provider = Arc::new(CountingProvider::default());
+        let manager = Arc::new(SessionManagerBuilder::new().build(provider.clone()));
+        let pending = {
+            let manager = manager.clone();
+            tokio::spawn(async move { manager.session("20211561").await.map(|_| ()) })
+        };
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        manager.invalidate("20211561").await;
+        let (first, second) = tokio::join!(pending, manager.session("20211561"));
+        first.unwrap().unwrap();
+        second.unwrap();
+        assert_eq!(provider.logins.load(Ordering::SeqCst), 2);
+        assert_eq!(provider.max_active.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn remove_forgets_account() {
+        let provider = Arc::new(CountingProvider::default());
+        let manager = SessionManagerBuilder::new().build(provider.clone());
+        let session = manager.session("20211561").await.unwrap();
+        manager.session("20211562").await.unwrap();
+        let removed = manager.remove("20211561").await.unwrap();
+        assert!(Arc::ptr_eq(&session, &removed));
+        assert!(manager.remove("20211561").await.is_none());
+        assert_eq!(manager.slot_entries().len(), 1);
+        assert_eq!(manager.cached_ids().await, vec!["20211562".to_string()]);
+        manager.session("20211561").await.unwrap();
+        assert_eq!(provider.logins.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn clear_removes_all_sessions() {
+        let provider = Arc::new(CountingProvider::default());
+        let manager = SessionManagerBuilder::new().build(provider.clone());
+        manager.session("20211561").await.unwrap();
+        manager.session("20211562").await.unwrap();
+        manager.clear().await;
+        assert!(manager.cached_ids().await.is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn refreshes_stale_sessions_in_background() {
+        let provider = Arc::new(CountingProvider::default());
+        let manager = Arc::new(
+            SessionManagerBuilder::new()
+                .session_lifetime(Duration::from_millis(50))
+                .refresh_margin(Duration::from_millis(40))
+                .build(provider.clone()),
+        );
+        manager.session("20211561").await.unwrap();
+        assert_eq!(manager.refresh_stale().await, 0);
+        let handle = manager.spawn_background_refresh(Duration::from_millis(10));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        handle.abort();
+        assert!(provider.logins.load(Ordering::SeqCst) > 1);
+    }
+}
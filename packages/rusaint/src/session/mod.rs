@@ -19,6 +19,9 @@ use crate::{
     webdynpro::error::{ClientError, WebDynproError},
 };
 
+/// 여러 계정의 세션을 관리하는 [`SessionManager`](manager::SessionManager)
+pub mod manager;
+
 const SSU_USAINT_PORTAL_URL: &str = "https://saint.ssu.ac.kr/irj/portal";
 const SSU_USAINT_SSO_URL: &str = "https://saint.ssu.ac.kr/webSSO/sso.jsp";
 const SMARTID_LOGIN_URL: &str = "https://smartid.ssu.ac.kr/Symtra_sso/smln.asp";
@@ -26,27 +29,36 @@ const SMARTID_LOGIN_FORM_REQUEST_URL: &str = "https://smartid.ssu.ac.kr/Symtra_s
 
 /// u-saint 로그인이 필요한 애플리케이션 사용 시 애플리케이션에 제공하는 세션
 #[derive(Debug, Default)]
-pub struct USaintSession(CookieStoreRwLock);
+pub struct USaintSession {
+    store: CookieStoreRwLock,
+    id: Option<String>,
+}
 
 impl CookieStore for USaintSession {
     fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &Url) {
-        self.0.set_cookies(cookie_headers, url)
+        self.store.set_cookies(cookie_headers, url)
     }
 
     fn cookies(&self, url: &Url) -> Option<HeaderValue> {
-        self.0.cookies(url)
+        self.store.cookies(url)
     }
 }
 
 impl USaintSession {
     /// 익명 세션을 반환합니다. 인증이 필요 없는 애플리케이션에서의 세션 동작과 동일합니다.
     pub fn anonymous() -> USaintSession {
-        USaintSession(CookieStoreRwLock::default())
+        USaintSession::default()
     }
 
-    /// SSO 로그인 토큰과 학번으로 인증된 세션을 반환합니다.
+    /// SSO 로그인 토큰과 학번(또는 교직원 번호)으로 인증된 세션을 반환합니다.
+    ///
+    /// 교직원 번호처럼 숫자가 아닌 문자가 포함된 번호도 그대로 전달되며, 앞뒤 공백은 제거됩니다.
     pub async fn with_token(id: &str, token: &str) -> Result<USaintSession, RusaintError> {
-        let session_store = Self::anonymous();
+        let id = id.trim();
+        if id.is_empty() || id.chars().any(char::is_whitespace) {
+            return Err(SsuSsoError::InvalidId(id.to_string()))?;
+        }
+        let mut session_store = Self::anonymous();
         let client = Client::builder()
             .user_agent(DEFAULT_USER_AGENT)
             .build()
@@ -79,7 +91,7 @@ impl USaintSession {
         if let Some(waf) = waf {
             let waf_cookie_str = format!("WAF={}; domain=saint.ssu.ac.kr; path=/;", waf.value());
             session_store
-                .0
+                .store
                 .write()
                 .unwrap()
                 .parse(
@@ -92,7 +104,7 @@ impl USaintSession {
         }
         let token_cookie_str = format!("sToken={token}; domain=.ssu.ac.kr; path=/; secure");
         let req = client
-            .get(format!("{SSU_USAINT_SSO_URL}?sToken={token}&sIdno={id}"))
+            .get(SSU_USAINT_SSO_URL)
             .query(&[("sToken", token), ("sIdno", id)])
             .headers(default_header())
             .header(
@@ -123,6 +135,7 @@ impl USaintSession {
                 .or(Err(ClientError::NoCookies(res.url().to_string())))
                 .map_err(WebDynproError::from)?;
             if str.contains("MYSAPSSO2") {
+                session_store.id = Some(id.to_string());
                 Ok(session_store)
             } else {
                 Err(WebDynproError::from(ClientError::NoSuchCookie(
@@ -136,15 +149,21 @@ impl USaintSession {
         }
     }
 
+    /// 세션을 생성할 때 사용한 학번(또는 교직원 번호)을 반환합니다. 쿠키로부터 불러온 세션은 `None`을 반환합니다.
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
     /// 학번과 비밀번호로 인증된 세션을 반환합니다.
     pub async fn with_password(id: &str, password: &str) -> Result<USaintSession, RusaintError> {
+        let id = id.trim();
         let token = obtain_ssu_sso_token(id, password).await?;
         Self::with_token(id, &token).await
     }
 
     /// 현재 세션의 쿠키를 json 형식으로 저장합니다.
     pub fn save_to_json<W: Write>(&self, writer: &mut W) -> Result<(), RusaintError> {
-        let store = self.0.read().unwrap();
+        let store = self.store.read().unwrap();
         save_incl_expired_and_nonpersistent(&store, writer).map_err(|_| {
             WebDynproError::from(ClientError::NoCookies("Failed to save cookies".to_string()))
         })?;
@@ -158,7 +177,10 @@ impl USaintSession {
             WebDynproError::from(ClientError::NoCookies("Failed to load cookies".to_string()))
         })?;
         let store = CookieStoreRwLock::new(store);
-        Ok(USaintSession(store))
+        Ok(USaintSession {
+            store,
+            ..USaintSession::default()
+        })
     }
 }
 
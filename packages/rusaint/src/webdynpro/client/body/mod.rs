@@ -29,6 +29,10 @@ pub(crate) struct BodyUpdate {
 }
 
 impl BodyUpdate {
+    pub(super) fn take_script_calls(&mut self) -> Vec<String> {
+        self.script_calls.take().unwrap_or_default()
+    }
+
     pub(super) fn new(response: &str) -> Result<BodyUpdate, UpdateBodyError> {
         let response_xml = roxmltree::Document::parse(response)?;
         let updates = response_xml
@@ -38,6 +42,7 @@ impl BodyUpdate {
         let update = updates.first_child().ok_or(UpdateBodyError::NoSuchNode(
             "<full-update> or <delta-update>".to_string(),
         ))?;
+        let mut script_calls = Vec::new();
         let update_type: Option<BodyUpdateType>;
         if update.tag_name().name() == "full-update" {
             let windowid =
@@ -102,6 +107,9 @@ impl BodyUpdate {
                                 .to_owned(),
                         );
                     }
+                    "script-call" => {
+                        script_calls.extend(children.text().map(str::to_owned));
+                    }
                     &_ => {
                         log::warn!("Unknown body update {tag_name} is found, ignore.");
                     }
@@ -113,11 +121,17 @@ impl BodyUpdate {
                 update.tag_name().name().to_owned(),
             ))?;
         }
+        script_calls.extend(
+            updates
+                .children()
+                .filter(|node| node.tag_name().name() == "script-call")
+                .filter_map(|node| node.text().map(str::to_owned)),
+        );
         // TODO: Apply additional updates to BodyUpdate struct.
         Ok(BodyUpdate {
             update: update_type,
             initialize_ids: None,
-            script_calls: None,
+            script_calls: (!script_calls.is_empty()).then_some(script_calls),
             model_updates: None,
             animation_updates: None,
         })
@@ -273,11 +287,63 @@ fn parse_sap_ssr_client(document: &str) -> Result<SapSsrClient, BodyError> {
     })
 }
 
+/// 테스트에서 사용할 페이지를 주어진 HTML 내용과 최소한의 SSR 클라이언트 폼으로 만듭니다.
+#[cfg(test)]
+pub(crate) fn body_fixture(content: &str) -> Body {
+    Body::new(format!(
+        concat!(
+            r#"<html><body><form name="sap.client.SsrClient.form" id="sap.client.SsrClient.form" action="/sap/bc/webdynpro/SAP/TEST">"#,
+            r#"<input type="hidden" name="sap-charset" id="sap-charset" value="utf-8">"#,
+            r#"<input type="hidden" name="sap-wd-secure-id" id="sap-wd-secure-id" value="secure">"#,
+            r#"<input type="hidden" name="fesrAppName" id="fesrAppName" value="TEST">"#,
+            r#"<input type="hidden" name="fesrUseBeacon" id="fesrUseBeacon" value="false">"#,
+            r#"</form>{}</body></html>"#
+        ),
+        content
+    ))
+    .unwrap()
+}
+
+/// 테스트에서 사용할 [`SapTable`](crate::webdynpro::element::complex::SapTable)의 HTML을 열 제목과 행별 값으로 만듭니다.
+#[cfg(test)]
+pub(crate) fn sap_table_fixture(id: &str, headers: &[&str], rows: &[&[&str]]) -> String {
+    let row_count = rows.len();
+    let header = headers
+        .iter()
+        .enumerate()
+        .map(|(col, text)| {
+            format!(
+                r#"<th id="{id}-hdr-{col}" subct="HC"><div id="{id}-hdr-{col}-CONTENT"><span id="{id}-hdr-{col}-txt" ct="TV" lsdata="{{}}">{text}</span></div></th>"#
+            )
+        })
+        .collect::<String>();
+    let rows = rows
+        .iter()
+        .enumerate()
+        .map(|(row, values)| {
+            let cells = values
+                .iter()
+                .enumerate()
+                .map(|(col, text)| {
+                    format!(
+                        r#"<td id="{id}-{row}-{col}" subct="STC"><span id="{id}-{row}-{col}-txt" ct="TV" lsdata="{{}}">{text}</span></td>"#
+                    )
+                })
+                .collect::<String>();
+            format!(r#"<tr rr="{}" rt="1">{cells}</tr>"#, row + 1)
+        })
+        .collect::<String>();
+    format!(
+        r#"<div id="{id}" ct="ST" lsdata="{{2:{}}}"><table><tbody id="{id}-contentTBody"><tr rt="2">{header}</tr>{rows}</tbody></table></div>"#,
+        row_count
+    )
+}
+
 #[cfg(test)]
 mod test {
     use crate::utils::DEFAULT_USER_AGENT;
     use crate::webdynpro::client::Requests;
-    use crate::webdynpro::client::body::parse_sap_ssr_client;
+    use crate::webdynpro::client::body::{BodyUpdate, BodyUpdateType, parse_sap_ssr_client};
     use reqwest::cookie::Jar;
     use std::sync::Arc;
     use url::Url;
@@ -302,4 +368,30 @@ mod test {
         let ssr_client = parse_sap_ssr_client(&result.text().await.unwrap()).unwrap();
         dbg!(ssr_client);
     }
+
+    #[test]
+    fn script_calls_in_updates() {
+        let mut update = BodyUpdate::new(concat!(
+            r#"<updates>"#,
+            r#"<delta-update windowid="DEFAULT">"#,
+            r#"<control-update id="WD01"><content><![CDATA[<span id="WD01">출력</span>]]></content></control-update>"#,
+            r#"<script-call><![CDATA[window.open('/sap/public/bc/print.pdf')]]></script-call>"#,
+            r#"</delta-update>"#,
+            r#"<script-call><![CDATA[sap.open("https://ecc.ssu.ac.kr/sap/report")]]></script-call>"#,
+            r#"</updates>"#
+        ))
+        .unwrap();
+        let Some(BodyUpdateType::Delta(windowid, controls)) = &update.update else {
+            panic!("expected delta update");
+        };
+        assert_eq!(windowid, "DEFAULT");
+        assert_eq!(controls.len(), 1);
+        assert_eq!(
+            update.take_script_calls(),
+            vec![
+                "window.open('/sap/public/bc/print.pdf')".to_string(),
+                r#"sap.open("https://ecc.ssu.ac.kr/sap/report")"#.to_string(),
+            ]
+        );
+    }
 }
@@ -10,7 +10,7 @@ use crate::{
 use body::{Body, BodyUpdate};
 use reqwest::{RequestBuilder, cookie::Jar, header::*};
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock};
 use tokio::sync::Mutex;
 use url::Url;
 
@@ -22,6 +22,7 @@ pub struct WebDynproClient {
     body: Body,
     client: reqwest::Client,
     event_queue: Mutex<EventQueue>,
+    script_calls: Vec<String>,
 }
 
 fn wd_xhr_header() -> HeaderMap {
@@ -96,6 +97,7 @@ impl WebDynproClient {
             body: Body::new(raw_body)?,
             client,
             event_queue: Mutex::new(EventQueue::new()),
+            script_calls: Vec::new(),
         })
     }
 
@@ -163,9 +165,73 @@ impl WebDynproClient {
         Ok(res.text().await?)
     }
 
-    fn mutate_body(&mut self, update: BodyUpdate) -> Result<(), WebDynproError> {
+    fn mutate_body(&mut self, mut update: BodyUpdate) -> Result<(), WebDynproError> {
+        self.script_calls = update.take_script_calls();
         Ok(self.body.apply(update)?)
     }
+
+    /// 마지막으로 처리한 서버 응답에 포함된 스크립트 호출 목록을 반환합니다.
+    pub fn script_calls(&self) -> &[String] {
+        &self.script_calls
+    }
+
+    /// 마지막으로 처리한 서버 응답의 스크립트 호출이 열거나 내려받으려는 URL 목록을 반환합니다.
+    pub fn script_urls(&self) -> Vec<Url> {
+        self.script_calls
+            .iter()
+            .flat_map(|script| script_urls(script.as_str()))
+            .filter_map(|url| self.base_url.join(&url).ok())
+            .collect()
+    }
+
+    /// 주어진 URL의 파일을 현재 세션으로 내려받습니다.
+    pub async fn download(&self, url: &Url) -> Result<WebDynproDownload, ClientError> {
+        let res = self
+            .client
+            .get(url.clone())
+            .headers(default_header())
+            .send()
+            .await?;
+        if !res.status().is_success() {
+            return Err(ClientError::InvalidResponse(res));
+        }
+        let content_type = res
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+        let file_name = res
+            .headers()
+            .get(CONTENT_DISPOSITION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(content_disposition_file_name);
+        let data = res.bytes().await?.to_vec();
+        Ok(WebDynproDownload {
+            content_type,
+            file_name,
+            data,
+        })
+    }
+}
+
+static SCRIPT_URL_REGEX: LazyLock<regex_lite::Regex> =
+    LazyLock::new(|| regex_lite::Regex::new(r#"["']((?:https?://|/sap/)[^"'\s]+)["']"#).unwrap());
+
+fn script_urls(script: &str) -> Vec<String> {
+    SCRIPT_URL_REGEX
+        .captures_iter(script)
+        .filter_map(|captures| captures.get(1))
+        .map(|url| html_escape::decode_html_entities(url.as_str()).into_owned())
+        .collect()
+}
+
+fn content_disposition_file_name(value: &str) -> Option<String> {
+    value.split(';').find_map(|part| {
+        let (key, name) = part.trim().split_once('=')?;
+        key.trim()
+            .eq_ignore_ascii_case("filename")
+            .then(|| name.trim().trim_matches('"').to_owned())
+    })
 }
 
 /// [`WebDynproClient`]을 생성하는 빌더
@@ -294,6 +360,36 @@ pub(crate) struct SapSsrClient {
     use_beacon: bool,
 }
 
+/// WebDynpro 애플리케이션에서 내려받은 파일
+#[derive(Clone, Debug)]
+pub struct WebDynproDownload {
+    content_type: Option<String>,
+    file_name: Option<String>,
+    data: Vec<u8>,
+}
+
+impl WebDynproDownload {
+    /// 파일의 Content-Type을 반환합니다.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// 서버가 알려준 파일 이름을 반환합니다.
+    pub fn file_name(&self) -> Option<&str> {
+        self.file_name.as_deref()
+    }
+
+    /// 파일 내용을 반환합니다.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// 파일 내용을 소유권과 함께 반환합니다.
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+}
+
 /// 전달받은 이벤트가 어떻게 처리되었는지 표현합니다.
 pub enum EventProcessResult {
     /// 전달받은 이벤트가 큐에 추가되었을 경우
@@ -309,7 +405,22 @@ pub mod body;
 mod test {
     use url::Url;
 
-    use crate::webdynpro::client::WebDynproClientBuilder;
+    use crate::webdynpro::client::{
+        WebDynproClientBuilder, content_disposition_file_name, script_urls,
+    };
+
+    #[test]
+    fn parse_script_call_urls() {
+        let script = r#"SAPUR.openExternalWindow('/sap/bc/webdynpro/SAP/ZCMW_DOWNLOAD?id=1&amp;type=pdf', 'CERT')"#;
+        assert_eq!(
+            script_urls(script),
+            vec!["/sap/bc/webdynpro/SAP/ZCMW_DOWNLOAD?id=1&type=pdf".to_string()]
+        );
+        assert_eq!(
+            content_disposition_file_name(r#"attachment; filename="cert.pdf""#),
+            Some("cert.pdf".to_string())
+        );
+    }
 
     #[tokio::test]
     async fn initial_load() {
@@ -4,7 +4,10 @@ use crate::webdynpro::{
     command::WebDynproCommand,
     element::{
         Element,
-        complex::{SapTableDef, SapTableLSData, sap_table::SapTableBody},
+        complex::{
+            SapTableDef, SapTableLSData,
+            sap_table::{SapTableBody, property::AccessType},
+        },
     },
     error::WebDynproError,
 };
@@ -63,6 +66,51 @@ impl WebDynproCommand for SapTableVerticalScrollEventCommand {
     }
 }
 
+/// 주어진 [`SapTable`](crate::webdynpro::element::complex::SapTable)의 행을 선택하는 이벤트를 반환
+pub struct SapTableRowSelectEventCommand {
+    element_def: SapTableDef,
+    row_index: i32,
+    row_user_data: String,
+    cell_user_data: String,
+    access_type: AccessType,
+    trigger_cell_id: String,
+}
+
+impl SapTableRowSelectEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(
+        element_def: SapTableDef,
+        row_index: i32,
+        row_user_data: &str,
+        cell_user_data: &str,
+        access_type: AccessType,
+        trigger_cell_id: &str,
+    ) -> Self {
+        Self {
+            element_def,
+            row_index,
+            row_user_data: row_user_data.to_string(),
+            cell_user_data: cell_user_data.to_string(),
+            access_type,
+            trigger_cell_id: trigger_cell_id.to_string(),
+        }
+    }
+}
+
+impl WebDynproCommand for SapTableRowSelectEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser.element_from_def(&self.element_def)?.row_select(
+            self.row_index,
+            &self.row_user_data,
+            &self.cell_user_data,
+            self.access_type,
+            &self.trigger_cell_id,
+        )
+    }
+}
+
 /// [`SapTableLSData`]를 반환
 pub struct SapTableLSDataCommand {
     element_def: SapTableDef,
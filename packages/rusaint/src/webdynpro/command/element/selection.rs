@@ -7,7 +7,7 @@ use crate::webdynpro::{
         Element,
         definition::ElementDefinition,
         selection::{
-            ComboBoxDef, ComboBoxLSData,
+            ComboBoxDef, ComboBoxLSData, RadioButtonDef,
             list_box::{ListBoxDefWrapper, ListBoxWrapper, item::ListBoxItemInfo},
         },
     },
@@ -246,3 +246,23 @@ impl WebDynproCommand for ListBoxItemInfoCommand {
         }
     }
 }
+
+/// [`RadioButton`](crate::webdynpro::element::selection::RadioButton)을 선택하는 이벤트를 반환
+pub struct RadioButtonSelectEventCommand {
+    element_def: RadioButtonDef,
+}
+
+impl RadioButtonSelectEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: RadioButtonDef) -> RadioButtonSelectEventCommand {
+        Self { element_def }
+    }
+}
+
+impl WebDynproCommand for RadioButtonSelectEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser.element_from_def(&self.element_def)?.select()
+    }
+}
@@ -1,4 +1,5 @@
 use crate::webdynpro::element::parser::ElementParser;
+use crate::webdynpro::event::Event;
 use crate::webdynpro::{
     command::WebDynproCommand,
     element::{definition::ElementDefinition, text::InputFieldDef},
@@ -32,3 +33,29 @@ impl WebDynproCommand for InputFieldValueCommand {
         Ok(text)
     }
 }
+
+/// [`InputField`](crate::webdynpro::element::text::InputField)의 내용을 바꾸는 이벤트를 반환
+pub struct InputFieldChangeEventCommand {
+    element_def: InputFieldDef,
+    value: String,
+}
+
+impl InputFieldChangeEventCommand {
+    /// 새로운 명령 객체를 생성합니다.
+    pub fn new(element_def: InputFieldDef, value: &str) -> InputFieldChangeEventCommand {
+        Self {
+            element_def,
+            value: value.to_string(),
+        }
+    }
+}
+
+impl WebDynproCommand for InputFieldChangeEventCommand {
+    type Result = Event;
+
+    fn dispatch(&self, parser: &ElementParser) -> Result<Self::Result, WebDynproError> {
+        parser
+            .element_from_def(&self.element_def)?
+            .change(&self.value)
+    }
+}
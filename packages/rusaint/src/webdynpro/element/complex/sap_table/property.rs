@@ -3,6 +3,7 @@ use std::fmt::Display;
 
 /// 테이블 내의 셀 접근 방식
 #[allow(missing_docs)]
+#[derive(Clone, Copy, Debug)]
 pub enum AccessType {
     Invalid,
     Standard,
@@ -15,7 +15,7 @@ use self::{
         tab_strip::item::TabStripItem,
     },
     selection::{
-        ComboBox,
+        ComboBox, RadioButton,
         list_box::{
             ListBoxMultiple, ListBoxPopup, ListBoxPopupFiltered, ListBoxPopupJson,
             ListBoxPopupJsonFiltered, ListBoxSingle,
@@ -207,6 +207,7 @@ register_elements![
     ListBoxActionItem: ListBoxActionItem<'a>,
     LoadingPlaceholder: LoadingPlaceholder<'a>,
     PopupWindow: PopupWindow<'a>,
+    RadioButton: RadioButton<'a>,
     TabStrip: TabStrip<'a>,
     TabStripItem: TabStripItem<'a>,
     Tray: Tray<'a>,
@@ -371,6 +372,7 @@ impl ElementWrapper<'_> {
             ElementWrapper::TextView(tv) => Ok(tv.text().to_string()),
             ElementWrapper::Caption(cp) => Ok(cp.text().to_string()),
             ElementWrapper::CheckBox(c) => Ok(format!("{}", c.checked())),
+            ElementWrapper::RadioButton(rb) => Ok(format!("{}", rb.checked())),
             ElementWrapper::ComboBox(cb) => Ok(cb.value().unwrap_or_default().to_string()),
             ElementWrapper::InputField(ifield) => {
                 Ok(ifield.value().unwrap_or_default().to_string())
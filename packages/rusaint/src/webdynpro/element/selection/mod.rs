@@ -3,7 +3,9 @@ mod combo_box;
 pub use self::combo_box::{ComboBox, ComboBoxDef, ComboBoxLSData, property::ComboBoxBehavior};
 
 pub use self::check_box::CheckBox;
+pub use self::radio_button::{RadioButton, RadioButtonDef, RadioButtonLSData};
 /// [`ListBox`](list_box::ListBox) 구현
 pub mod list_box;
 
 mod check_box;
+mod radio_button;
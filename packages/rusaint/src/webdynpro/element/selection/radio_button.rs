@@ -0,0 +1,102 @@
+use std::{borrow::Cow, cell::OnceCell, collections::HashMap};
+
+use crate::webdynpro::element::{
+    Interactable, macros::define_element_interactable, property::Visibility,
+};
+use crate::webdynpro::{error::WebDynproError, event::Event};
+
+define_element_interactable! {
+    #[doc = "라디오 버튼"]
+    RadioButton<"RB", "RadioButton"> {},
+    #[doc = "[`RadioButton`]의 정의"]
+    RadioButtonDef,
+    #[doc = "[`RadioButton`]의 내부 데이터"]
+    RadioButtonLSData {
+        key: String => "0",
+        checked: bool => "1",
+        enabled: bool => "2",
+        readonly: bool => "3",
+        text: String => "4",
+        tooltip: String => "5",
+        invalid: bool => "6",
+        visibility: Visibility => "7",
+        group_id: String => "8",
+        access_key: String => "9",
+        text_design: String => "10",
+        used_in_sap_table: bool => "11",
+        custom_data: String => "12",
+        custom_style: String => "13",
+        labelled_by: String => "14"
+    }
+}
+
+impl<'a> RadioButton<'a> {
+    /// HTML 엘리먼트로부터 새로운 [`RadioButton`] 엘리먼트를 생성합니다.
+    pub fn new(id: Cow<'static, str>, element_ref: scraper::ElementRef<'a>) -> Self {
+        Self {
+            id,
+            element_ref,
+            lsdata: OnceCell::new(),
+            lsevents: OnceCell::new(),
+        }
+    }
+
+    /// 이 [`RadioButton`]이 선택되었는지 여부를 반환합니다.
+    pub fn checked(&self) -> bool {
+        self.element_ref
+            .attr("aria-checked")
+            .is_some_and(|str| str == "true")
+    }
+
+    /// 이 [`RadioButton`]이 비활성화 상태인지 여부를 반환합니다.
+    pub fn disabled(&self) -> bool {
+        self.element_ref
+            .attr("aria-disabled")
+            .is_some_and(|str| str == "true")
+    }
+
+    /// 라디오 버튼을 선택하는 이벤트를 반환합니다.
+    pub fn select(&self) -> Result<Event, WebDynproError> {
+        let mut parameters: HashMap<String, String> = HashMap::new();
+        parameters.insert("Id".to_string(), self.id.clone().to_string());
+        self.fire_event("Select".to_string(), parameters)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::webdynpro::{
+        client::body::body_fixture,
+        element::{Element, parser::ElementParser, selection::RadioButtonDef},
+    };
+
+    // 라디오 버튼 그룹 안의 선택된 라디오 버튼 요소
+    const RADIO_BUTTON: &str = concat!(
+        r#"<span id="ZCMB2W1020.ID_0001:VIW_POPUP.RB_01_1" ct="RB" role="radio" aria-checked="true" aria-disabled="false" "#,
+        r#"lsdata="{0:'1',1:true,2:true,3:false,4:'매우\x20그렇다',7:'VISIBLE',8:'ZCMB2W1020.ID_0001:VIW_POPUP.RBG_01'}" "#,
+        r#"lsevents="{'Select':[{'ResponseData':'delta','ClientAction':'submit'},{}]}"></span>"#
+    );
+
+    #[test]
+    fn parse_radio_button() {
+        let body = body_fixture(RADIO_BUTTON);
+        let parser = ElementParser::new(&body);
+        let def = RadioButtonDef::new("ZCMB2W1020.ID_0001:VIW_POPUP.RB_01_1");
+        let radio_button = parser.element_from_def(&def).unwrap();
+        let lsdata = radio_button.lsdata();
+        assert_eq!(lsdata.key().map(String::as_str), Some("1"));
+        assert_eq!(lsdata.checked(), Some(&true));
+        assert_eq!(lsdata.enabled(), Some(&true));
+        assert_eq!(lsdata.readonly(), Some(&false));
+        assert_eq!(lsdata.text().map(String::as_str), Some("매우 그렇다"));
+        assert_eq!(
+            lsdata.group_id().map(String::as_str),
+            Some("ZCMB2W1020.ID_0001:VIW_POPUP.RBG_01")
+        );
+        assert!(radio_button.checked());
+        assert!(!radio_button.disabled());
+        let event = radio_button.select().unwrap().to_string();
+        assert!(event.contains("RadioButton_Select"));
+        assert!(event.contains("RB_01_1"));
+    }
+}
@@ -1,12 +1,14 @@
-use std::{borrow::Cow, cell::OnceCell};
+use std::{borrow::Cow, cell::OnceCell, collections::HashMap};
 
 use crate::webdynpro::element::{
+    Interactable,
     macros::define_element_interactable,
     property::{
         EmbeddingBehaviour, IMEMode, InputFieldTextStyle, InputFieldType, SemanticColor,
         TabBehaviour, TableFieldDesign, Visibility,
     },
 };
+use crate::webdynpro::{error::WebDynproError, event::Event};
 
 // TODO: Implement additional events and data
 define_element_interactable! {
@@ -68,4 +70,12 @@ impl<'a> InputField<'a> {
     pub fn value(&self) -> Option<&str> {
         self.element_ref.attr("value")
     }
+
+    /// 입력 필드의 내용을 변경하는 이벤트를 반환합니다.
+    pub fn change(&self, value: &str) -> Result<Event, WebDynproError> {
+        let mut parameters: HashMap<String, String> = HashMap::new();
+        parameters.insert("Id".to_string(), self.id.clone().to_string());
+        parameters.insert("Value".to_string(), value.to_string());
+        self.fire_event("Change".to_string(), parameters)
+    }
 }
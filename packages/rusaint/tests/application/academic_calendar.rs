@@ -0,0 +1,53 @@
+use crate::{TARGET_YEAR, get_session};
+use lazy_static::lazy_static;
+use rusaint::{
+    RusaintError,
+    application::{USaintClientBuilder, academic_calendar::AcademicCalendarApplication},
+};
+use std::sync::{Arc, OnceLock};
+use test_log::test;
+use tokio::sync::{Mutex, RwLock};
+
+lazy_static! {
+    static ref APP: Mutex<OnceLock<Arc<RwLock<AcademicCalendarApplication>>>> =
+        Mutex::new(OnceLock::new());
+}
+
+async fn get_app() -> Result<Arc<RwLock<AcademicCalendarApplication>>, RusaintError> {
+    let app_lock = APP.lock().await;
+    if let Some(lock) = app_lock.get() {
+        Ok(lock.clone())
+    } else {
+        let session = get_session().await.unwrap().clone();
+        app_lock
+            .set(Arc::new(RwLock::new(
+                USaintClientBuilder::new()
+                    .session(session)
+                    .build_into()
+                    .await?,
+            )))
+            .unwrap();
+        Ok(app_lock.get().unwrap().clone())
+    }
+}
+
+#[test(tokio::test)]
+async fn events() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let events = app.events(*TARGET_YEAR).await.unwrap();
+    assert!(events.iter().all(|event| event.year() == *TARGET_YEAR));
+    println!("{:?}", events);
+}
+
+#[test(tokio::test)]
+async fn current_semester() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let events = app.events(*TARGET_YEAR).await.unwrap();
+    let Some(event) = events.iter().find(|event| event.semester().is_some()) else {
+        return;
+    };
+    let (year, semester) = app.current_semester(event.end()).await.unwrap();
+    println!("{} {:?}", year, semester);
+}
@@ -0,0 +1,19 @@
+use crate::get_session;
+use rusaint::application::USaintClientBuilder;
+use rusaint::application::academic_status::AcademicStatusApplication;
+use test_log::test;
+
+#[test(tokio::test)]
+async fn requests() {
+    let session = get_session().await.unwrap().clone();
+    let mut app = USaintClientBuilder::new()
+        .session(session)
+        .build_into::<AcademicStatusApplication>()
+        .await
+        .unwrap();
+    let requests = app.requests().await.unwrap();
+    for request in &requests {
+        println!("{:?} {:?}", request.kind(), request.state());
+    }
+    println!("{:?}", requests);
+}
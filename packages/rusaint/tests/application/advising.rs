@@ -0,0 +1,63 @@
+use crate::{TARGET_SEMESTER, TARGET_YEAR, get_session};
+use lazy_static::lazy_static;
+use rusaint::{
+    RusaintError,
+    application::{USaintClientBuilder, advising::AdvisingApplication},
+};
+use std::sync::{Arc, OnceLock};
+use test_log::test;
+use tokio::sync::{Mutex, RwLock};
+
+lazy_static! {
+    static ref APP: Mutex<OnceLock<Arc<RwLock<AdvisingApplication>>>> = Mutex::new(OnceLock::new());
+}
+
+async fn get_app() -> Result<Arc<RwLock<AdvisingApplication>>, RusaintError> {
+    let app_lock = APP.lock().await;
+    if let Some(lock) = app_lock.get() {
+        Ok(lock.clone())
+    } else {
+        let session = get_session().await.unwrap().clone();
+        app_lock
+            .set(Arc::new(RwLock::new(
+                USaintClientBuilder::new()
+                    .session(session)
+                    .build_into()
+                    .await?,
+            )))
+            .unwrap();
+        Ok(app_lock.get().unwrap().clone())
+    }
+}
+
+#[test(tokio::test)]
+async fn advisor() {
+    let lock = get_app().await.unwrap();
+    let app = lock.read().await;
+    let advisor = app.advisor().unwrap();
+    println!("{:?}", advisor);
+    assert!(!advisor.name().is_empty());
+}
+
+#[test(tokio::test)]
+async fn counseling() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let counseling = app
+        .counseling(*TARGET_YEAR, *TARGET_SEMESTER)
+        .await
+        .unwrap();
+    assert_eq!(counseling.year(), *TARGET_YEAR);
+    assert_eq!(counseling.semester(), *TARGET_SEMESTER);
+    println!("{:?}", counseling);
+    for record in counseling.records() {
+        assert!(!record.date().is_empty());
+    }
+    if counseling
+        .records()
+        .iter()
+        .any(|record| record.is_mandatory())
+    {
+        assert!(counseling.mandatory_completed());
+    }
+}
@@ -0,0 +1,48 @@
+use crate::get_session;
+use lazy_static::lazy_static;
+use rusaint::{
+    RusaintError,
+    application::{USaintClientBuilder, certificate::CertificateApplication},
+};
+use std::sync::{Arc, OnceLock};
+use test_log::test;
+use tokio::sync::{Mutex, RwLock};
+
+lazy_static! {
+    static ref APP: Mutex<OnceLock<Arc<RwLock<CertificateApplication>>>> =
+        Mutex::new(OnceLock::new());
+}
+
+async fn get_app() -> Result<Arc<RwLock<CertificateApplication>>, RusaintError> {
+    let app_lock = APP.lock().await;
+    if let Some(lock) = app_lock.get() {
+        Ok(lock.clone())
+    } else {
+        let session = get_session().await.unwrap().clone();
+        app_lock
+            .set(Arc::new(RwLock::new(
+                USaintClientBuilder::new()
+                    .session(session)
+                    .build_into()
+                    .await?,
+            )))
+            .unwrap();
+        Ok(app_lock.get().unwrap().clone())
+    }
+}
+
+#[test(tokio::test)]
+async fn certificate_types() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let types = app.certificate_types().await.unwrap();
+    println!("{:?}", types);
+}
+
+#[test(tokio::test)]
+async fn history() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let history = app.history().await.unwrap();
+    println!("{:?}", history);
+}
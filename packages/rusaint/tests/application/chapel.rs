@@ -54,3 +54,15 @@ async fn no_chapel() {
     println!("{:?}", info);
     println!("{:?}", info);
 }
+
+#[test(tokio::test)]
+async fn absence_reasons() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    app.information(*TARGET_YEAR, *TARGET_SEMESTER)
+        .await
+        .unwrap();
+    let reasons = app.absence_reasons().unwrap();
+    assert!(reasons.iter().all(|reason| !reason.code().is_empty()));
+    println!("{:?}", reasons);
+}
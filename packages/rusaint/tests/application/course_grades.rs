@@ -3,7 +3,7 @@ use lazy_static::lazy_static;
 use rusaint::RusaintError;
 use rusaint::application::{
     USaintClientBuilder,
-    course_grades::{CourseGradesApplication, model::CourseType},
+    course_grades::{CourseGradesApplication, model::CourseType, watcher::GradeWatcherBuilder},
 };
 use std::sync::{Arc, OnceLock};
 use test_log::test;
@@ -32,6 +32,29 @@ async fn get_app() -> Result<Arc<RwLock<CourseGradesApplication>>, RusaintError>
     }
 }
 
+#[test(tokio::test)]
+async fn course_types() {
+    let lock = get_app().await.unwrap();
+    let app = lock.read().await;
+    let course_types = app.course_types().unwrap();
+    println!("{:?}", course_types);
+    assert!(!course_types.is_empty());
+    let current = app.current_course_type().unwrap();
+    assert!(current.is_some_and(|current| course_types.contains(&current)));
+}
+
+#[test(tokio::test)]
+async fn account() {
+    let lock = get_app().await.unwrap();
+    let app = lock.read().await;
+    let account = app.account().unwrap();
+    println!("{:?}", account);
+    assert!(!account.course_types().is_empty());
+    if let Some(current) = account.current_course_type() {
+        assert!(account.course_types().contains(&current));
+    }
+}
+
 #[test(tokio::test)]
 async fn recorded_summary() {
     let lock = get_app().await.unwrap();
@@ -94,3 +117,32 @@ async fn classes_with_detail() {
     println!("{:?}", detail);
     assert!(!detail.is_empty());
 }
+
+#[test(tokio::test)]
+async fn grade_objections() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let objections = app
+        .grade_objections(CourseType::Bachelor, *TARGET_YEAR, *TARGET_SEMESTER)
+        .await
+        .unwrap();
+    println!("{:?}", objections);
+}
+
+#[test(tokio::test)]
+async fn grade_watcher() {
+    let session = get_session().await.unwrap();
+    let app = USaintClientBuilder::new()
+        .session(session)
+        .build_into::<CourseGradesApplication>()
+        .await
+        .unwrap();
+    let mut watcher =
+        GradeWatcherBuilder::new(CourseType::Bachelor, *TARGET_YEAR, *TARGET_SEMESTER)
+            .emit_initial(true)
+            .build(app);
+    let events = watcher.poll().await.unwrap();
+    println!("{:?}", events);
+    let events = watcher.poll().await.unwrap();
+    assert!(events.is_empty());
+}
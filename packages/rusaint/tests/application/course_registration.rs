@@ -0,0 +1,54 @@
+use crate::get_session;
+use lazy_static::lazy_static;
+use rusaint::{
+    RusaintError,
+    application::{USaintClientBuilder, course_registration::CourseRegistrationApplication},
+};
+use std::sync::{Arc, OnceLock};
+use test_log::test;
+use tokio::sync::{Mutex, RwLock};
+
+lazy_static! {
+    static ref APP: Mutex<OnceLock<Arc<RwLock<CourseRegistrationApplication>>>> =
+        Mutex::new(OnceLock::new());
+}
+
+async fn get_app() -> Result<Arc<RwLock<CourseRegistrationApplication>>, RusaintError> {
+    let app_lock = APP.lock().await;
+    if let Some(lock) = app_lock.get() {
+        Ok(lock.clone())
+    } else {
+        let session = get_session().await.unwrap().clone();
+        app_lock
+            .set(Arc::new(RwLock::new(
+                USaintClientBuilder::new()
+                    .session(session)
+                    .build_into()
+                    .await?,
+            )))
+            .unwrap();
+        Ok(app_lock.get().unwrap().clone())
+    }
+}
+
+#[test(tokio::test)]
+async fn cart() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let cart = app.cart().await.unwrap();
+    println!("{:?}", cart);
+}
+
+#[test(tokio::test)]
+async fn registered() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let registered = app.registered().await.unwrap();
+    let sum: f32 = registered
+        .lectures()
+        .iter()
+        .map(|lecture| lecture.credit())
+        .sum();
+    assert_eq!(sum, registered.total_credits());
+    println!("{:?}", registered);
+}
@@ -4,7 +4,13 @@ use rusaint::{
     ApplicationError, RusaintError,
     application::{
         USaintClientBuilder,
-        course_schedule::{CourseScheduleApplication, model::LectureCategory},
+        course_schedule::{
+            CourseScheduleApplication,
+            catalog::LectureKey,
+            model::LectureCategory,
+            query::{LectureQuery, LectureRows},
+            seat_monitor::{SeatEvent, SeatMonitorBuilder},
+        },
     },
     model::SemesterType,
 };
@@ -201,3 +207,106 @@ async fn find_nothing() {
         RusaintError::ApplicationError(ApplicationError::NoLectureResult)
     ));
 }
+
+#[test(tokio::test)]
+async fn lecture_syllabus() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let category = LectureCategory::major("IT대학", "글로벌미디어학부", None);
+    let lecture = app
+        .find_lectures(2025, SemesterType::One, &category)
+        .await
+        .unwrap()
+        .find(|lecture| lecture.syllabus().is_some())
+        .unwrap();
+    let syllabus = app.lecture_syllabus(&lecture).await.unwrap();
+    assert_eq!(syllabus.code(), lecture.code());
+    println!("{:?}", syllabus);
+}
+
+#[test(tokio::test)]
+async fn category_tree() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let tree = app.category_tree(2025, SemesterType::One).await.unwrap();
+    assert!(!tree.majors().is_empty());
+    for category in tree.categories() {
+        println!("{}", category);
+    }
+}
+
+#[test(tokio::test)]
+async fn crawl_catalog() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let categories = [
+        LectureCategory::major("IT대학", "글로벌미디어학부", None),
+        LectureCategory::find_by_lecture("미디어"),
+    ];
+    let catalog = app
+        .crawl_catalog_with(2025, SemesterType::One, &categories)
+        .await
+        .unwrap();
+    assert!(!catalog.is_empty());
+    assert!(catalog.diff(&catalog).is_empty());
+    println!("{} lectures", catalog.len());
+}
+
+#[test(tokio::test)]
+async fn seat_monitor() {
+    let session = get_session().await.unwrap().clone();
+    let mut app = USaintClientBuilder::new()
+        .session(session)
+        .build_into::<CourseScheduleApplication>()
+        .await
+        .unwrap();
+    let category = LectureCategory::find_by_lecture("미디어");
+    let lecture = app
+        .find_lectures(2025, SemesterType::One, &category)
+        .await
+        .unwrap()
+        .next()
+        .unwrap();
+    let mut monitor = SeatMonitorBuilder::new(2025, SemesterType::One)
+        .lecture(&lecture)
+        .emit_initial(true)
+        .build(app);
+    let key = LectureKey::of(&lecture);
+    let seats = lecture.seat_availability().unwrap();
+    let events = monitor.poll().await.unwrap();
+    assert!(monitor.initialized());
+    if seats.is_available() {
+        assert!(matches!(
+            &events[..],
+            [SeatEvent::Opened { lecture, remaining_seats }]
+                if LectureKey::of(lecture) == key && *remaining_seats == seats
+        ));
+    } else {
+        assert!(events.is_empty());
+    }
+    let targets = monitor.targets().collect::<Vec<_>>();
+    assert_eq!(targets.len(), 1);
+    assert_eq!(targets[0].0, &key);
+    assert!(targets[0].1.is_some());
+    let events = monitor.poll().await.unwrap();
+    assert!(
+        events
+            .iter()
+            .all(|event| !matches!(event, SeatEvent::NotFound(_)))
+    );
+}
+
+#[test(tokio::test)]
+async fn query_lectures() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let query = LectureQuery::new(LectureCategory::major("IT대학", "글로벌미디어학부", None))
+        .rows(LectureRows::Hundred)
+        .grade(2)
+        .available_only(true);
+    let lectures = app
+        .query_lectures(2025, SemesterType::One, &query)
+        .await
+        .unwrap();
+    assert!(lectures.iter().all(|lecture| query.matches(lecture)));
+}
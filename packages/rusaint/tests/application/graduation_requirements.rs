@@ -2,8 +2,11 @@ use crate::get_session;
 use lazy_static::lazy_static;
 use rusaint::RusaintError;
 use rusaint::application::{
-    USaintClientBuilder, graduation_requirements::GraduationRequirementsApplication,
+    USaintClientBuilder,
+    course_schedule::{CourseScheduleApplication, model::LectureCategory},
+    graduation_requirements::{GraduationRequirementsApplication, planner::GraduationPlan},
 };
+use rusaint::model::SemesterType;
 use std::sync::{Arc, OnceLock};
 use test_log::test;
 use tokio::sync::{Mutex, RwLock};
@@ -46,3 +49,33 @@ async fn graduation_requirements() {
     let graduation_requirements = app.requirements().await.unwrap();
     println!("{:?}", graduation_requirements);
 }
+
+#[test(tokio::test)]
+async fn graduation_plan() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let requirements = app.requirements().await.unwrap();
+    let session = get_session().await.unwrap().clone();
+    let mut schedule_app = USaintClientBuilder::new()
+        .session(session)
+        .build_into::<CourseScheduleApplication>()
+        .await
+        .unwrap();
+    let catalog = schedule_app
+        .crawl_catalog_with(
+            2025,
+            SemesterType::One,
+            &[LectureCategory::major("IT대학", "글로벌미디어학부", None)],
+        )
+        .await
+        .unwrap();
+    let plan = GraduationPlan::new(&requirements, &catalog);
+    for requirement in plan.requirements() {
+        println!(
+            "{}: {} short, {} suggestions",
+            requirement.name(),
+            requirement.shortfall(),
+            requirement.suggestions().len()
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use crate::get_session;
+use lazy_static::lazy_static;
+use rusaint::{
+    ApplicationError, RusaintError,
+    application::{
+        USaintClientBuilder, lecture_assessment_submission::LectureAssessmentSubmissionApplication,
+    },
+};
+use std::sync::{Arc, OnceLock};
+use test_log::test;
+use tokio::sync::{Mutex, RwLock};
+
+lazy_static! {
+    static ref APP: Mutex<OnceLock<Arc<RwLock<LectureAssessmentSubmissionApplication>>>> =
+        Mutex::new(OnceLock::new());
+}
+
+async fn get_app() -> Result<Arc<RwLock<LectureAssessmentSubmissionApplication>>, RusaintError> {
+    let app_lock = APP.lock().await;
+    if let Some(lock) = app_lock.get() {
+        Ok(lock.clone())
+    } else {
+        let session = get_session().await.unwrap().clone();
+        app_lock
+            .set(Arc::new(RwLock::new(
+                USaintClientBuilder::new()
+                    .session(session)
+                    .build_into()
+                    .await?,
+            )))
+            .unwrap();
+        Ok(app_lock.get().unwrap().clone())
+    }
+}
+
+#[test(tokio::test)]
+async fn pending_assessments() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let assessments = app.pending_assessments().await.unwrap();
+    println!("{:?}", assessments);
+}
+
+#[test(tokio::test)]
+async fn questionnaire() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let assessments = app.pending_assessments().await.unwrap();
+    let Some(assessment) = assessments
+        .iter()
+        .find(|assessment| !assessment.completed())
+    else {
+        return;
+    };
+    match app.questionnaire(assessment).await {
+        Ok(questions) => println!("{:?}", questions),
+        Err(err) => println!("{:?}", err),
+    }
+}
+
+#[test(tokio::test)]
+async fn submit_incomplete_answers() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let assessments = app.pending_assessments().await.unwrap();
+    let Some(assessment) = assessments
+        .iter()
+        .find(|assessment| !assessment.completed())
+    else {
+        return;
+    };
+    if app.questionnaire(assessment).await.is_err() {
+        return;
+    }
+    let result = app.submit(assessment, &[]).await;
+    assert!(matches!(
+        result,
+        Err(RusaintError::ApplicationError(
+            ApplicationError::IncompleteAssessment(_)
+        ))
+    ));
+}
@@ -1,9 +1,17 @@
+mod academic_calendar;
+mod academic_status;
+mod advising;
+mod certificate;
 mod chapel;
 mod course_grades;
+mod course_registration;
 mod course_schedule;
 mod graduation_requirements;
 mod lecture_assessment;
+mod lecture_assessment_submission;
+mod multiple_major;
 mod personal_course_schedule;
 mod student_information;
+mod tuition;
 
 mod scholarships;
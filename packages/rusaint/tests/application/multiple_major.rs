@@ -0,0 +1,84 @@
+use crate::get_session;
+use lazy_static::lazy_static;
+use rusaint::{
+    RusaintError,
+    application::{
+        USaintClientBuilder,
+        multiple_major::{MultipleMajorApplication, model::MajorApplicationState},
+    },
+};
+use std::sync::{Arc, OnceLock};
+use test_log::test;
+use tokio::sync::{Mutex, RwLock};
+
+lazy_static! {
+    static ref APP: Mutex<OnceLock<Arc<RwLock<MultipleMajorApplication>>>> =
+        Mutex::new(OnceLock::new());
+}
+
+async fn get_app() -> Result<Arc<RwLock<MultipleMajorApplication>>, RusaintError> {
+    let app_lock = APP.lock().await;
+    if let Some(lock) = app_lock.get() {
+        Ok(lock.clone())
+    } else {
+        let session = get_session().await.unwrap().clone();
+        app_lock
+            .set(Arc::new(RwLock::new(
+                USaintClientBuilder::new()
+                    .session(session)
+                    .build_into()
+                    .await?,
+            )))
+            .unwrap();
+        Ok(app_lock.get().unwrap().clone())
+    }
+}
+
+#[test(tokio::test)]
+async fn application_window() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let window = app.application_window().await.unwrap();
+    println!("{:?}", window);
+    if let Some(window) = window {
+        assert!(!window.start().is_empty());
+        assert!(window.start() <= window.end());
+    }
+}
+
+#[test(tokio::test)]
+async fn programs() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let programs = app.programs().await.unwrap();
+    println!("{:?}", programs);
+    for program in &programs {
+        assert!(!program.name().is_empty());
+        assert!(!program.kind_text().is_empty());
+    }
+}
+
+#[test(tokio::test)]
+async fn applications() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let applications = app.applications().await.unwrap();
+    println!("{:?}", applications);
+    for application in &applications {
+        assert!(!application.applied_at().is_empty());
+        assert!(!application.name().is_empty());
+        assert_ne!(application.state(), MajorApplicationState::Unknown);
+    }
+}
+
+#[test(tokio::test)]
+async fn eligibility() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let criteria = app.eligibility().await.unwrap();
+    println!("{:?}", criteria);
+    for criterion in &criteria {
+        assert!(!criterion.name().is_empty());
+        assert!(!criterion.requirement().is_empty());
+    }
+}
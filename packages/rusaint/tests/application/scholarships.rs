@@ -13,4 +13,45 @@ async fn scholarships() {
         .unwrap();
     let info = app.scholarships().await.unwrap();
     println!("{:?}", info);
+    for scholarship in &info {
+        assert!(!scholarship.name().is_empty());
+    }
+}
+
+#[test(tokio::test)]
+async fn open_scholarships() {
+    let session = get_session().await.unwrap().clone();
+    let mut app = USaintClientBuilder::new()
+        .session(session)
+        .build_into::<ScholarshipsApplication>()
+        .await
+        .unwrap();
+    let open = app.open_scholarships().await.unwrap();
+    println!("{:?}", open);
+    for scholarship in &open {
+        assert!(!scholarship.name().is_empty());
+        assert!(scholarship.apply_start() <= scholarship.apply_end());
+    }
+}
+
+#[test(tokio::test)]
+async fn applications_and_documents() {
+    let session = get_session().await.unwrap().clone();
+    let mut app = USaintClientBuilder::new()
+        .session(session)
+        .build_into::<ScholarshipsApplication>()
+        .await
+        .unwrap();
+    let applications = app.applications().await.unwrap();
+    for application in &applications {
+        println!("{} {:?}", application.name(), application.state());
+        assert!(!application.name().is_empty());
+        assert!(!application.applied_at().is_empty());
+    }
+    let documents = app.required_documents().await.unwrap();
+    println!("{:?}", documents);
+    for document in &documents {
+        assert!(!document.scholarship().is_empty());
+        assert!(!document.name().is_empty());
+    }
 }
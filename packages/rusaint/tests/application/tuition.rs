@@ -0,0 +1,60 @@
+use crate::{TARGET_SEMESTER, TARGET_YEAR, get_session};
+use lazy_static::lazy_static;
+use rusaint::{
+    ApplicationError, RusaintError,
+    application::{USaintClientBuilder, tuition::TuitionApplication},
+    model::SemesterType,
+};
+use std::sync::{Arc, OnceLock};
+use test_log::test;
+use tokio::sync::{Mutex, RwLock};
+
+lazy_static! {
+    static ref APP: Mutex<OnceLock<Arc<RwLock<TuitionApplication>>>> = Mutex::new(OnceLock::new());
+}
+
+async fn get_app() -> Result<Arc<RwLock<TuitionApplication>>, RusaintError> {
+    let app_lock = APP.lock().await;
+    if let Some(lock) = app_lock.get() {
+        Ok(lock.clone())
+    } else {
+        let session = get_session().await.unwrap().clone();
+        app_lock
+            .set(Arc::new(RwLock::new(
+                USaintClientBuilder::new()
+                    .session(session)
+                    .build_into()
+                    .await?,
+            )))
+            .unwrap();
+        Ok(app_lock.get().unwrap().clone())
+    }
+}
+
+#[test(tokio::test)]
+async fn bill() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let bill = app.bill(*TARGET_YEAR, *TARGET_SEMESTER).await.unwrap();
+    assert_eq!(bill.year(), *TARGET_YEAR);
+    println!("{:?}", bill);
+}
+
+#[test(tokio::test)]
+async fn no_bill() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let err = app.bill(2010, SemesterType::One).await.unwrap_err();
+    assert!(matches!(
+        err,
+        RusaintError::ApplicationError(ApplicationError::NoTuitionBill)
+    ));
+}
+
+#[test(tokio::test)]
+async fn payments() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let payments = app.payments().await.unwrap();
+    println!("{:?}", payments);
+}